@@ -5,6 +5,7 @@ use criterion::async_executor::FuturesExecutor;
 use criterion::criterion_group;
 use criterion::criterion_main;
 
+use deno_doc::DocCache;
 use deno_doc::DocNode;
 use deno_doc::DocParser;
 use deno_doc::DocParserOptions;
@@ -17,24 +18,38 @@ use deno_graph::source::MemoryLoader;
 use deno_graph::source::Source;
 use indexmap::IndexMap;
 
-async fn parse() -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
+async fn parse(
+  modules: usize,
+  cache: Option<DocCache>,
+) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
   let source = std::fs::read_to_string("./benches/fixtures/deno.d.ts").unwrap();
-  let sources = vec![(
-    "file:///test/fixtures/deno.d.ts",
-    Source::Module {
-      specifier: "file:///test/fixtures/deno.d.ts",
-      maybe_headers: None,
-      content: source.as_str(),
-    },
-  )];
+  let specifiers = (0..modules)
+    .map(|i| format!("file:///test/fixtures/{i}/deno.d.ts"))
+    .collect::<Vec<_>>();
+  let sources = specifiers
+    .iter()
+    .map(|specifier| {
+      (
+        specifier.as_str(),
+        Source::Module {
+          specifier: specifier.as_str(),
+          maybe_headers: None,
+          content: source.as_str(),
+        },
+      )
+    })
+    .collect();
   let memory_loader = MemoryLoader::new(sources, vec![]);
-  let root = ModuleSpecifier::parse("file:///test/fixtures/deno.d.ts").unwrap();
+  let roots = specifiers
+    .iter()
+    .map(|specifier| ModuleSpecifier::parse(specifier).unwrap())
+    .collect::<Vec<_>>();
 
   let analyzer = CapturingModuleAnalyzer::default();
   let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
   graph
     .build(
-      vec![root.clone()],
+      roots.clone(),
       Vec::new(),
       &memory_loader,
       BuildOptions {
@@ -43,16 +58,48 @@ async fn parse() -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
       },
     )
     .await;
-  DocParser::new(&graph, &analyzer, &[root], DocParserOptions::default())
-    .unwrap()
-    .parse()
-    .unwrap()
+  DocParser::new(
+    &graph,
+    &analyzer,
+    &roots,
+    DocParserOptions {
+      cache,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+  .parse()
+  .unwrap()
 }
 
 fn doc_parser(c: &mut Criterion) {
   c.bench_function("parse large", |b| {
-    b.to_async(FuturesExecutor).iter_with_large_drop(parse)
+    b.to_async(FuturesExecutor)
+      .iter_with_large_drop(|| parse(1, None))
+  });
+
+  // extracted in parallel with the `parallel` feature
+  c.bench_function("parse many", |b| {
+    b.to_async(FuturesExecutor)
+      .iter_with_large_drop(|| parse(4, None))
   });
+
+  let cache_dir = std::env::temp_dir().join("deno_doc_bench_cache");
+  let _ = std::fs::remove_dir_all(&cache_dir);
+  let cache = DocCache::new(&cache_dir);
+  // warms the cache, and checks that the cached nodes match the extracted ones
+  let block_on_json = |cache| {
+    serde_json::to_value(futures::executor::block_on(parse(1, cache))).unwrap()
+  };
+  block_on_json(Some(cache.clone()));
+  assert_eq!(block_on_json(Some(cache.clone())), block_on_json(None));
+
+  c.bench_function("parse large cached", |b| {
+    b.to_async(FuturesExecutor)
+      .iter_with_large_drop(|| parse(1, Some(cache.clone())))
+  });
+
+  let _ = std::fs::remove_dir_all(&cache_dir);
 }
 
 criterion_group!(benches, doc_parser);
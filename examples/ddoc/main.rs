@@ -2,16 +2,20 @@
 
 use clap::App;
 use clap::Arg;
+use deno_doc::DocCache;
 use deno_doc::DocParser;
 use deno_doc::DocParserOptions;
 use deno_doc::DocPrinter;
-use deno_doc::find_nodes_by_name_recursively;
+use deno_doc::DocPrinterOrder;
+use deno_doc::NodeModules;
 use deno_doc::html::GenerateCtx;
 use deno_doc::html::HrefResolver;
+use deno_doc::html::Shared;
 use deno_doc::html::UrlResolveKind;
 use deno_doc::html::UsageComposer;
 use deno_doc::html::UsageComposerEntry;
 use deno_doc::node::DocNodeDef;
+use deno_doc::node::DocNodesDocument;
 use deno_graph::BuildOptions;
 use deno_graph::GraphKind;
 use deno_graph::ModuleGraph;
@@ -20,11 +24,11 @@ use deno_graph::ast::CapturingModuleAnalyzer;
 use deno_graph::source::LoadFuture;
 use deno_graph::source::LoadResponse;
 use deno_graph::source::Loader;
+use deno_graph::source::Resolver;
 use futures::executor::block_on;
 use futures::future;
 use indexmap::IndexMap;
 use std::env::current_dir;
-use std::rc::Rc;
 
 struct SourceFileLoader {}
 
@@ -70,16 +74,40 @@ async fn run() -> anyhow::Result<()> {
         .takes_value(true),
     )
     .arg(Arg::with_name("output").long("output").takes_value(true))
+    .arg(
+      Arg::with_name("single_page")
+        .long("single_page")
+        .requires("html"),
+    )
     .arg(Arg::with_name("source_files").required(true).multiple(true))
     .arg(
       Arg::with_name("filter")
         .long("filter")
+        .takes_value(true)
         .conflicts_with("html"),
     )
     .arg(Arg::with_name("private").long("private"))
+    .arg(
+      Arg::with_name("cache_dir")
+        .long("cache_dir")
+        .takes_value(true),
+    )
+    .arg(
+      Arg::with_name("node_modules_dir")
+        .long("node_modules_dir")
+        .takes_value(true),
+    )
+    .arg(
+      Arg::with_name("sort")
+        .long("sort")
+        .takes_value(true)
+        .possible_values(&["kind", "source", "alphabetical", "category"])
+        .conflicts_with_all(&["html", "json"]),
+    )
     .get_matches();
   let source_files = matches.values_of("source_files").unwrap();
   let html = matches.is_present("html");
+  let single_page = matches.is_present("single_page");
   let json = matches.is_present("json");
   let name = if html {
     matches.value_of("name").map(|name| name.to_string())
@@ -103,6 +131,14 @@ async fn run() -> anyhow::Result<()> {
   };
   let maybe_filter = matches.value_of("filter");
   let private = matches.is_present("private");
+  let cache = matches.value_of("cache_dir").map(DocCache::new);
+  let node_modules = matches.value_of("node_modules_dir").map(NodeModules::new);
+  let maybe_order = matches.value_of("sort").map(|order| match order {
+    "source" => DocPrinterOrder::Source,
+    "alphabetical" => DocPrinterOrder::Alphabetical,
+    "category" => DocPrinterOrder::Category,
+    _ => DocPrinterOrder::Kind,
+  });
   let source_files: Vec<ModuleSpecifier> = source_files
     .into_iter()
     .map(|source_file| {
@@ -113,15 +149,24 @@ async fn run() -> anyhow::Result<()> {
     })
     .collect();
   let loader = SourceFileLoader {};
+  let node_modules_loader = node_modules
+    .as_ref()
+    .map(|node_modules| node_modules.loader(&loader));
   let analyzer = CapturingModuleAnalyzer::default();
   let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
   graph
     .build(
       source_files.clone(),
       Vec::new(),
-      &loader,
+      match &node_modules_loader {
+        Some(node_modules_loader) => node_modules_loader,
+        None => &loader,
+      },
       BuildOptions {
         module_analyzer: &analyzer,
+        resolver: node_modules
+          .as_ref()
+          .map(|node_modules| node_modules as &dyn Resolver),
         ..Default::default()
       },
     )
@@ -137,6 +182,7 @@ async fn run() -> anyhow::Result<()> {
     DocParserOptions {
       diagnostics: false,
       private,
+      cache,
     },
   )?;
   let doc_nodes_by_url = parser.parse()?;
@@ -147,24 +193,41 @@ async fn run() -> anyhow::Result<()> {
       output_dir,
       main_entrypoint,
       doc_nodes_by_url,
+      deno_doc::html::collect_source_files(&graph),
+      single_page,
     )?;
     return Ok(());
   }
 
-  let mut doc_nodes =
-    parser.parse()?.into_values().flatten().collect::<Vec<_>>();
+  let mut doc_nodes_by_url = parser.parse()?;
 
-  doc_nodes
-    .retain(|doc_node| !matches!(doc_node.def, DocNodeDef::Import { .. }));
-  if let Some(filter) = maybe_filter {
-    doc_nodes = find_nodes_by_name_recursively(doc_nodes, filter);
+  for doc_nodes in doc_nodes_by_url.values_mut() {
+    doc_nodes
+      .retain(|doc_node| !matches!(doc_node.def, DocNodeDef::Import { .. }));
+    if let Some(filter) = maybe_filter {
+      *doc_nodes = deno_doc::query::select_nodes(doc_nodes, filter)?
+        .into_iter()
+        .map(|query_match| query_match.node)
+        .collect();
+    }
   }
 
   if json {
-    serde_json::to_writer_pretty(std::io::stdout(), &doc_nodes)?;
+    let document = DocNodesDocument::new(
+      doc_nodes_by_url
+        .into_iter()
+        .map(|(specifier, doc_nodes)| (specifier.to_string(), doc_nodes))
+        .collect(),
+    );
+    serde_json::to_writer_pretty(std::io::stdout(), &document)?;
     println!();
   } else {
-    let result = DocPrinter::new(&doc_nodes, true, false);
+    let doc_nodes =
+      doc_nodes_by_url.into_values().flatten().collect::<Vec<_>>();
+    let mut result = DocPrinter::new(&doc_nodes, true, false);
+    if let Some(order) = maybe_order {
+      result = result.with_order(order).with_member_order(order);
+    }
     println!("{}", result);
   }
 
@@ -248,6 +311,8 @@ fn generate_docs_directory(
   output_dir: String,
   main_entrypoint: Option<ModuleSpecifier>,
   doc_nodes_by_url: IndexMap<ModuleSpecifier, Vec<deno_doc::DocNode>>,
+  source_files: IndexMap<ModuleSpecifier, std::sync::Arc<str>>,
+  single_page: bool,
 ) -> Result<(), anyhow::Error> {
   let cwd = current_dir().unwrap();
   let output_dir_resolved = cwd.join(output_dir);
@@ -260,8 +325,8 @@ fn generate_docs_directory(
   let options = deno_doc::html::GenerateOptions {
     package_name,
     main_entrypoint,
-    href_resolver: Rc::new(EmptyResolver),
-    usage_composer: Rc::new(EmptyResolver),
+    href_resolver: Shared::new(EmptyResolver),
+    usage_composer: Shared::new(EmptyResolver),
     rewrite_map: Some(index_map),
     category_docs: None,
     disable_search: false,
@@ -270,28 +335,43 @@ fn generate_docs_directory(
     markdown_renderer: deno_doc::html::comrak::create_renderer(
       None, None, None,
     ),
-    markdown_stripper: Rc::new(deno_doc::html::comrak::strip),
-    head_inject: Some(Rc::new(|root| {
-      format!(
-        r#"<link rel="stylesheet" href="{root}{}">"#,
-        deno_doc::html::comrak::COMRAK_STYLESHEET_FILENAME
-      )
-    })),
+    markdown_stripper: Shared::new(deno_doc::html::comrak::strip),
+    // the single page inlines the stylesheet
+    head_inject: (!single_page).then(|| -> deno_doc::html::HeadInject {
+      Shared::new(|root| {
+        format!(
+          r#"<link rel="stylesheet" href="{root}{}">"#,
+          deno_doc::html::comrak::COMRAK_STYLESHEET_FILENAME
+        )
+      })
+    }),
     id_prefix: None,
+    template_overrides: None,
+    messages: None,
+    versions: None,
+    source_files: Some(source_files),
+    class_hierarchy: false,
+    full_text_search: false,
+    base_url: None,
+    inventories: None,
+    expand_types: false,
   };
   let ctx = GenerateCtx::create_basic(options, doc_nodes_by_url)?;
-  let html = deno_doc::html::generate(ctx)?;
 
   let path = &output_dir_resolved;
   let _ = std::fs::remove_dir_all(path);
   std::fs::create_dir(path)?;
 
-  for (name, content) in html {
-    let this_path = path.join(name);
-    let prefix = this_path.parent().unwrap();
-    std::fs::create_dir_all(prefix).unwrap();
-    std::fs::write(this_path, content).unwrap();
+  if single_page {
+    let html = deno_doc::html::generate_single_page(ctx)?;
+    std::fs::write(path.join("index.html"), html)?;
+    return Ok(());
   }
 
+  deno_doc::html::generate_to_sink(
+    ctx,
+    &mut deno_doc::html::DirectorySink::new(path),
+  )?;
+
   Ok(())
 }
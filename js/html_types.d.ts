@@ -8,7 +8,6 @@ export interface HtmlHeadCtx {
   reset_stylesheet_url: string;
   url_search_index: string;
   script_js: string;
-  fuse_js: string;
   url_search: string;
   head_inject: string | null;
   disable_search: boolean;
@@ -10,6 +10,12 @@ export type { CacheSetting, LoadResponse } from "@deno/graph";
 export * from "./types.d.ts";
 export * from "./html_types.d.ts";
 
+/**
+ * The version of the format of the documentation nodes, which is bumped on
+ * breaking changes to their serialization.
+ */
+export const DOC_NODE_FORMAT_VERSION = 1;
+
 const encoder = new TextEncoder();
 
 export interface DocOptions {
@@ -127,6 +133,91 @@ export async function doc(
   );
 }
 
+export interface DocSourcesOptions {
+  /** If `true` include all documentation nodes in the output, included private
+   * (non-exported) nodes. The default is `false`. */
+  includeAll?: boolean;
+}
+
+/**
+ * Generate asynchronously an array of documentation nodes for modules whose
+ * sources are provided in memory, without needing a `load()` callback.
+ *
+ * ### Example
+ *
+ * ```ts
+ * import { docSources } from "https://deno.land/x/deno_doc/mod.ts";
+ *
+ * const entries = await docSources(["file:///mod.ts"], {
+ *   "file:///mod.ts": `export * from "./a.ts";`,
+ *   "file:///a.ts": `/** a doc *\/ export const a = 1;`,
+ * });
+ * ```
+ *
+ * @param specifiers List of the URL strings of the specifiers to document
+ * @param sources The source text of every module in the graph, keyed by URL
+ * string
+ * @param options A set of options for generating the documentation
+ * @returns A promise that resolves with an array of documentation nodes
+ */
+export async function docSources(
+  specifiers: string[],
+  sources: Record<string, string>,
+  options: DocSourcesOptions = {},
+): Promise<Record<string, Array<DocNode>>> {
+  const { includeAll = false } = options;
+
+  const wasm = await instantiate();
+  return wasm.doc_sources(specifiers, sources, includeAll);
+}
+
+export interface QueryMatch {
+  /** The name of the node, prefixed by the names of the namespaces, classes
+   * or interfaces it is nested in, joined by `.`. */
+  qualifiedName: string;
+  /** The matched node. Class and interface members are represented as
+   * function and variable nodes. */
+  node: DocNode;
+}
+
+/**
+ * Select the documentation nodes matching a query, walking into namespace
+ * elements and class and interface members.
+ *
+ * A query is a whitespace separated list of terms which all have to match:
+ * - `Foo`, `Foo.bar`, `get*`, `Deno.**`: glob on the (qualified) name
+ * - `/^use[A-Z]/`: regex on the qualified name
+ * - `kind:function`, `kind:class,interface`: the kind of node
+ * - `@deprecated`, `@experimental`, `@category Name`: JSDoc tags
+ * - `module:*\/fs/*.ts`: glob on the specifier of the declaring module
+ * - `returns:Promise`: functions and methods returning the type
+ *
+ * Terms can be negated with a `!` prefix.
+ *
+ * ### Example
+ *
+ * ```ts
+ * import { doc, selectNodes } from "https://deno.land/x/deno_doc/mod.ts";
+ *
+ * const records = await doc(["https://deno.land/std/fmt/colors.ts"]);
+ * const matches = await selectNodes(
+ *   Object.values(records).flat(),
+ *   "kind:function strip*",
+ * );
+ * ```
+ *
+ * @param docNodes The nodes to select from
+ * @param query The query to select nodes with
+ * @returns A promise that resolves with the matching nodes
+ */
+export async function selectNodes(
+  docNodes: Array<DocNode>,
+  query: string,
+): Promise<Array<QueryMatch>> {
+  const wasm = await instantiate();
+  return wasm.select_nodes(docNodes, query);
+}
+
 export interface ShortPath {
   /** Name identifier for the path. */
   path: string;
@@ -285,6 +376,179 @@ export interface GenerateOptions {
   markdownStripper(md: string): string;
   /** Prefix for IDs of elements. */
   idPrefix?: string;
+  /** Replacements for the built-in templates, and additional partials. */
+  templateOverrides?: TemplateOverrides;
+  /**
+   * The message catalog for the user-facing strings. Missing entries fall
+   * back to English.
+   */
+  messages?: Partial<Messages>;
+  /**
+   * The current version and the other versions to link to from the version
+   * switcher in the top navigation.
+   */
+  versions?: VersionOptions;
+  /**
+   * The source text of modules, keyed by their absolute URL. When set, a
+   * highlighted source page is generated for each module, and source links
+   * point to these pages instead of using `hrefResolver.resolveSource`.
+   */
+  sourceFiles?: Record<string, string>;
+  /**
+   * Render a diagram of the ancestors and descendants on class and interface
+   * pages, and generate a `hierarchy.html` page with all the hierarchies.
+   * Defaults to `false`.
+   */
+  classHierarchy?: boolean;
+  /**
+   * Index the full text of the documentation for the search, including
+   * parameters, return values, examples and the sections of module docs.
+   * This increases the size of the search index. Defaults to `false`.
+   */
+  fullTextSearch?: boolean;
+  /**
+   * The absolute URL the documentation is published at, eg.
+   * `https://example.com/docs/`. When set, every page gets a canonical link,
+   * and a `sitemap.xml` and `robots.txt` are generated.
+   */
+  baseUrl?: string;
+  /**
+   * The inventories of other packages, as emitted in their `inventory.json`,
+   * keyed by the specifier prefix of the package, eg. `jsr:@std/path` or
+   * `npm:zod`. Symbols imported from these packages link to their
+   * documentation.
+   */
+  inventories?: Record<string, Inventory>;
+  /**
+   * Whether to render a disclosure with the members of the types of type
+   * aliases and variables which reference other type aliases, interfaces or
+   * the `Partial`, `Readonly`, `Pick`, `Omit` and `Record` utility types.
+   */
+  expandTypes?: boolean;
+}
+
+export interface VersionOptions {
+  /** The name of the version being generated, eg. "1.2.0". */
+  current: string;
+  /** The other versions to link to, in the order they are listed. */
+  others: DocVersion[];
+  /**
+   * The root URL of the current version's own output. When set, the output
+   * is treated as an alias of the current version (eg. "latest"), and every
+   * page carries a canonical link to the same page under this root.
+   */
+  canonicalRoot?: string;
+}
+
+export interface DocVersion {
+  /** The name of the version, eg. "1.1.0". */
+  name: string;
+  /**
+   * The root of the version's output, either as a URL or relative to the root
+   * of the current output, eg. `../1.1.0/`.
+   */
+  root: string;
+  /**
+   * The symbols documented in the version, keyed by module path. When not
+   * set, every page is assumed to exist in the version.
+   */
+  symbols?: Record<string, string[]>;
+  /**
+   * Paths relative to the version's root for symbols which have been renamed
+   * or moved, keyed by module path and the name of the symbol in the current
+   * version.
+   */
+  symbolRedirectMap?: Record<string, Record<string, string>>;
+}
+
+/** The symbols documented by a package, emitted as `inventory.json`. */
+export interface Inventory {
+  /**
+   * The URL the hrefs are relative to, which must be set to link to the
+   * symbols of the inventory.
+   */
+  baseUrl?: string;
+  packageName?: string;
+  /**
+   * The modules by the subpath they are imported from, eg. `posix` for
+   * `jsr:@std/path/posix`, or an empty string for the main module.
+   */
+  modules: Record<string, InventoryModule>;
+}
+
+export interface InventoryModule {
+  href: string;
+  /** The symbols by their qualified name, eg. `Foo.prototype.bar`. */
+  symbols: Record<string, { href: string; kind: string[] }>;
+}
+
+/**
+ * The user-facing strings of the generated HTML. `lang` is the BCP 47
+ * language tag of the messages, which is set on the document and used for
+ * slugifying category names. `{count}` in `view_all_symbols` is replaced with
+ * the number of symbols, and `{package}` in `package_documentation` with the
+ * name of the package.
+ */
+export type Messages = Record<
+  | "lang"
+  | "parameters"
+  | "return_type"
+  | "throws"
+  | "examples"
+  | "deprecated"
+  | "see"
+  | "type_parameters"
+  | "type"
+  | "definition"
+  | "value"
+  | "members"
+  | "constructors"
+  | "properties"
+  | "methods"
+  | "static_properties"
+  | "static_methods"
+  | "index_signatures"
+  | "call_signatures"
+  | "documentation"
+  | "package_documentation"
+  | "breadcrumb_index"
+  | "breadcrumb_all_symbols"
+  | "all_symbols"
+  | "symbols"
+  | "view_all_symbols"
+  | "document_navigation"
+  | "usage"
+  | "usage_in_deno"
+  | "use_with"
+  | "no_documentation"
+  | "extends"
+  | "implements"
+  | "implemented_by"
+  | "extended_by"
+  | "subclasses"
+  | "accepted_by"
+  | "returned_by"
+  | "hierarchy"
+  | "expanded_type"
+  | "anchor"
+  | "toggle_dark_mode"
+  | "search_placeholder"
+  | "search_focused_placeholder"
+  | "search_no_results"
+  | "search_in_documentation"
+  | "select_version",
+  string
+>;
+
+export interface TemplateOverrides {
+  /**
+   * Replacements for built-in Handlebars templates, keyed by the template
+   * name, eg. `pages/top_nav`, `symbol_content` or `example`. A replacement
+   * may only reference fields that the built-in template uses.
+   */
+  templates?: Record<string, string>;
+  /** Additional partials which can be used from any template. */
+  partials?: Record<string, string>;
 }
 
 const defaultUsageComposer: UsageComposer = {
@@ -334,6 +598,15 @@ export async function generateHtml(
     options.markdownStripper,
     options.headInject,
     options.idPrefix,
+    options.templateOverrides,
+    options.messages,
+    options.versions,
+    options.sourceFiles,
+    options.classHierarchy ?? false,
+    options.fullTextSearch ?? false,
+    options.baseUrl,
+    options.inventories,
+    options.expandTypes ?? false,
     docNodesByUrl,
     false,
   );
@@ -372,6 +645,15 @@ export async function generateHtmlAsJSON(
     options.markdownStripper,
     options.headInject,
     options.idPrefix,
+    options.templateOverrides,
+    options.messages,
+    options.versions,
+    options.sourceFiles,
+    options.classHierarchy ?? false,
+    options.fullTextSearch ?? false,
+    options.baseUrl,
+    options.inventories,
+    options.expandTypes ?? false,
     docNodesByUrl,
     true,
   );
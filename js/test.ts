@@ -1,7 +1,14 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 import { assert, assertEquals, assertRejects } from "@std/assert";
-import { doc, generateHtml, generateHtmlAsJSON } from "./mod.ts";
+import {
+  doc,
+  DOC_NODE_FORMAT_VERSION,
+  docSources,
+  generateHtml,
+  generateHtmlAsJSON,
+  selectNodes,
+} from "./mod.ts";
 
 Deno.test({
   name: "doc()",
@@ -56,6 +63,43 @@ Deno.test({
   },
 });
 
+Deno.test({
+  name: "DOC_NODE_FORMAT_VERSION",
+  fn() {
+    assertEquals(DOC_NODE_FORMAT_VERSION, 1);
+  },
+});
+
+Deno.test({
+  name: "docSources()",
+  async fn() {
+    const records = await docSources(["file:///mod.ts"], {
+      "file:///mod.ts": `export { b } from "./b.ts";\nexport const a = 1;`,
+      "file:///b.ts": `export function b(): void {}`,
+    });
+    const entries = records["file:///mod.ts"];
+    assertEquals(entries.map((n) => n.name).sort(), ["a", "b"]);
+  },
+});
+
+Deno.test({
+  name: "selectNodes()",
+  async fn() {
+    const records = await docSources(["file:///mod.ts"], {
+      "file:///mod.ts": `/** @deprecated */
+export function a(): void {}
+export interface B {
+  c(): string;
+}`,
+    });
+    const matches = await selectNodes(
+      records["file:///mod.ts"],
+      "kind:function !@deprecated",
+    );
+    assertEquals(matches.map((m) => m.qualifiedName), ["B.c"]);
+  },
+});
+
 Deno.test({
   name: "doc() - with headers",
   async fn() {
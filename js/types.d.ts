@@ -1,738 +1,611 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+// Generated by `deno_doc::schema::typescript_declarations`. Run
+// `UPDATE=1 cargo test --features schema js_types_match_schema` to update.
+
+/** The version of the serialized format described by these types. */
+export declare const DOC_NODE_FORMAT_VERSION: 1;
+
+/**
+ * The serialized output for a set of modules, tagged with the format version
+ * so consumers can detect breaking changes.
+ */
+export type DocNodesDocument = {
+  version: number;
+  /** The documentation nodes, keyed by module specifier. */
+  nodes: Record<string, DocNode[]>;
+};
 
-export type DocNode =
-  | DocNodeModuleDoc
-  | DocNodeFunction
-  | DocNodeVariable
-  | DocNodeEnum
-  | DocNodeClass
-  | DocNodeTypeAlias
-  | DocNodeNamespace
-  | DocNodeInterface
-  | DocNodeImport;
-
-/** Indicates how the documentation node was declared. `"private"` indicates
- * the node is un-exported. `"export"` indicates it is exported from the current
- * module. `"declare"` indicates that it is a type only declaration. */
-export type DeclarationKind = "private" | "export" | "declare";
-
-interface DocNodeBase {
-  kind: DocNodeKind;
+export type DocNode = {
   name: string;
+  isDefault?: boolean | null;
   location: Location;
   declarationKind: DeclarationKind;
   jsDoc?: JsDoc;
-}
-
-export type DocNodeKind =
-  | "moduleDoc"
-  | "function"
-  | "variable"
-  | "enum"
-  | "class"
-  | "typeAlias"
-  | "namespace"
-  | "interface"
-  | "import";
-
-export interface DocNodeModuleDoc extends DocNodeBase {
-  kind: "moduleDoc";
-  jsDoc: JsDoc;
-}
-
-export interface DocNodeFunction extends DocNodeBase {
+} & ({
   kind: "function";
   functionDef: FunctionDef;
-}
-
-export interface DocNodeVariable extends DocNodeBase {
+} | {
   kind: "variable";
   variableDef: VariableDef;
-}
-
-export interface DocNodeEnum extends DocNodeBase {
+} | {
   kind: "enum";
   enumDef: EnumDef;
-}
-
-export interface DocNodeClass extends DocNodeBase {
+} | {
   kind: "class";
   classDef: ClassDef;
-}
-
-export interface DocNodeTypeAlias extends DocNodeBase {
+} | {
   kind: "typeAlias";
   typeAliasDef: TypeAliasDef;
-}
-
-export interface DocNodeNamespace extends DocNodeBase {
+} | {
   kind: "namespace";
   namespaceDef: NamespaceDef;
-}
-
-export interface DocNodeInterface extends DocNodeBase {
+} | {
   kind: "interface";
   interfaceDef: InterfaceDef;
-}
-
-export interface DocNodeImport extends DocNodeBase {
+} | {
   kind: "import";
   importDef: ImportDef;
-}
+} | {
+  kind: "moduleDoc";
+} | {
+  kind: "reference";
+  reference_def: ReferenceDef;
+});
 
-export type Accessibility = "public" | "protected" | "private";
+export type Location = {
+  filename: string;
+  /**
+   * The 1-indexed display line.
+   * todo(#150): why is one of these 0-indexed and the other 1-indexed?
+   */
+  line: number;
+  /** The 0-indexed display column. */
+  col: number;
+  /** The 0-indexed byte offset in the source text. */
+  byteIndex?: number;
+};
 
-export interface ClassDef {
-  defName?: string;
-  isAbstract: boolean;
-  constructors: ClassConstructorDef[];
-  properties: ClassPropertyDef[];
-  indexSignatures: ClassIndexSignatureDef[];
-  methods: ClassMethodDef[];
-  extends?: string;
-  implements: TsTypeDef[];
-  typeParams: TsTypeParamDef[];
-  superTypeParams: TsTypeDef[];
-  decorators?: DecoratorDef[];
-}
+export type DeclarationKind = "private" | "declare" | "export";
 
-export type ClassConstructorParamDef = ParamDef & {
-  accessibility?: Accessibility;
-  isOverride?: boolean;
-  readonly?: boolean;
+export type JsDoc = {
+  doc?: string | null;
+  tags?: JsDocTag[];
 };
 
-export interface ClassConstructorDef {
-  jsDoc?: JsDoc;
-  accessibility?: Accessibility;
-  isOptional?: boolean;
-  hasBody?: boolean;
+export type JsDocTag = {
+  kind: "callback";
   name: string;
-  params: ClassConstructorParamDef[];
-  location: Location;
-}
-
-export interface ClassIndexSignatureDef {
-  readonly: boolean;
-  params: ParamDef[];
-  tsType?: TsTypeDef;
-}
-
-export interface ClassMethodDef {
-  jsDoc?: JsDoc;
-  accessibility?: Accessibility;
-  optional: boolean;
-  isAbstract: boolean;
-  isStatic: boolean;
-  isOverride?: boolean;
+  doc?: string | null;
+} | {
+  kind: "category";
+  doc?: string;
+} | {
+  kind: "constructor";
+} | {
+  kind: "default";
+  value: string;
+  doc?: string | null;
+} | {
+  kind: "deprecated";
+  doc?: string | null;
+} | {
+  kind: "enum";
+  type: string;
+  doc?: string | null;
+} | {
+  kind: "example";
+  doc?: string;
+} | {
+  kind: "experimental";
+} | {
+  kind: "extends";
+  type: string;
+  doc?: string | null;
+} | {
+  kind: "ignore";
+} | {
+  kind: "internal";
+} | {
+  kind: "module";
+  name?: string | null;
+} | {
+  kind: "param";
   name: string;
-  kind: MethodKind;
-  functionDef: FunctionDef;
-  location: Location;
-}
-
-export interface ClassPropertyDef {
-  jsDoc?: JsDoc;
-  tsType?: TsTypeDef;
-  readonly: boolean;
-  accessibility?: Accessibility;
-  optional: boolean;
-  isAbstract: boolean;
-  isStatic: boolean;
-  isOverride?: boolean;
+  type?: string | null;
+  optional?: boolean;
+  default?: string | null;
+  doc?: string | null;
+} | {
+  kind: "public";
+} | {
+  kind: "private";
+} | {
+  kind: "property";
   name: string;
-  decorators?: DecoratorDef[];
-  location: Location;
-}
-
-export interface DecoratorDef {
+  type?: string;
+  doc?: string | null;
+} | {
+  kind: "protected";
+} | {
+  kind: "readonly";
+} | {
+  kind: "return";
+  type?: string | null;
+  doc?: string | null;
+} | {
+  kind: "tags";
+  tags: string[];
+} | {
+  kind: "template";
   name: string;
-  args?: string[];
-  location: Location;
-}
-
-export interface EnumDef {
-  members: EnumMemberDef[];
-}
-
-export interface EnumMemberDef {
+  doc?: string | null;
+} | {
+  kind: "this";
+  type: string;
+  doc?: string | null;
+} | {
+  kind: "throws";
+  type?: string | null;
+  doc?: string | null;
+} | {
+  kind: "typedef";
   name: string;
-  init?: TsTypeDef;
-  jsDoc?: JsDoc;
-  location: Location;
-}
+  type: string;
+  doc?: string | null;
+} | {
+  kind: "type";
+  type: string;
+  doc?: string | null;
+} | {
+  kind: "see";
+  doc: string;
+} | {
+  kind: "since";
+  doc: string;
+} | {
+  kind: "unsupported";
+  value: string;
+};
 
-export interface FunctionDef {
-  defName?: string;
+export type FunctionDef = {
+  /** set when the function is a default export and has a name in its declaration */
+  defName?: string | null;
   params: ParamDef[];
-  returnType?: TsTypeDef;
+  returnType?: TsTypeDef | null;
   hasBody?: boolean;
   isAsync: boolean;
   isGenerator: boolean;
   typeParams: TsTypeParamDef[];
   decorators?: DecoratorDef[];
-}
-
-export interface ImportDef {
-  src: string;
-  imported?: string;
-}
-
-export interface InterfaceDef {
-  defName?: string;
-  extends: TsTypeDef[];
-  methods: InterfaceMethodDef[];
-  properties: InterfacePropertyDef[];
-  callSignatures: InterfaceCallSignatureDef[];
-  indexSignatures: InterfaceIndexSignatureDef[];
-  typeParams: TsTypeParamDef[];
-}
-
-export interface InterfaceCallSignatureDef {
-  location: Location;
-  jsDoc?: JsDoc;
-  params: ParamDef[];
-  tsType?: TsTypeDef;
-  typeParams: TsTypeParamDef[];
-}
-
-export interface InterfaceIndexSignatureDef {
-  readonly: boolean;
-  params: ParamDef[];
-  tsType?: TsTypeDef;
-}
+};
 
-export interface InterfaceMethodDef {
-  name: string;
-  kind: MethodKind;
-  location: Location;
-  jsDoc?: JsDoc;
-  computed?: boolean;
+export type ParamDef = {
+  decorators?: DecoratorDef[];
+  tsType?: TsTypeDef | null;
+} & ({
+  kind: "array";
+  elements: (ParamDef | null)[];
   optional: boolean;
-  params: ParamDef[];
-  returnType?: TsTypeDef;
-  typeParams: TsTypeParamDef[];
-}
-
-export interface InterfacePropertyDef {
+} | {
+  kind: "assign";
+  left: ParamDef;
+  right: string;
+} | {
+  kind: "identifier";
   name: string;
-  location: Location;
-  jsDoc?: JsDoc;
-  params: ParamDef[];
-  readonly?: boolean;
-  computed: boolean;
   optional: boolean;
-  tsType?: TsTypeDef;
-  typeParams: TsTypeParamDef[];
-}
-
-export interface JsDoc {
-  doc?: string;
-  tags?: JsDocTag[];
-}
-
-export type JsDocTagKind =
-  | "callback"
-  | "category"
-  | "constructor"
-  | "default"
-  | "deprecated"
-  | "enum"
-  | "example"
-  | "extends"
-  | "ignore"
-  | "module"
-  | "param"
-  | "public"
-  | "private"
-  | "property"
-  | "protected"
-  | "readonly"
-  | "return"
-  | "tags"
-  | "template"
-  | "this"
-  | "typedef"
-  | "type"
-  | "see"
-  | "unsupported";
-
-export type JsDocTag =
-  | JsDocTagOnly
-  | JsDocTagDoc
-  | JsDocTagDocRequired
-  | JsDocTagNamed
-  | JsDocTagValued
-  | JsDocTagTyped
-  | JsDocTagNamedTyped
-  | JsDocTagParam
-  | JsDocTagReturn
-  | JsDocTagTags
-  | JsDocTagUnsupported;
-
-export interface JsDocTagBase {
-  kind: JsDocTagKind;
-}
-
-export interface JsDocTagOnly extends JsDocTagBase {
-  kind:
-    | "constructor"
-    | "ignore"
-    | "module"
-    | "public"
-    | "private"
-    | "protected"
-    | "readonly";
-}
-
-export interface JsDocTagDoc extends JsDocTagBase {
-  kind: "deprecated";
-  doc?: string;
-}
+} | {
+  kind: "object";
+  props: ObjectPatPropDef[];
+  optional: boolean;
+} | {
+  kind: "rest";
+  arg: ParamDef;
+});
 
-export interface JsDocTagDocRequired extends JsDocTagBase {
-  kind: "category" | "example" | "see";
-  doc: string;
-}
+export type ObjectPatPropDef = {
+  kind: "assign";
+  key: string;
+  value?: string | null;
+} | {
+  kind: "keyValue";
+  key: string;
+  value: ParamDef;
+} | {
+  kind: "rest";
+  arg: ParamDef;
+};
 
-export interface JsDocTagNamed extends JsDocTagBase {
-  kind: "callback" | "template";
+export type DecoratorDef = {
   name: string;
-  doc?: string;
-}
-
-export interface JsDocTagValued extends JsDocTagBase {
-  kind: "default";
-  value: string;
-  doc?: string;
-}
+  args?: string[];
+  location: Location;
+};
 
-export interface JsDocTagTyped extends JsDocTagBase {
-  kind: "enum" | "extends" | "this" | "type";
-  type: string;
-  doc?: string;
-}
+export type TsTypeDef = {
+  repr: string;
+  kind?: TsTypeDefKind | null;
+  keyword?: string | null;
+  literal?: LiteralDef | null;
+  typeRef?: TsTypeRefDef | null;
+  union?: TsTypeDef[] | null;
+  intersection?: TsTypeDef[] | null;
+  array?: TsTypeDef | null;
+  tuple?: TsTypeDef[] | null;
+  typeOperator?: TsTypeOperatorDef | null;
+  parenthesized?: TsTypeDef | null;
+  rest?: TsTypeDef | null;
+  optional?: TsTypeDef | null;
+  typeQuery?: string | null;
+  this?: boolean | null;
+  fnOrConstructor?: TsFnOrConstructorDef | null;
+  conditionalType?: TsConditionalDef | null;
+  infer?: TsInferDef | null;
+  indexedAccess?: TsIndexedAccessDef | null;
+  mappedType?: TsMappedTypeDef | null;
+  typeLiteral?: TsTypeLiteralDef | null;
+  typePredicate?: TsTypePredicateDef | null;
+  importType?: TsImportTypeDef | null;
+};
 
-export interface JsDocTagNamedTyped extends JsDocTagBase {
-  kind: "property" | "typedef";
-  name: string;
-  type: string;
-  doc?: string;
-}
+export type TsTypeDefKind = "keyword" | "literal" | "typeRef" | "union" | "intersection" | "array" | "tuple" | "typeOperator" | "parenthesized" | "rest" | "optional" | "typeQuery" | "this" | "fnOrConstructor" | "conditional" | "infer" | "indexedAccess" | "mapped" | "typeLiteral" | "typePredicate" | "importType";
 
-export interface JsDocTagParam extends JsDocTagBase {
-  kind: "param";
-  name: string;
-  type?: string;
-  optional?: true;
-  default?: string;
-  doc?: string;
-}
+export type LiteralDef = {
+  kind: LiteralDefKind;
+  number?: number | null;
+  string?: string | null;
+  tsTypes?: TsTypeDef[] | null;
+  boolean?: boolean | null;
+};
 
-export interface JsDocTagReturn extends JsDocTagBase {
-  kind: "return";
-  type?: string;
-  doc?: string;
-}
+export type LiteralDefKind = "number" | "string" | "template" | "boolean" | "bigInt";
 
-export interface JsDocTagTags extends JsDocTagBase {
-  kind: "tags";
-  tags: string[];
-}
+export type TsTypeRefDef = {
+  typeParams?: TsTypeDef[] | null;
+  typeName: string;
+};
 
-export interface JsDocTagUnsupported extends JsDocTagBase {
-  kind: "unsupported";
-  value: string;
-}
+export type TsTypeOperatorDef = {
+  operator: string;
+  tsType: TsTypeDef;
+};
 
-export interface LiteralCallSignatureDef {
+export type TsFnOrConstructorDef = {
+  constructor: boolean;
+  tsType: TsTypeDef;
   params: ParamDef[];
-  tsType?: TsTypeDef;
   typeParams: TsTypeParamDef[];
-}
-
-export type LiteralDefKind =
-  | "number"
-  | "string"
-  | "template"
-  | "boolean"
-  | "bigInt";
-
-export type LiteralDef =
-  | LiteralDefNumber
-  | LiteralDefBigInt
-  | LiteralDefString
-  | LiteralDefTemplate
-  | LiteralDefBoolean;
-
-interface LiteralDefBase {
-  kind: LiteralDefKind;
-}
+};
 
-export interface LiteralDefNumber extends LiteralDefBase {
-  kind: "number";
-  number: number;
-}
+export type TsTypeParamDef = {
+  name: string;
+  constraint?: TsTypeDef | null;
+  default?: TsTypeDef | null;
+};
 
-export interface LiteralDefBigInt extends LiteralDefBase {
-  kind: "bigInt";
-  string: string;
-}
+export type TsConditionalDef = {
+  checkType: TsTypeDef;
+  extendsType: TsTypeDef;
+  trueType: TsTypeDef;
+  falseType: TsTypeDef;
+};
 
-export interface LiteralDefString extends LiteralDefBase {
-  kind: "string";
-  string: string;
-}
+export type TsInferDef = {
+  typeParam: TsTypeParamDef;
+};
 
-export interface LiteralDefTemplate extends LiteralDefBase {
-  kind: "template";
-  tsTypes: TsTypeDef[];
-}
+export type TsIndexedAccessDef = {
+  readonly: boolean;
+  objType: TsTypeDef;
+  indexType: TsTypeDef;
+};
+
+/**
+ * Mapped Types
+ *
+ * ```ts
+ * readonly [Properties in keyof Type as NewType]: Type[Properties]
+ * ```
+ *
+ * - `readonly` = `TruePlusMinus::True`
+ * - `type_param` = `Some(TsTypeParamDef)` (`Properties in keyof Type`)
+ * - `name_type` = `Some(TsTypeDef)` (`NewType`)
+ * - `optional` = `None`
+ * - `ts_type` = `Some(TsTypeDef)` (`Type[Properties]`)
+ */
+export type TsMappedTypeDef = {
+  readonly?: true | "+" | "-" | null;
+  typeParam: TsTypeParamDef;
+  nameType?: TsTypeDef | null;
+  optional?: true | "+" | "-" | null;
+  tsType?: TsTypeDef | null;
+};
 
-export interface LiteralDefBoolean extends LiteralDefBase {
-  kind: "boolean";
-  boolean: boolean;
-}
+export type TsTypeLiteralDef = {
+  constructors?: ConstructorDef[];
+  methods: MethodDef[];
+  properties: PropertyDef[];
+  callSignatures: CallSignatureDef[];
+  indexSignatures: IndexSignatureDef[];
+};
 
-export interface LiteralIndexSignatureDef {
-  readonly: boolean;
+export type ConstructorDef = {
+  jsDoc?: JsDoc;
   params: ParamDef[];
-  tsType?: TsTypeDef;
-}
+  returnType?: TsTypeDef | null;
+  typeParams: TsTypeParamDef[];
+  location: Location;
+};
 
-export interface LiteralMethodDef {
+export type MethodDef = {
   name: string;
-  kind: MethodKind;
+  jsDoc?: JsDoc;
+  kind: "method" | "getter" | "setter";
+  location?: Location;
   params: ParamDef[];
   computed?: boolean;
   optional: boolean;
-  returnType?: TsTypeDef;
+  returnType?: TsTypeDef | null;
   typeParams: TsTypeParamDef[];
-}
+};
 
-export interface LiteralPropertyDef {
+export type PropertyDef = {
   name: string;
+  jsDoc?: JsDoc;
+  location?: Location;
   params: ParamDef[];
   readonly?: boolean;
   computed: boolean;
   optional: boolean;
-  tsType?: TsTypeDef;
+  tsType?: TsTypeDef | null;
   typeParams: TsTypeParamDef[];
-}
+};
 
-export interface Location {
-  filename: string;
-  line: number;
-  col: number;
-}
+export type CallSignatureDef = {
+  jsDoc?: JsDoc;
+  location?: Location;
+  params: ParamDef[];
+  tsType?: TsTypeDef | null;
+  typeParams: TsTypeParamDef[];
+};
 
-export type MethodKind = "method" | "getter" | "setter";
+export type IndexSignatureDef = {
+  jsDoc?: JsDoc;
+  readonly: boolean;
+  params: ParamDef[];
+  tsType?: TsTypeDef | null;
+  location?: Location;
+};
 
-export interface NamespaceDef {
-  elements: DocNode[];
-}
+/**
+ * ```ts
+ * function foo(param: any): asserts param is SomeType { ... }
+ *                           ^^^^^^^ ^^^^^    ^^^^^^^^
+ *                           (1)     (2)      (3)
+ * ```
+ */
+export type TsTypePredicateDef = {
+  /** (1) Whether the predicate includes `asserts` keyword or not */
+  asserts: boolean;
+  /** (2) The term of predicate */
+  param: ThisOrIdent;
+  /** (3) The type against which the parameter is checked */
+  type?: TsTypeDef | null;
+};
 
-export type ObjectPatPropDef =
-  | ObjectPatPropAssignDef
-  | ObjectPatPropKeyValueDef
-  | ObjectPatPropRestDef;
+export type ThisOrIdent = {
+  type: "this";
+} | {
+  type: "identifier";
+  name: string;
+};
 
-export interface ObjectPatPropAssignDef {
-  kind: "assign";
-  key: string;
-  value?: string;
-}
+export type TsImportTypeDef = {
+  specifier: string;
+  qualifier?: string | null;
+  typeParams?: TsTypeDef[] | null;
+};
 
-export interface ObjectPatPropKeyValueDef {
-  kind: "keyValue";
-  key: string;
-  value: ParamDef;
-}
+export type VariableDef = {
+  tsType?: TsTypeDef | null;
+  kind: "var" | "let" | "const";
+  /**
+   * A preview of the value, truncated, for the variables documenting JSON
+   * modules.
+   */
+  valuePreview?: string | null;
+};
 
-export interface ObjectPatPropRestDef {
-  kind: "rest";
-  arg: ParamDef;
-}
+export type EnumDef = {
+  members: EnumMemberDef[];
+};
 
-export type ParamDef =
-  | ParamArrayDef
-  | ParamAssignDef
-  | ParamIdentifierDef
-  | ParamObjectDef
-  | ParamRestDef;
+export type EnumMemberDef = {
+  name: string;
+  init?: TsTypeDef | null;
+  jsDoc?: JsDoc;
+  location: Location;
+};
 
-export interface ParamArrayDef {
-  kind: "array";
-  elements: (ParamDef | undefined)[];
-  optional: boolean;
+export type ClassDef = {
+  /** set when the class is a default export and has a name in its declaration */
+  defName?: string | null;
+  isAbstract: boolean;
+  constructors: ClassConstructorDef[];
+  properties: ClassPropertyDef[];
+  indexSignatures: IndexSignatureDef[];
+  methods: ClassMethodDef[];
+  extends?: string | null;
+  implements: TsTypeDef[];
+  typeParams: TsTypeParamDef[];
+  superTypeParams: TsTypeDef[];
   decorators?: DecoratorDef[];
-  tsType?: TsTypeDef;
-}
+};
 
-export interface ParamAssignDef {
+export type ClassConstructorDef = {
+  jsDoc?: JsDoc;
+  accessibility: "public" | "protected" | "private" | null;
+  isOptional?: boolean;
+  hasBody?: boolean;
+  name: string;
+  params: ClassConstructorParamDef[];
+  location: Location;
+};
+
+export type ClassConstructorParamDef = {
+  accessibility?: "public" | "protected" | "private" | null;
+  isOverride?: boolean;
+  decorators?: DecoratorDef[];
+  tsType?: TsTypeDef | null;
+  readonly?: boolean;
+} & ({
+  kind: "array";
+  elements: (ParamDef | null)[];
+  optional: boolean;
+} | {
   kind: "assign";
   left: ParamDef;
   right: string;
-  decorators?: DecoratorDef[];
-  tsType?: TsTypeDef;
-}
-
-export interface ParamIdentifierDef {
+} | {
   kind: "identifier";
   name: string;
   optional: boolean;
-  decorators?: DecoratorDef[];
-  tsType?: TsTypeDef;
-}
-
-export interface ParamObjectDef {
+} | {
   kind: "object";
   props: ObjectPatPropDef[];
   optional: boolean;
-  decorators?: DecoratorDef[];
-  tsType?: TsTypeDef;
-}
-
-export interface ParamRestDef {
+} | {
   kind: "rest";
   arg: ParamDef;
-  decorators?: DecoratorDef[];
-  tsType?: TsTypeDef;
-}
-
-export type TruePlusMinus = true | "+" | "-";
-
-export interface TsConditionalDef {
-  checkType: TsTypeDef;
-  extendsType: TsTypeDef;
-  trueType: TsTypeDef;
-  falseType: TsTypeDef;
-}
-
-export interface TsFnOrConstructorDef {
-  constructor: boolean;
-  tsType: TsTypeDef;
-  params: ParamDef[];
-  typeParams: TsTypeParamDef[];
-}
-
-export interface TsImportTypeDef {
-  specifier: string;
-  qualifier?: string;
-  typeParams?: TsTypeDef[];
-}
+});
 
-export interface TsIndexedAccessDef {
+export type ClassPropertyDef = {
+  jsDoc?: JsDoc;
+  tsType?: TsTypeDef | null;
   readonly: boolean;
-  objType: TsTypeDef;
-  indexType: TsTypeDef;
-}
-
-export interface TsInferDef {
-  typeParam: TsTypeParamDef;
-}
-
-export interface TsMappedTypeDef {
-  readonly?: TruePlusMinus;
-  typeParam: TsTypeParamDef;
-  nameType?: TsTypeDef;
-  optional?: TruePlusMinus;
-  tsType?: TsTypeDef;
-}
-
-export interface TsTypeLiteralDef {
-  methods: LiteralMethodDef[];
-  properties: LiteralPropertyDef[];
-  callSignatures: LiteralCallSignatureDef[];
-  indexSignatures: LiteralIndexSignatureDef[];
-}
-
-export interface TsTypeOperatorDef {
-  operator: string;
-  tsType: TsTypeDef;
-}
-
-export interface TsTypeParamDef {
+  accessibility: "public" | "protected" | "private" | null;
+  decorators?: DecoratorDef[];
+  optional: boolean;
+  isAbstract: boolean;
+  isStatic: boolean;
+  isOverride?: boolean;
   name: string;
-  constraint?: TsTypeDef;
-  default?: TsTypeDef;
-}
-
-export interface TsTypePredicateDef {
-  asserts: boolean;
-  param: { type: "this" | "identifier"; name?: string };
-  type?: TsTypeDef;
-}
-
-export type TsTypeDef =
-  | TsTypeKeywordDef
-  | TsTypeDefLiteral
-  | TsTypeTypeRefDef
-  | TsTypeUnionDef
-  | TsTypeIntersectionDef
-  | TsTypeArrayDef
-  | TsTypeTupleDef
-  | TsTypeTypeOperatorDef
-  | TsTypeParenthesizedDef
-  | TsTypeRestDef
-  | TsTypeOptionalDef
-  | TsTypeQueryDef
-  | TsTypeThisDef
-  | TsTypeFnOrConstructorDef
-  | TsTypeConditionalDef
-  | TsTypeImportTypeDef
-  | TsTypeInferDef
-  | TsTypeIndexedAccessDef
-  | TsTypeMappedDef
-  | TsTypeTypeLiteralDef
-  | TsTypeTypePredicateDef;
-
-interface TsTypeDefBase {
-  repr: string;
-  kind: TsTypeDefKind;
-}
-
-export interface TsTypeKeywordDef extends TsTypeDefBase {
-  kind: "keyword";
-  keyword: string;
-}
-
-export interface TsTypeDefLiteral extends TsTypeDefBase {
-  kind: "literal";
-  literal: LiteralDef;
-}
-
-export interface TsTypeTypeRefDef extends TsTypeDefBase {
-  kind: "typeRef";
-  typeRef: TsTypeRefDef;
-}
-
-export interface TsTypeUnionDef extends TsTypeDefBase {
-  kind: "union";
-  union: TsTypeDef[];
-}
-
-export interface TsTypeIntersectionDef extends TsTypeDefBase {
-  kind: "intersection";
-  intersection: TsTypeDef[];
-}
-
-export interface TsTypeArrayDef extends TsTypeDefBase {
-  kind: "array";
-  array: TsTypeDef;
-}
-
-export interface TsTypeTupleDef extends TsTypeDefBase {
-  kind: "tuple";
-  tuple: TsTypeDef[];
-}
-
-export interface TsTypeTypeOperatorDef extends TsTypeDefBase {
-  kind: "typeOperator";
-  typeOperator: TsTypeOperatorDef;
-}
-
-export interface TsTypeParenthesizedDef extends TsTypeDefBase {
-  kind: "parenthesized";
-  parenthesized: TsTypeDef;
-}
-
-export interface TsTypeRestDef extends TsTypeDefBase {
-  kind: "rest";
-  rest: TsTypeDef;
-}
+  location: Location;
+};
 
-export interface TsTypeOptionalDef extends TsTypeDefBase {
-  kind: "optional";
-  optional: TsTypeDef;
-}
+export type ClassMethodDef = {
+  jsDoc?: JsDoc;
+  accessibility: "public" | "protected" | "private" | null;
+  optional: boolean;
+  isAbstract: boolean;
+  isStatic: boolean;
+  isOverride?: boolean;
+  name: string;
+  kind: "method" | "getter" | "setter";
+  functionDef: FunctionDef;
+  location: Location;
+};
 
-export interface TsTypeQueryDef extends TsTypeDefBase {
-  kind: "typeQuery";
-  typeQuery: string;
-}
+export type TypeAliasDef = {
+  tsType: TsTypeDef;
+  typeParams: TsTypeParamDef[];
+};
 
-export interface TsTypeThisDef extends TsTypeDefBase {
-  kind: "this";
-  this: boolean;
-}
-
-export interface TsTypeFnOrConstructorDef extends TsTypeDefBase {
-  kind: "fnOrConstructor";
-  fnOrConstructor: TsFnOrConstructorDef;
-}
-
-export interface TsTypeConditionalDef extends TsTypeDefBase {
-  kind: "conditional";
-  conditionalType: TsConditionalDef;
-}
-
-export interface TsTypeInferDef extends TsTypeDefBase {
-  kind: "infer";
-  infer: TsInferDef;
-}
-
-export interface TsTypeMappedDef extends TsTypeDefBase {
-  kind: "mapped";
-  mappedType: TsMappedTypeDef;
-}
-
-export interface TsTypeImportTypeDef extends TsTypeDefBase {
-  kind: "importType";
-  importType: TsImportTypeDef;
-}
-
-export interface TsTypeIndexedAccessDef extends TsTypeDefBase {
-  kind: "indexedAccess";
-  indexedAccess: TsIndexedAccessDef;
-}
-
-export interface TsTypeTypeLiteralDef extends TsTypeDefBase {
-  kind: "typeLiteral";
-  typeLiteral: TsTypeLiteralDef;
-}
-
-export interface TsTypeTypePredicateDef extends TsTypeDefBase {
-  kind: "typePredicate";
-  typePredicate: TsTypePredicateDef;
-}
-
-export type TsTypeDefKind =
-  | "keyword"
-  | "literal"
-  | "typeRef"
-  | "union"
-  | "intersection"
-  | "array"
-  | "tuple"
-  | "typeOperator"
-  | "parenthesized"
-  | "rest"
-  | "optional"
-  | "typeQuery"
-  | "this"
-  | "fnOrConstructor"
-  | "conditional"
-  | "importType"
-  | "infer"
-  | "indexedAccess"
-  | "mapped"
-  | "typeLiteral"
-  | "typePredicate";
-
-export interface TsTypeRefDef {
-  typeParams?: TsTypeDef[];
-  typeName: string;
-}
+export type NamespaceDef = {
+  elements: DocNode[];
+};
 
-export interface TypeAliasDef {
-  tsType: TsTypeDef;
+export type InterfaceDef = {
+  /** set when the interface is a default export */
+  defName?: string | null;
+  extends: TsTypeDef[];
+  constructors?: ConstructorDef[];
+  methods: MethodDef[];
+  properties: PropertyDef[];
+  callSignatures: CallSignatureDef[];
+  indexSignatures: IndexSignatureDef[];
   typeParams: TsTypeParamDef[];
-}
+};
 
-export type VariableDeclKind = "var" | "let" | "const";
+export type ImportDef = {
+  src: string;
+  imported?: string | null;
+};
+
+export type ReferenceDef = {
+  target: Location;
+};
 
-export interface VariableDef {
-  tsType?: TsTypeDef;
-  kind: VariableDeclKind;
-}
+// The variants of the unions above, and the names of the types which are
+// shared between classes, interfaces and type literals.
+export type DocNodeKind = DocNode["kind"];
+export type DocNodeModuleDoc = Extract<DocNode, { kind: "moduleDoc" }>;
+export type DocNodeFunction = Extract<DocNode, { kind: "function" }>;
+export type DocNodeVariable = Extract<DocNode, { kind: "variable" }>;
+export type DocNodeEnum = Extract<DocNode, { kind: "enum" }>;
+export type DocNodeClass = Extract<DocNode, { kind: "class" }>;
+export type DocNodeTypeAlias = Extract<DocNode, { kind: "typeAlias" }>;
+export type DocNodeNamespace = Extract<DocNode, { kind: "namespace" }>;
+export type DocNodeInterface = Extract<DocNode, { kind: "interface" }>;
+export type DocNodeImport = Extract<DocNode, { kind: "import" }>;
+export type DocNodeReference = Extract<DocNode, { kind: "reference" }>;
+export type Accessibility = NonNullable<ClassMethodDef["accessibility"]>;
+export type MethodKind = MethodDef["kind"];
+export type VariableDeclKind = VariableDef["kind"];
+export type TruePlusMinus = NonNullable<TsMappedTypeDef["readonly"]>;
+export type ClassIndexSignatureDef = IndexSignatureDef;
+export type InterfaceCallSignatureDef = CallSignatureDef;
+export type InterfaceIndexSignatureDef = IndexSignatureDef;
+export type InterfaceMethodDef = MethodDef;
+export type InterfacePropertyDef = PropertyDef;
+export type LiteralCallSignatureDef = CallSignatureDef;
+export type LiteralIndexSignatureDef = IndexSignatureDef;
+export type LiteralMethodDef = MethodDef;
+export type LiteralPropertyDef = PropertyDef;
+export type JsDocTagKind = JsDocTag["kind"];
+export type JsDocTagBase = { kind: JsDocTagKind };
+export type JsDocTagOnly = Extract<JsDocTag, { kind: "constructor" | "experimental" | "ignore" | "internal" | "module" | "public" | "private" | "protected" | "readonly" }>;
+export type JsDocTagDoc = Extract<JsDocTag, { kind: "deprecated" }>;
+export type JsDocTagDocRequired = Extract<JsDocTag, { kind: "category" | "example" | "see" | "since" }>;
+export type JsDocTagNamed = Extract<JsDocTag, { kind: "callback" | "template" }>;
+export type JsDocTagValued = Extract<JsDocTag, { kind: "default" }>;
+export type JsDocTagTyped = Extract<JsDocTag, { kind: "enum" | "extends" | "this" | "type" }>;
+export type JsDocTagNamedTyped = Extract<JsDocTag, { kind: "property" | "typedef" }>;
+export type JsDocTagParam = Extract<JsDocTag, { kind: "param" }>;
+export type JsDocTagReturn = Extract<JsDocTag, { kind: "return" }>;
+export type JsDocTagTags = Extract<JsDocTag, { kind: "tags" }>;
+export type JsDocTagUnsupported = Extract<JsDocTag, { kind: "unsupported" }>;
+export type LiteralDefNumber = LiteralDef & { kind: "number"; number: number };
+export type LiteralDefBigInt = LiteralDef & { kind: "bigInt"; string: string };
+export type LiteralDefString = LiteralDef & { kind: "string"; string: string };
+export type LiteralDefTemplate = LiteralDef & { kind: "template"; tsTypes: TsTypeDef[] };
+export type LiteralDefBoolean = LiteralDef & { kind: "boolean"; boolean: boolean };
+export type ParamArrayDef = Extract<ParamDef, { kind: "array" }>;
+export type ParamAssignDef = Extract<ParamDef, { kind: "assign" }>;
+export type ParamIdentifierDef = Extract<ParamDef, { kind: "identifier" }>;
+export type ParamObjectDef = Extract<ParamDef, { kind: "object" }>;
+export type ParamRestDef = Extract<ParamDef, { kind: "rest" }>;
+export type ObjectPatPropAssignDef = Extract<ObjectPatPropDef, { kind: "assign" }>;
+export type ObjectPatPropKeyValueDef = Extract<ObjectPatPropDef, { kind: "keyValue" }>;
+export type ObjectPatPropRestDef = Extract<ObjectPatPropDef, { kind: "rest" }>;
+export type TsTypeKeywordDef = TsTypeDef & { kind: "keyword"; keyword: string };
+export type TsTypeDefLiteral = TsTypeDef & { kind: "literal"; literal: LiteralDef };
+export type TsTypeTypeRefDef = TsTypeDef & { kind: "typeRef"; typeRef: TsTypeRefDef };
+export type TsTypeUnionDef = TsTypeDef & { kind: "union"; union: TsTypeDef[] };
+export type TsTypeIntersectionDef = TsTypeDef & { kind: "intersection"; intersection: TsTypeDef[] };
+export type TsTypeArrayDef = TsTypeDef & { kind: "array"; array: TsTypeDef };
+export type TsTypeTupleDef = TsTypeDef & { kind: "tuple"; tuple: TsTypeDef[] };
+export type TsTypeTypeOperatorDef = TsTypeDef & { kind: "typeOperator"; typeOperator: TsTypeOperatorDef };
+export type TsTypeParenthesizedDef = TsTypeDef & { kind: "parenthesized"; parenthesized: TsTypeDef };
+export type TsTypeRestDef = TsTypeDef & { kind: "rest"; rest: TsTypeDef };
+export type TsTypeOptionalDef = TsTypeDef & { kind: "optional"; optional: TsTypeDef };
+export type TsTypeQueryDef = TsTypeDef & { kind: "typeQuery"; typeQuery: string };
+export type TsTypeThisDef = TsTypeDef & { kind: "this"; this: boolean };
+export type TsTypeFnOrConstructorDef = TsTypeDef & { kind: "fnOrConstructor"; fnOrConstructor: TsFnOrConstructorDef };
+export type TsTypeConditionalDef = TsTypeDef & { kind: "conditional"; conditionalType: TsConditionalDef };
+export type TsTypeInferDef = TsTypeDef & { kind: "infer"; infer: TsInferDef };
+export type TsTypeIndexedAccessDef = TsTypeDef & { kind: "indexedAccess"; indexedAccess: TsIndexedAccessDef };
+export type TsTypeMappedDef = TsTypeDef & { kind: "mapped"; mappedType: TsMappedTypeDef };
+export type TsTypeTypeLiteralDef = TsTypeDef & { kind: "typeLiteral"; typeLiteral: TsTypeLiteralDef };
+export type TsTypeTypePredicateDef = TsTypeDef & { kind: "typePredicate"; typePredicate: TsTypePredicateDef };
+export type TsTypeImportTypeDef = TsTypeDef & { kind: "importType"; importType: TsImportTypeDef };
@@ -261,6 +261,7 @@ async fn inner_doc(
     deno_doc::DocParserOptions {
       diagnostics: false,
       private: include_all,
+      cache: None,
     },
   )?
   .parse()?;
@@ -269,6 +270,71 @@ async fn inner_doc(
   Ok(entries.serialize(&serializer).unwrap())
 }
 
+#[wasm_bindgen]
+pub async fn doc_sources(
+  root_specifiers: Vec<String>,
+  sources: JsValue,
+  include_all: bool,
+) -> anyhow::Result<JsValue, JsValue> {
+  console_error_panic_hook::set_once();
+  inner_doc_sources(root_specifiers, sources, include_all)
+    .await
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
+}
+
+async fn inner_doc_sources(
+  root_specifiers: Vec<String>,
+  sources: JsValue,
+  include_all: bool,
+) -> Result<JsValue, anyhow::Error> {
+  let root_specifiers = root_specifiers
+    .into_iter()
+    .map(|root_specifier| ModuleSpecifier::parse(&root_specifier))
+    .collect::<Result<Vec<_>, _>>()?;
+  let sources = serde_wasm_bindgen::from_value::<
+    IndexMap<ModuleSpecifier, String>,
+  >(sources)
+  .map_err(|err| anyhow!("sources: {}", err))?;
+  let entries = deno_doc::parse_sources(
+    &root_specifiers,
+    sources,
+    deno_doc::DocParserOptions {
+      diagnostics: false,
+      private: include_all,
+      cache: None,
+    },
+  )
+  .await?;
+  let serializer =
+    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+  Ok(entries.serialize(&serializer).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn select_nodes(
+  doc_nodes: JsValue,
+  query: String,
+) -> Result<JsValue, JsValue> {
+  console_error_panic_hook::set_once();
+  select_nodes_inner(doc_nodes, query)
+    .map_err(|err| JsValue::from(js_sys::Error::new(&err.to_string())))
+}
+
+fn select_nodes_inner(
+  doc_nodes: JsValue,
+  query: String,
+) -> Result<JsValue, anyhow::Error> {
+  let doc_nodes: Vec<deno_doc::DocNode> =
+    serde_wasm_bindgen::from_value(doc_nodes)
+      .map_err(|err| anyhow!("docNodes: {}", err))?;
+  let matches = deno_doc::query::select_nodes(&doc_nodes, &query)?;
+  let serializer =
+    serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+  matches
+    .serialize(&serializer)
+    .map_err(|err| anyhow!("{}", err))
+}
+
 #[allow(clippy::too_many_arguments)]
 #[wasm_bindgen]
 pub fn generate_html(
@@ -294,6 +360,15 @@ pub fn generate_html(
   markdown_stripper: js_sys::Function,
   head_inject: Option<js_sys::Function>,
   id_prefix: Option<String>,
+  template_overrides: JsValue,
+  messages: JsValue,
+  versions: JsValue,
+  source_files: JsValue,
+  class_hierarchy: bool,
+  full_text_search: bool,
+  base_url: Option<String>,
+  inventories: JsValue,
+  expand_types: bool,
 
   doc_nodes_by_url: JsValue,
 
@@ -320,6 +395,15 @@ pub fn generate_html(
     markdown_stripper,
     head_inject,
     id_prefix,
+    template_overrides,
+    messages,
+    versions,
+    source_files,
+    class_hierarchy,
+    full_text_search,
+    base_url,
+    inventories,
+    expand_types,
     doc_nodes_by_url,
     json,
   )
@@ -332,6 +416,7 @@ struct JsHrefResolver {
   resolve_import_href: js_sys::Function,
   resolve_source: js_sys::Function,
   resolve_external_jsdoc_module: js_sys::Function,
+  lang: String,
 }
 
 impl deno_doc::html::HrefResolver for JsHrefResolver {
@@ -354,6 +439,7 @@ impl deno_doc::html::HrefResolver for JsHrefResolver {
         TARGET.set(target);
       }
 
+      let lang = self.lang.clone();
       let default_closure = Box::new(move || {
         CURRENT.with(|current| {
           let current_ptr = *current.borrow() as *const UrlResolveKind;
@@ -369,8 +455,11 @@ impl deno_doc::html::HrefResolver for JsHrefResolver {
             //  after compose is called
             let target_val = unsafe { &*target_ptr };
 
-            let path =
-              deno_doc::html::href_path_resolve(*current_val, *target_val);
+            let path = deno_doc::html::href_path_resolve_localized(
+              *current_val,
+              *target_val,
+              &lang,
+            );
 
             *target.borrow_mut() =
               target_val as *const UrlResolveKind as *const c_void;
@@ -411,7 +500,7 @@ impl deno_doc::html::HrefResolver for JsHrefResolver {
       serde_wasm_bindgen::from_value(global_symbol)
         .expect("resolve_path returned an invalid value")
     } else {
-      deno_doc::html::href_path_resolve(current, target)
+      deno_doc::html::href_path_resolve_localized(current, target, &self.lang)
     }
   }
 
@@ -536,6 +625,15 @@ fn generate_html_inner(
   markdown_stripper: js_sys::Function,
   head_inject: Option<js_sys::Function>,
   id_prefix: Option<String>,
+  template_overrides: JsValue,
+  messages: JsValue,
+  versions: JsValue,
+  source_files: JsValue,
+  class_hierarchy: bool,
+  full_text_search: bool,
+  base_url: Option<String>,
+  inventories: JsValue,
+  expand_types: bool,
 
   doc_nodes_by_url: JsValue,
 
@@ -566,6 +664,50 @@ fn generate_html_inner(
   >(default_symbol_map)
   .map_err(|err| anyhow!("defaultSymbolMap: {}", err))?;
 
+  #[derive(serde::Deserialize)]
+  struct JsTemplateOverrides {
+    #[serde(default)]
+    templates: IndexMap<String, String>,
+    #[serde(default)]
+    partials: IndexMap<String, String>,
+  }
+
+  let template_overrides = serde_wasm_bindgen::from_value::<
+    Option<JsTemplateOverrides>,
+  >(template_overrides)
+  .map_err(|err| anyhow!("templateOverrides: {}", err))?
+  .map(|overrides| deno_doc::html::TemplateOverrides {
+    templates: overrides.templates,
+    partials: overrides.partials,
+    helpers: Default::default(),
+  });
+
+  let messages = serde_wasm_bindgen::from_value::<
+    Option<deno_doc::html::Messages>,
+  >(messages)
+  .map_err(|err| anyhow!("messages: {}", err))?;
+
+  let versions = serde_wasm_bindgen::from_value::<
+    Option<deno_doc::html::VersionOptions>,
+  >(versions)
+  .map_err(|err| anyhow!("versions: {}", err))?;
+
+  let inventories = serde_wasm_bindgen::from_value::<
+    Option<IndexMap<String, deno_doc::html::Inventory>>,
+  >(inventories)
+  .map_err(|err| anyhow!("inventories: {}", err))?;
+
+  let source_files = serde_wasm_bindgen::from_value::<
+    Option<IndexMap<ModuleSpecifier, String>>,
+  >(source_files)
+  .map_err(|err| anyhow!("sourceFiles: {}", err))?
+  .map(|source_files| {
+    source_files
+      .into_iter()
+      .map(|(specifier, text)| (specifier, text.into()))
+      .collect()
+  });
+
   let doc_nodes_by_url: IndexMap<ModuleSpecifier, Vec<deno_doc::DocNode>> =
     serde_wasm_bindgen::from_value(doc_nodes_by_url)
       .map_err(|err| anyhow!("docNodesByUrl: {}", err))?;
@@ -633,6 +775,10 @@ fn generate_html_inner(
         resolve_import_href,
         resolve_source,
         resolve_external_jsdoc_module,
+        lang: messages
+          .as_ref()
+          .map(|messages| messages.lang.clone())
+          .unwrap_or_else(|| deno_doc::html::Messages::default().lang),
       }),
       usage_composer: Rc::new(JsUsageComposer {
         single_mode: usage_composer_single_mode,
@@ -647,6 +793,15 @@ fn generate_html_inner(
       markdown_stripper,
       head_inject,
       id_prefix,
+      template_overrides,
+      messages,
+      versions,
+      source_files,
+      class_hierarchy,
+      full_text_search,
+      base_url,
+      inventories,
+      expand_types,
     },
     doc_nodes_by_url,
   )?;
@@ -0,0 +1,251 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use deno_terminal::colors;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use crate::Location;
+use crate::class::ClassMethodDef;
+use crate::decorators::DecoratorDef;
+use crate::display::display_abstract;
+use crate::display::display_accessibility;
+use crate::display::display_computed;
+use crate::display::display_override;
+use crate::display::display_readonly;
+use crate::display::display_static;
+use crate::display::display_writeonly;
+use crate::js_doc::JsDoc;
+use crate::ts_type::MethodDef;
+use crate::ts_type::TsTypeDef;
+
+/// A getter and a setter of the same name merged into a single property.
+///
+/// Getters and setters are kept as separate methods in [`ClassDef`],
+/// [`InterfaceDef`] and [`TsTypeLiteralDef`]; this is a view of them as the
+/// property they define, built by their `accessors` methods.
+///
+/// [`ClassDef`]: crate::class::ClassDef
+/// [`InterfaceDef`]: crate::interface::InterfaceDef
+/// [`TsTypeLiteralDef`]: crate::ts_type::TsTypeLiteralDef
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessorDef {
+  pub name: Box<str>,
+  /// The documentation of the getter followed by the one of the setter.
+  pub js_doc: JsDoc,
+  pub has_getter: bool,
+  pub has_setter: bool,
+  /// The return type of the getter.
+  pub getter_type: Option<TsTypeDef>,
+  /// The type of the parameter of the setter.
+  pub setter_type: Option<TsTypeDef>,
+  pub computed: bool,
+  pub accessibility: Option<Accessibility>,
+  pub decorators: Box<[DecoratorDef]>,
+  pub is_abstract: bool,
+  pub is_static: bool,
+  pub is_override: bool,
+  /// The location of the getter if any, otherwise of the setter.
+  pub location: Location,
+}
+
+impl AccessorDef {
+  /// Whether the property only has a getter.
+  pub fn is_readonly(&self) -> bool {
+    !self.has_setter
+  }
+
+  /// Whether the property only has a setter.
+  pub fn is_writeonly(&self) -> bool {
+    !self.has_getter
+  }
+
+  /// The type of the property, which is the type returned by the getter if
+  /// any, otherwise the type accepted by the setter.
+  pub fn ts_type(&self) -> Option<&TsTypeDef> {
+    self.getter_type.as_ref().or(self.setter_type.as_ref())
+  }
+
+  /// Whether the setter accepts a different type than the getter returns.
+  pub fn has_divergent_types(&self) -> bool {
+    match (&self.getter_type, &self.setter_type) {
+      (Some(getter_type), Some(setter_type)) => getter_type != setter_type,
+      _ => false,
+    }
+  }
+
+  fn from_class_method(method: &ClassMethodDef) -> Self {
+    let is_getter = method.kind == MethodKind::Getter;
+    Self {
+      name: method.name.clone(),
+      js_doc: method.js_doc.clone(),
+      has_getter: is_getter,
+      has_setter: !is_getter,
+      getter_type: is_getter
+        .then(|| method.function_def.return_type.clone())
+        .flatten(),
+      setter_type: (!is_getter)
+        .then(|| setter_type(&method.function_def.params))
+        .flatten(),
+      computed: false,
+      accessibility: method.accessibility,
+      decorators: method.function_def.decorators.clone(),
+      is_abstract: method.is_abstract,
+      is_static: method.is_static,
+      is_override: method.is_override,
+      location: method.location.clone(),
+    }
+  }
+
+  fn from_method(method: &MethodDef) -> Self {
+    let is_getter = method.kind == MethodKind::Getter;
+    Self {
+      name: method.name.as_str().into(),
+      js_doc: method.js_doc.clone(),
+      has_getter: is_getter,
+      has_setter: !is_getter,
+      getter_type: is_getter.then(|| method.return_type.clone()).flatten(),
+      setter_type: (!is_getter).then(|| setter_type(&method.params)).flatten(),
+      computed: method.computed,
+      accessibility: None,
+      decorators: Box::new([]),
+      is_abstract: false,
+      is_static: false,
+      is_override: false,
+      location: method.location.clone(),
+    }
+  }
+
+  fn merge(getter: Self, setter: Self) -> Self {
+    Self {
+      js_doc: merge_js_docs(&getter.js_doc, &setter.js_doc),
+      has_setter: true,
+      setter_type: setter.setter_type,
+      decorators: [getter.decorators, setter.decorators].concat().into(),
+      is_abstract: getter.is_abstract || setter.is_abstract,
+      is_override: getter.is_override || setter.is_override,
+      ..getter
+    }
+  }
+}
+
+impl Display for AccessorDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(
+      f,
+      "{}{}{}{}{}{}{}",
+      display_abstract(self.is_abstract),
+      display_override(self.is_override),
+      display_accessibility(self.accessibility, false),
+      display_static(self.is_static),
+      display_readonly(self.is_readonly()),
+      display_writeonly(self.is_writeonly()),
+      display_computed(self.computed, &self.name),
+    )?;
+    if let Some(ts_type) = self.ts_type() {
+      write!(f, ": {}", ts_type)?;
+    }
+    if self.has_divergent_types() {
+      write!(
+        f,
+        " ({}: {})",
+        colors::magenta("set"),
+        self.setter_type.as_ref().unwrap()
+      )?;
+    }
+    Ok(())
+  }
+}
+
+fn setter_type(params: &[crate::ParamDef]) -> Option<TsTypeDef> {
+  params.first().and_then(|param| param.ts_type.clone())
+}
+
+/// Concatenates the documentation of a getter and of its setter, skipping the
+/// parts documented identically on both.
+fn merge_js_docs(getter: &JsDoc, setter: &JsDoc) -> JsDoc {
+  let doc = match (&getter.doc, &setter.doc) {
+    (Some(getter_doc), Some(setter_doc)) if getter_doc != setter_doc => {
+      Some(format!("{getter_doc}\n\n{setter_doc}").into_boxed_str())
+    }
+    (getter_doc, setter_doc) => getter_doc.clone().or(setter_doc.clone()),
+  };
+
+  let mut tags = getter.tags.to_vec();
+  for tag in setter.tags.iter() {
+    if !tags.contains(tag) {
+      tags.push(tag.clone());
+    }
+  }
+
+  JsDoc {
+    doc,
+    tags: tags.into_boxed_slice(),
+  }
+}
+
+/// Merges the getters and setters into accessors, in the order in which the
+/// first of each pair is declared.
+fn merge_accessors(
+  accessors: impl Iterator<Item = AccessorDef>,
+) -> Vec<AccessorDef> {
+  let mut pairs: Vec<(Option<AccessorDef>, Option<AccessorDef>)> = vec![];
+
+  for accessor in accessors {
+    let pair = pairs.iter_mut().find(|(getter, setter)| {
+      getter.as_ref().or(setter.as_ref()).is_some_and(|existing| {
+        existing.name == accessor.name
+          && existing.is_static == accessor.is_static
+      })
+    });
+    let pair = match pair {
+      Some(pair) => pair,
+      None => {
+        pairs.push((None, None));
+        pairs.last_mut().unwrap()
+      }
+    };
+
+    let slot = if accessor.has_getter {
+      &mut pair.0
+    } else {
+      &mut pair.1
+    };
+    // keep the first of the overloads
+    if slot.is_none() {
+      *slot = Some(accessor);
+    }
+  }
+
+  pairs
+    .into_iter()
+    .filter_map(|pair| match pair {
+      (Some(getter), Some(setter)) => Some(AccessorDef::merge(getter, setter)),
+      (getter, setter) => getter.or(setter),
+    })
+    .collect()
+}
+
+pub(crate) fn class_accessors(methods: &[ClassMethodDef]) -> Vec<AccessorDef> {
+  merge_accessors(
+    methods
+      .iter()
+      .filter(|method| is_accessor(method.kind))
+      .map(AccessorDef::from_class_method),
+  )
+}
+
+pub(crate) fn accessors(methods: &[MethodDef]) -> Vec<AccessorDef> {
+  merge_accessors(
+    methods
+      .iter()
+      .filter(|method| is_accessor(method.kind))
+      .map(AccessorDef::from_method),
+  )
+}
+
+pub(crate) fn is_accessor(kind: MethodKind) -> bool {
+  matches!(kind, MethodKind::Getter | MethodKind::Setter)
+}
@@ -0,0 +1,127 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_graph::CheckJsOption;
+use deno_graph::ModuleEntryRef;
+use deno_graph::ModuleGraph;
+use deno_graph::ModuleSpecifier;
+use deno_graph::WalkOptions;
+use serde::Deserialize;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::DocNode;
+use crate::util::hash::StableHasher;
+use crate::util::hash::stable_hash;
+
+/// The version of the layout of the cache, which is part of the names of the
+/// entries, and is bumped when the way they are keyed or stored changes.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// A persistent cache of the doc nodes extracted from each module, stored as
+/// one JSON file per module in a directory.
+///
+/// The entries are keyed by the module specifier and a hash of the sources of
+/// the module and of all its dependencies, so a module is only extracted
+/// again once it or anything it may re-export changes. Entries that can't be
+/// read or written are treated as misses.
+#[derive(Debug, Clone)]
+pub struct DocCache {
+  dir: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<N> {
+  version: String,
+  specifier: String,
+  hash: String,
+  nodes: N,
+}
+
+impl DocCache {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    Self { dir: dir.into() }
+  }
+
+  fn entry_path(&self, specifier: &ModuleSpecifier) -> PathBuf {
+    self.dir.join(format!(
+      "v{CACHE_FORMAT_VERSION}-{}.json",
+      stable_hash(specifier.as_str())
+    ))
+  }
+
+  pub(crate) fn get(
+    &self,
+    specifier: &ModuleSpecifier,
+    hash: &str,
+  ) -> Option<Vec<DocNode>> {
+    let content = std::fs::read(self.entry_path(specifier)).ok()?;
+    let entry =
+      serde_json::from_slice::<CacheEntry<Vec<DocNode>>>(&content).ok()?;
+
+    (entry.version == env!("CARGO_PKG_VERSION")
+      && entry.specifier == specifier.as_str()
+      && entry.hash == hash)
+      .then_some(entry.nodes)
+  }
+
+  pub(crate) fn set(
+    &self,
+    specifier: &ModuleSpecifier,
+    hash: &str,
+    nodes: &[DocNode],
+  ) {
+    let entry = CacheEntry {
+      version: env!("CARGO_PKG_VERSION").to_string(),
+      specifier: specifier.to_string(),
+      hash: hash.to_string(),
+      nodes,
+    };
+
+    if let Ok(content) = serde_json::to_vec(&entry) {
+      let _ = std::fs::create_dir_all(&self.dir)
+        .and_then(|_| std::fs::write(self.entry_path(specifier), content));
+    }
+  }
+}
+
+/// Hashes the sources of the module and of all the modules it depends on,
+/// together with the options that affect the extracted nodes.
+pub(crate) fn module_hash(
+  graph: &ModuleGraph,
+  specifier: &ModuleSpecifier,
+  private: bool,
+) -> String {
+  let mut hasher = StableHasher::default();
+  hasher.write(CACHE_FORMAT_VERSION.to_le_bytes());
+  hasher.write([private as u8]);
+
+  let modules = graph.walk(
+    std::iter::once(specifier),
+    WalkOptions {
+      check_js: CheckJsOption::True,
+      follow_dynamic: false,
+      kind: graph.graph_kind(),
+      prefer_fast_check_graph: false,
+    },
+  );
+  for (specifier, entry) in modules {
+    hasher.write(specifier.as_str());
+    match entry {
+      ModuleEntryRef::Module(module) => {
+        hasher.write("module");
+        hasher
+          .write(module.source().map(|source| source.as_ref()).unwrap_or(""));
+      }
+      ModuleEntryRef::Err(err) => {
+        hasher.write("err");
+        hasher.write(err.to_string());
+      }
+      ModuleEntryRef::Redirect(target) => {
+        hasher.write("redirect");
+        hasher.write(target.as_str());
+      }
+    }
+  }
+
+  hasher.finish()
+}
@@ -8,6 +8,7 @@ use serde::Serialize;
 use crate::DocNode;
 use crate::Location;
 use crate::ParamDef;
+use crate::accessor::AccessorDef;
 use crate::decorators::DecoratorDef;
 use crate::decorators::decorators_to_defs;
 use crate::function::FunctionDef;
@@ -51,9 +52,14 @@ cfg_if! {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClassConstructorParamDef {
   #[serde(skip_serializing_if = "Option::is_none", default)]
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::accessibility")
+  )]
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
   #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
@@ -78,10 +84,15 @@ impl Display for ClassConstructorParamDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClassConstructorDef {
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::accessibility")
+  )]
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
   #[serde(skip_serializing_if = "is_false", default)]
   pub is_optional: bool,
@@ -106,12 +117,17 @@ impl Display for ClassConstructorDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClassPropertyDef {
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
   pub ts_type: Option<TsTypeDef>,
   pub readonly: bool,
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::accessibility")
+  )]
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
   #[serde(skip_serializing_if = "<[_]>::is_empty", default)]
   pub decorators: Box<[DecoratorDef]>,
@@ -135,6 +151,7 @@ impl From<ClassPropertyDef> for DocNode {
       VariableDef {
         ts_type: def.ts_type,
         kind: deno_ast::swc::ast::VarDeclKind::Const,
+        value_preview: None,
       },
     )
   }
@@ -162,10 +179,15 @@ impl Display for ClassPropertyDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClassMethodDef {
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::accessibility")
+  )]
   pub accessibility: Option<deno_ast::swc::ast::Accessibility>,
   pub optional: bool,
   pub is_abstract: bool,
@@ -173,6 +195,10 @@ pub struct ClassMethodDef {
   #[serde(skip_serializing_if = "is_false", default)]
   pub is_override: bool,
   pub name: Box<str>,
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::method_kind")
+  )]
   pub kind: deno_ast::swc::ast::MethodKind,
   pub function_def: FunctionDef,
   pub location: Location,
@@ -216,6 +242,7 @@ impl Display for ClassMethodDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClassDef {
   #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -234,6 +261,13 @@ pub struct ClassDef {
   pub decorators: Box<[DecoratorDef]>,
 }
 
+impl ClassDef {
+  /// The getters and setters of the class, merged into properties.
+  pub fn accessors(&self) -> Vec<AccessorDef> {
+    crate::accessor::class_accessors(&self.methods)
+  }
+}
+
 pub fn class_to_class_def(
   module_info: &EsModuleInfo,
   class: &deno_ast::swc::ast::Class,
@@ -15,6 +15,7 @@ use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DecoratorDef {
   pub name: String,
@@ -52,25 +52,52 @@ pub(crate) fn display_readonly(is_readonly: bool) -> impl Display {
   colors::magenta(if is_readonly { "readonly " } else { "" })
 }
 
-cfg_if! {
-  if #[cfg(feature = "rust")] {
-    pub(crate) fn display_abstract(is_abstract: bool) -> impl Display {
-      colors::magenta(if is_abstract { "abstract " } else { "" })
-    }
+pub(crate) fn display_writeonly(is_writeonly: bool) -> impl Display {
+  colors::magenta(if is_writeonly { "writeonly " } else { "" })
+}
+
+pub(crate) fn display_abstract(is_abstract: bool) -> impl Display {
+  colors::magenta(if is_abstract { "abstract " } else { "" })
+}
 
-    pub(crate) fn display_accessibility(
-      accessibility: Option<deno_ast::swc::ast::Accessibility>, show_public: bool
-    ) -> impl Display {
-      colors::magenta(
-        match accessibility {
-          None => "",
-          Some(deno_ast::swc::ast::Accessibility::Public) => if show_public { "public " } else { "" },
-          Some(deno_ast::swc::ast::Accessibility::Protected) => "protected ",
-          Some(deno_ast::swc::ast::Accessibility::Private) => "private ",
-        },
-      )
+pub(crate) fn display_accessibility(
+  accessibility: Option<deno_ast::swc::ast::Accessibility>,
+  show_public: bool,
+) -> impl Display {
+  colors::magenta(match accessibility {
+    None => "",
+    Some(deno_ast::swc::ast::Accessibility::Public) => {
+      if show_public {
+        "public "
+      } else {
+        ""
+      }
     }
+    Some(deno_ast::swc::ast::Accessibility::Protected) => "protected ",
+    Some(deno_ast::swc::ast::Accessibility::Private) => "private ",
+  })
+}
+
+pub(crate) fn display_override(is_override: bool) -> impl Display {
+  colors::magenta(if is_override { "override " } else { "" })
+}
+
+pub(crate) fn display_static(is_static: bool) -> impl Display {
+  colors::magenta(if is_static { "static " } else { "" })
+}
 
+pub(crate) fn display_method(
+  method: deno_ast::swc::ast::MethodKind,
+) -> impl Display {
+  colors::magenta(match method {
+    deno_ast::swc::ast::MethodKind::Getter => "get ",
+    deno_ast::swc::ast::MethodKind::Setter => "set ",
+    _ => "",
+  })
+}
+
+cfg_if! {
+  if #[cfg(feature = "rust")] {
     pub(crate) fn display_async(is_async: bool) -> impl Display {
       colors::magenta(if is_async { "async " } else { "" })
     }
@@ -78,23 +105,5 @@ cfg_if! {
     pub(crate) fn display_generator(is_generator: bool) -> impl Display {
       colors::magenta(if is_generator { "*" } else { "" })
     }
-
-    pub(crate) fn display_method(
-      method: deno_ast::swc::ast::MethodKind,
-    ) -> impl Display {
-      colors::magenta(match method {
-        deno_ast::swc::ast::MethodKind::Getter => "get ",
-        deno_ast::swc::ast::MethodKind::Setter => "set ",
-        _ => "",
-      })
-    }
-
-    pub(crate) fn display_override(is_override: bool) -> impl Display {
-      colors::magenta(if is_override { "override "} else { "" })
-    }
-
-    pub(crate) fn display_static(is_static: bool) -> impl Display {
-      colors::magenta(if is_static { "static " } else { "" })
-    }
   }
 }
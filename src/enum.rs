@@ -13,6 +13,7 @@ use crate::util::swc::get_location;
 use crate::util::swc::js_doc_for_range;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct EnumMemberDef {
   pub name: String,
@@ -24,6 +25,7 @@ pub struct EnumMemberDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct EnumDef {
   pub members: Vec<EnumMemberDef>,
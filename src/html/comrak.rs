@@ -1,3 +1,4 @@
+use crate::html::Shared;
 use crate::html::ShortPath;
 use comrak::Arena;
 use comrak::adapters::SyntaxHighlighterAdapter;
@@ -6,12 +7,22 @@ use comrak::nodes::NodeValue;
 use std::collections::HashMap;
 use std::io::BufWriter;
 use std::io::Write;
-use std::rc::Rc;
 use std::sync::Arc;
 
 pub const COMRAK_STYLESHEET: &str = include_str!("./templates/comrak.gen.css");
 pub const COMRAK_STYLESHEET_FILENAME: &str = "comrak.css";
 
+#[cfg(feature = "parallel")]
+pub type NodeHook = Box<
+  dyn for<'a> Fn(
+      &'a Arena<AstNode<'a>>,
+      &'a AstNode<'a>,
+      &comrak::Options,
+      &comrak::Plugins,
+    ) + Send
+    + Sync,
+>;
+#[cfg(not(feature = "parallel"))]
 pub type NodeHook = Box<
   dyn for<'a> Fn(
     &'a Arena<AstNode<'a>>,
@@ -109,6 +120,9 @@ pub fn strip(md: &str) -> String {
   String::from_utf8(bw.into_inner().unwrap()).unwrap()
 }
 
+#[cfg(feature = "parallel")]
+pub type HtmlClean = Box<dyn Fn(String) -> String + Send + Sync>;
+#[cfg(not(feature = "parallel"))]
 pub type HtmlClean = Box<dyn Fn(String) -> String>;
 
 pub fn create_renderer(
@@ -116,23 +130,24 @@ pub fn create_renderer(
   node_hook: Option<NodeHook>,
   clean: Option<HtmlClean>,
 ) -> super::jsdoc::MarkdownRenderer {
-  let mut options = comrak::Options::default();
-  options.extension.autolink = true;
-  options.extension.description_lists = true;
-  options.extension.strikethrough = true;
-  options.extension.superscript = true;
-  options.extension.table = true;
-  options.extension.tagfilter = true;
-  options.extension.tasklist = true;
-
-  options.render.escape = clean.is_none();
-  options.render.unsafe_ = clean.is_some(); // its fine because we run the cleaner afterwards
-
   let renderer = move |md: &str,
                        title_only: bool,
                        _file_path: Option<ShortPath>,
                        anchorizer: super::jsdoc::Anchorizer|
         -> Option<String> {
+    // the options are created for each render, as they are not `Sync`
+    let mut options = comrak::Options::default();
+    options.extension.autolink = true;
+    options.extension.description_lists = true;
+    options.extension.strikethrough = true;
+    options.extension.superscript = true;
+    options.extension.table = true;
+    options.extension.tagfilter = true;
+    options.extension.tasklist = true;
+
+    options.render.escape = clean.is_none();
+    options.render.unsafe_ = clean.is_some(); // its fine because we run the cleaner afterwards
+
     let mut plugins = comrak::Plugins::default();
     let heading_adapter = ComrakHeadingAdapter(anchorizer);
     let highlight_adapter =
@@ -182,7 +197,7 @@ pub fn create_renderer(
     Some(html)
   };
 
-  Rc::new(renderer)
+  Shared::new(renderer)
 }
 
 pub struct ComrakHeadingAdapter(super::jsdoc::Anchorizer);
@@ -0,0 +1,402 @@
+use super::DocNodeWithContext;
+use super::GenerateCtx;
+use super::UrlResolveKind;
+use super::VersionSwitcherCtx;
+use super::pages::HtmlHeadCtx;
+use super::references::DeclarationKey;
+use super::references::declaration_key;
+use super::render_context::RenderContext;
+use super::util::BreadcrumbCtx;
+use super::util::BreadcrumbsCtx;
+use super::util::SectionContentCtx;
+use super::util::SectionCtx;
+use crate::node::DocNodeDef;
+use indexmap::IndexMap;
+use indexmap::IndexSet;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+pub const HIERARCHY_FILENAME: &str = "hierarchy.html";
+
+// the diagram is drawn with a monospace font, so the width of the boxes can
+// be computed from the length of the names.
+const CHAR_WIDTH: usize = 8;
+const BOX_PADDING: usize = 12;
+const BOX_HEIGHT: usize = 28;
+const HORIZONTAL_GAP: usize = 16;
+const VERTICAL_GAP: usize = 32;
+const MARGIN: usize = 2;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+enum HierarchyKey {
+  Declaration(DeclarationKey),
+  /// A supertype which is not part of the documentation.
+  External(String),
+}
+
+struct HierarchyNode {
+  name: String,
+  href: Option<String>,
+  level: isize,
+  current: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HierarchyNodeCtx {
+  pub name: String,
+  pub href: Option<String>,
+  pub current: bool,
+  pub x: usize,
+  pub y: usize,
+  pub width: usize,
+  pub height: usize,
+  pub text_x: usize,
+  pub text_y: usize,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HierarchyEdgeCtx {
+  pub x1: usize,
+  pub y1: usize,
+  pub x2: usize,
+  pub y2: usize,
+  pub implements: bool,
+}
+
+/// An SVG diagram of the ancestors and descendants of a class or interface.
+#[derive(Debug, Serialize, Clone)]
+pub struct HierarchyCtx {
+  pub width: usize,
+  pub height: usize,
+  pub nodes: Vec<HierarchyNodeCtx>,
+  pub edges: Vec<HierarchyEdgeCtx>,
+}
+
+impl HierarchyCtx {
+  pub const TEMPLATE: &'static str = "hierarchy";
+
+  /// Returns `None` if the class or interface neither extends nor implements
+  /// anything, and has no descendants.
+  pub fn new(
+    ctx: &RenderContext,
+    doc_node: &DocNodeWithContext,
+    highlight: bool,
+  ) -> Option<Self> {
+    let reverse_index = &ctx.ctx.reverse_index;
+
+    let mut nodes = IndexMap::<HierarchyKey, HierarchyNode>::new();
+    let mut edges = IndexSet::<(HierarchyKey, HierarchyKey, bool)>::new();
+
+    let node_href = |node: &DocNodeWithContext| {
+      Some(ctx.ctx.resolve_path(
+        ctx.get_current_resolve(),
+        UrlResolveKind::Symbol {
+          file: &node.origin,
+          symbol: &node.get_qualified_name(),
+        },
+      ))
+    };
+
+    let root_key = HierarchyKey::Declaration(declaration_key(doc_node));
+    nodes.insert(
+      root_key.clone(),
+      HierarchyNode {
+        name: doc_node.get_qualified_name(),
+        href: node_href(doc_node),
+        level: 0,
+        current: highlight,
+      },
+    );
+
+    let mut queue = VecDeque::from([(doc_node, root_key.clone(), 0)]);
+    while let Some((node, key, level)) = queue.pop_front() {
+      for supertype in reverse_index.supertypes(node) {
+        let supertype_key = match &supertype.node {
+          Some(supertype_node) => {
+            HierarchyKey::Declaration(declaration_key(supertype_node))
+          }
+          None => HierarchyKey::External(supertype.name.clone()),
+        };
+
+        if !nodes.contains_key(&supertype_key) {
+          let (name, href) = match &supertype.node {
+            Some(supertype_node) => {
+              queue.push_back((
+                supertype_node,
+                supertype_key.clone(),
+                level - 1,
+              ));
+              (
+                supertype_node.get_qualified_name(),
+                node_href(supertype_node),
+              )
+            }
+            None => (
+              supertype.name.clone(),
+              ctx.lookup_symbol_href(&supertype.name),
+            ),
+          };
+          nodes.insert(
+            supertype_key.clone(),
+            HierarchyNode {
+              name,
+              href,
+              level: level - 1,
+              current: false,
+            },
+          );
+        }
+
+        edges.insert((key.clone(), supertype_key, supertype.implements));
+      }
+    }
+
+    let mut queue = VecDeque::from([(doc_node, root_key, 0)]);
+    while let Some((node, key, level)) = queue.pop_front() {
+      let Some(references) = reverse_index.get(node) else {
+        continue;
+      };
+
+      let descendants = references
+        .subclasses
+        .iter()
+        .chain(&references.extended_by)
+        .map(|descendant| (descendant, false))
+        .chain(
+          references
+            .implemented_by
+            .iter()
+            .map(|descendant| (descendant, true)),
+        );
+
+      for (descendant, implements) in descendants {
+        let descendant_key =
+          HierarchyKey::Declaration(declaration_key(descendant));
+
+        if !nodes.contains_key(&descendant_key) {
+          nodes.insert(
+            descendant_key.clone(),
+            HierarchyNode {
+              name: descendant.get_qualified_name(),
+              href: node_href(descendant),
+              level: level + 1,
+              current: false,
+            },
+          );
+          queue.push_back((descendant, descendant_key.clone(), level + 1));
+        }
+
+        edges.insert((descendant_key, key.clone(), implements));
+      }
+    }
+
+    if nodes.len() == 1 {
+      return None;
+    }
+
+    Some(Self::layout(nodes, edges))
+  }
+
+  /// Places the nodes in rows by their distance to the class or interface,
+  /// with the ancestors above it and the descendants below it.
+  fn layout(
+    nodes: IndexMap<HierarchyKey, HierarchyNode>,
+    edges: IndexSet<(HierarchyKey, HierarchyKey, bool)>,
+  ) -> Self {
+    let mut rows =
+      BTreeMap::<isize, Vec<(&HierarchyKey, &HierarchyNode)>>::new();
+    for (key, node) in &nodes {
+      rows.entry(node.level).or_default().push((key, node));
+    }
+
+    let box_width = |node: &HierarchyNode| {
+      node.name.chars().count() * CHAR_WIDTH + BOX_PADDING * 2
+    };
+    let row_width = |row: &[(&HierarchyKey, &HierarchyNode)]| {
+      row.iter().map(|(_, node)| box_width(node)).sum::<usize>()
+        + HORIZONTAL_GAP * (row.len() - 1)
+    };
+
+    let content_width = rows
+      .values()
+      .map(|row| row_width(row))
+      .max()
+      .unwrap_or_default();
+
+    let mut positions = IndexMap::<&HierarchyKey, HierarchyNodeCtx>::new();
+    for (i, row) in rows.values().enumerate() {
+      let y = MARGIN + i * (BOX_HEIGHT + VERTICAL_GAP);
+      let mut x = MARGIN + (content_width - row_width(row)) / 2;
+
+      for (key, node) in row {
+        let width = box_width(node);
+        positions.insert(
+          key,
+          HierarchyNodeCtx {
+            name: node.name.clone(),
+            href: node.href.clone(),
+            current: node.current,
+            x,
+            y,
+            width,
+            height: BOX_HEIGHT,
+            text_x: x + width / 2,
+            text_y: y + BOX_HEIGHT / 2,
+          },
+        );
+        x += width + HORIZONTAL_GAP;
+      }
+    }
+
+    let edges = edges
+      .iter()
+      .map(|(subtype, supertype, implements)| {
+        let subtype = &positions[subtype];
+        let supertype = &positions[supertype];
+
+        HierarchyEdgeCtx {
+          x1: subtype.text_x,
+          y1: subtype.y,
+          x2: supertype.text_x,
+          y2: supertype.y + supertype.height,
+          implements: *implements,
+        }
+      })
+      .collect();
+
+    Self {
+      width: content_width + MARGIN * 2,
+      height: rows.len() * (BOX_HEIGHT + VERTICAL_GAP) - VERTICAL_GAP
+        + MARGIN * 2,
+      nodes: positions.into_values().collect(),
+      edges,
+    }
+  }
+}
+
+/// Renders the section with the hierarchy diagram of a class or interface,
+/// if enabled with [`GenerateOptions::class_hierarchy`].
+///
+/// [`GenerateOptions::class_hierarchy`]: super::GenerateOptions::class_hierarchy
+pub(crate) fn render_hierarchy_section(
+  ctx: &RenderContext,
+  doc_node: &DocNodeWithContext,
+) -> Option<SectionCtx> {
+  if !ctx.ctx.class_hierarchy {
+    return None;
+  }
+
+  let hierarchy = HierarchyCtx::new(ctx, doc_node, true)?;
+  let mut section = SectionCtx::new(
+    ctx,
+    &ctx.ctx.messages.hierarchy,
+    SectionContentCtx::Hierarchy(hierarchy),
+  );
+  if let Some(header) = &mut section.header {
+    header.href = Some(format!(
+      "{}{HIERARCHY_FILENAME}",
+      ctx
+        .ctx
+        .resolve_path(ctx.get_current_resolve(), UrlResolveKind::Root)
+    ));
+  }
+
+  Some(section)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind")]
+pub struct HierarchyPageCtx {
+  pub html_head_ctx: HtmlHeadCtx,
+  pub breadcrumbs_ctx: BreadcrumbsCtx,
+  pub disable_search: bool,
+  pub version_switcher: Option<VersionSwitcherCtx>,
+  pub sections: Vec<SectionCtx>,
+}
+
+impl HierarchyPageCtx {
+  pub const TEMPLATE: &'static str = "pages/hierarchy";
+
+  /// Renders a diagram for each class and interface at the top of a
+  /// hierarchy.
+  pub fn new(ctx: &GenerateCtx) -> Self {
+    let render_ctx = RenderContext::new(ctx, &[], UrlResolveKind::Root);
+
+    let mut seen = HashSet::new();
+    let sections = ctx
+      .doc_nodes
+      .values()
+      .flatten()
+      .flat_map(|node| {
+        let mut nodes = vec![];
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+          nodes.push(node);
+          if let Some(children) = &node.namespace_children {
+            stack.extend(children);
+          }
+        }
+        nodes
+      })
+      .filter(|node| {
+        matches!(
+          node.def,
+          DocNodeDef::Class { .. } | DocNodeDef::Interface { .. }
+        ) && seen.insert(declaration_key(node))
+          && ctx
+            .reverse_index
+            .supertypes(node)
+            .iter()
+            .all(|supertype| supertype.node.is_none())
+      })
+      .filter_map(|node| {
+        let hierarchy = HierarchyCtx::new(&render_ctx, node, false)?;
+        Some(SectionCtx::new(
+          &render_ctx,
+          &node.get_qualified_name(),
+          SectionContentCtx::Hierarchy(hierarchy),
+        ))
+      })
+      .collect();
+
+    let mut html_head_ctx = HtmlHeadCtx::new(
+      ctx,
+      UrlResolveKind::Root,
+      "./",
+      Some(&ctx.messages.hierarchy),
+      None,
+    );
+    html_head_ctx.canonical_url =
+      super::seo::canonical_url(ctx, "./", HIERARCHY_FILENAME);
+
+    let breadcrumbs_ctx = BreadcrumbsCtx {
+      parts: vec![
+        BreadcrumbCtx {
+          name: ctx
+            .package_name
+            .clone()
+            .unwrap_or_else(|| ctx.messages.breadcrumb_index.clone()),
+          href: "./".to_string(),
+          is_symbol: false,
+          is_first_symbol: false,
+        },
+        BreadcrumbCtx {
+          name: ctx.messages.hierarchy.clone(),
+          href: String::new(),
+          is_symbol: false,
+          is_first_symbol: false,
+        },
+      ],
+    };
+
+    Self {
+      html_head_ctx,
+      breadcrumbs_ctx,
+      disable_search: ctx.disable_search,
+      version_switcher: None,
+      sections,
+    }
+  }
+}
@@ -0,0 +1,189 @@
+use handlebars::Context;
+use handlebars::Handlebars;
+use handlebars::Helper;
+use handlebars::HelperDef;
+use handlebars::RenderContext;
+use handlebars::RenderError;
+use handlebars::RenderErrorReason;
+use handlebars::ScopedJson;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The message catalog used for the user-facing strings of the generated
+/// HTML. Missing entries fall back to the English default when deserializing.
+///
+/// In templates, messages are available through the `t` helper, eg.
+/// `{{t "usage"}}`. Placeholders in the form of `{name}` are replaced with the
+/// hash arguments passed to the helper, eg. `{{t "view_all_symbols" count=5}}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Messages {
+  /// The BCP 47 language tag of the messages, set as the `lang` attribute of
+  /// the document and used for slugification.
+  pub lang: String,
+
+  pub parameters: String,
+  pub return_type: String,
+  pub throws: String,
+  pub examples: String,
+  pub deprecated: String,
+  pub see: String,
+  pub type_parameters: String,
+  pub r#type: String,
+  pub definition: String,
+  /// The preview of the value of a variable documenting a JSON module.
+  pub value: String,
+  pub members: String,
+  pub constructors: String,
+  pub properties: String,
+  pub methods: String,
+  pub static_properties: String,
+  pub static_methods: String,
+  pub index_signatures: String,
+  pub call_signatures: String,
+
+  /// The title of the documentation when there is no package name.
+  pub documentation: String,
+  /// The title of the documentation of the `{package}`.
+  pub package_documentation: String,
+  pub breadcrumb_index: String,
+  pub breadcrumb_all_symbols: String,
+  pub all_symbols: String,
+  pub symbols: String,
+  pub view_all_symbols: String,
+  pub document_navigation: String,
+  pub usage: String,
+  pub usage_in_deno: String,
+  pub use_with: String,
+  pub no_documentation: String,
+  pub extends: String,
+  pub implements: String,
+  pub implemented_by: String,
+  pub extended_by: String,
+  pub subclasses: String,
+  pub accepted_by: String,
+  pub returned_by: String,
+  pub hierarchy: String,
+  pub expanded_type: String,
+  pub anchor: String,
+  pub toggle_dark_mode: String,
+  pub search_placeholder: String,
+  pub search_focused_placeholder: String,
+  pub search_no_results: String,
+  pub search_in_documentation: String,
+  pub select_version: String,
+}
+
+/// The language of the default messages.
+pub(crate) const DEFAULT_LANG: &str = "en";
+
+impl Default for Messages {
+  fn default() -> Self {
+    Self {
+      lang: DEFAULT_LANG.to_string(),
+
+      parameters: "Parameters".to_string(),
+      return_type: "Return Type".to_string(),
+      throws: "Throws".to_string(),
+      examples: "Examples".to_string(),
+      deprecated: "Deprecated".to_string(),
+      see: "See".to_string(),
+      type_parameters: "Type Parameters".to_string(),
+      r#type: "Type".to_string(),
+      definition: "Definition".to_string(),
+      value: "Value".to_string(),
+      members: "Members".to_string(),
+      constructors: "Constructors".to_string(),
+      properties: "Properties".to_string(),
+      methods: "Methods".to_string(),
+      static_properties: "Static Properties".to_string(),
+      static_methods: "Static Methods".to_string(),
+      index_signatures: "Index Signatures".to_string(),
+      call_signatures: "Call Signatures".to_string(),
+
+      documentation: "documentation".to_string(),
+      package_documentation: "{package} documentation".to_string(),
+      breadcrumb_index: "index".to_string(),
+      breadcrumb_all_symbols: "all symbols".to_string(),
+      all_symbols: "All Symbols".to_string(),
+      symbols: "Symbols".to_string(),
+      view_all_symbols: "view all {count} symbols".to_string(),
+      document_navigation: "Document Navigation".to_string(),
+      usage: "Usage".to_string(),
+      usage_in_deno: "Usage in Deno".to_string(),
+      use_with: "Use with".to_string(),
+      no_documentation: "No documentation available".to_string(),
+      extends: "extends".to_string(),
+      implements: "implements".to_string(),
+      implemented_by: "Implemented by".to_string(),
+      extended_by: "Extended by".to_string(),
+      subclasses: "Subclasses".to_string(),
+      accepted_by: "Accepted by".to_string(),
+      returned_by: "Returned by".to_string(),
+      hierarchy: "Hierarchy".to_string(),
+      expanded_type: "Expanded type".to_string(),
+      anchor: "Anchor".to_string(),
+      toggle_dark_mode: "Toggle dark mode".to_string(),
+      search_placeholder: "Click or press 'S' to search...".to_string(),
+      search_focused_placeholder: "Type your query here...".to_string(),
+      search_no_results: "No result".to_string(),
+      search_in_documentation: "In documentation".to_string(),
+      select_version: "Select version".to_string(),
+    }
+  }
+}
+
+/// The `t` helper, which looks up a message in the catalog.
+pub(crate) struct TranslateHelper(serde_json::Map<String, Value>);
+
+impl TranslateHelper {
+  pub fn new(messages: &Messages) -> Self {
+    let Value::Object(messages) = serde_json::to_value(messages).unwrap()
+    else {
+      unreachable!()
+    };
+    Self(messages)
+  }
+}
+
+impl Messages {
+  /// The title of the documentation, naming the package if any.
+  pub fn documentation_title(&self, package_name: Option<&str>) -> String {
+    match package_name {
+      Some(package_name) => self
+        .package_documentation
+        .replace("{package}", package_name),
+      None => self.documentation.clone(),
+    }
+  }
+}
+
+impl HelperDef for TranslateHelper {
+  fn call_inner<'reg: 'rc, 'rc>(
+    &self,
+    h: &Helper<'rc>,
+    _: &'reg Handlebars<'reg>,
+    _: &'rc Context,
+    _: &mut RenderContext<'reg, 'rc>,
+  ) -> Result<ScopedJson<'rc>, RenderError> {
+    let key = h
+      .param(0)
+      .and_then(|param| param.value().as_str())
+      .ok_or(RenderErrorReason::ParamNotFoundForIndex("t", 0))?;
+    let message = self.0.get(key).and_then(Value::as_str).ok_or_else(|| {
+      RenderErrorReason::Other(format!("Unknown message \"{key}\""))
+    })?;
+
+    let mut message = message.to_string();
+    for (name, value) in h.hash() {
+      let value = match value.value() {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+      };
+      message = message.replace(&format!("{{{name}}}"), &value);
+    }
+
+    Ok(ScopedJson::Derived(Value::String(message)))
+  }
+}
@@ -0,0 +1,317 @@
+use super::DocNodeWithContext;
+use super::GenerateCtx;
+use super::ShortPath;
+use super::references::flatten_namespaces;
+use crate::js_doc::JsDocTag;
+use crate::util::hash::StableHasher;
+use crate::util::hash::stable_hash;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+/// The files of a build, with the hashes of their content and of the inputs
+/// they were rendered from. Passed to [`generate_incremental`] as the
+/// previous build to skip the files whose inputs are unchanged.
+///
+/// Changes to the resolvers and template overrides are not tracked, so the
+/// documentation must be generated without a previous manifest after
+/// changing them.
+///
+/// [`generate_incremental`]: super::generate_incremental
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildManifest {
+  pub files: BTreeMap<String, ManifestEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEntry {
+  /// The hash of the content of the file.
+  pub hash: String,
+  /// The hashes of the inputs of the file, which is empty for the files
+  /// that are always rendered, such as the assets.
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub inputs: BTreeMap<String, String>,
+  /// Whether the file is listed in the sitemap.
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub page: bool,
+}
+
+/// The result of [`generate_incremental`], with the paths of the files which
+/// were written to the sink, and of the files of the previous build which no
+/// longer exist.
+///
+/// [`generate_incremental`]: super::generate_incremental
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalOutput {
+  pub manifest: BuildManifest,
+  pub added: Vec<String>,
+  pub changed: Vec<String>,
+  pub removed: Vec<String>,
+}
+
+pub(crate) const GLOBAL_INPUT: &str = "global";
+
+pub(crate) fn module_input(short_path: &ShortPath) -> String {
+  format!("module:{}", short_path.path)
+}
+
+pub(crate) fn source_input(short_path: &ShortPath) -> String {
+  format!("source:{}", short_path.path)
+}
+
+/// The inputs of the pages listing the symbols of all modules.
+pub(crate) fn all_modules_inputs(ctx: &GenerateCtx) -> Vec<String> {
+  std::iter::once(GLOBAL_INPUT.to_string())
+    .chain(
+      ctx
+        .doc_nodes
+        .keys()
+        .map(|short_path| module_input(short_path)),
+    )
+    .collect()
+}
+
+/// The inputs of the symbol pages of a module, which also render the
+/// summaries of the symbols of other modules referencing or extended by its
+/// classes and interfaces.
+pub(crate) fn symbol_page_inputs(
+  ctx: &GenerateCtx,
+  short_path: &ShortPath,
+  doc_nodes: &[DocNodeWithContext],
+) -> Vec<String> {
+  // expanded types can resolve declarations from any module
+  if ctx.expand_types {
+    return all_modules_inputs(ctx);
+  }
+
+  let mut modules = BTreeSet::from([short_path.path.as_str()]);
+
+  for doc_node in flatten_namespaces(doc_nodes) {
+    if let Some(references) = ctx.reverse_index.get(doc_node) {
+      for node in [
+        &references.implemented_by,
+        &references.extended_by,
+        &references.subclasses,
+        &references.accepted_by,
+        &references.returned_by,
+      ]
+      .into_iter()
+      .flatten()
+      {
+        modules.insert(&node.origin.path);
+      }
+    }
+
+    for supertype in ctx.reverse_index.supertypes(doc_node) {
+      if let Some(node) = &supertype.node {
+        modules.insert(&node.origin.path);
+      }
+    }
+  }
+
+  std::iter::once(GLOBAL_INPUT.to_string())
+    .chain(modules.into_iter().map(|path| format!("module:{path}")))
+    .collect()
+}
+
+/// Inserts the hash of the content before the extension of the asset, eg.
+/// `styles.0123456789abcdef.css`.
+pub(crate) fn hashed_filename(filename: &str, content: &str) -> String {
+  let hash = stable_hash(content);
+  match filename.rsplit_once('.') {
+    Some((name, extension)) => format!("{name}.{hash}.{extension}"),
+    None => format!("{filename}.{hash}"),
+  }
+}
+
+/// Hashes the inputs of the pages.
+///
+/// The global input covers the options, and the names, kinds and categories
+/// of the symbols of all modules, which are linked to and listed in the
+/// navigation of every page. The content of the symbols only affects the
+/// pages of their module, and the pages listing them.
+fn hash_inputs(ctx: &GenerateCtx) -> HashMap<String, String> {
+  let mut hashes = HashMap::new();
+
+  let mut global = StableHasher::default();
+  global.write(env!("CARGO_PKG_VERSION"));
+  global.write(format!("{:?}", ctx.package_name));
+  global.write(format!(
+    "{:?}",
+    ctx
+      .main_entrypoint
+      .as_ref()
+      .map(|main_entrypoint| &main_entrypoint.path)
+  ));
+  global.write(format!("{:?}", ctx.file_mode));
+  global.write(format!("{:?}", ctx.category_docs));
+  global.write([ctx.disable_search as u8]);
+  global.write(format!("{:?}", ctx.symbol_redirect_map));
+  global.write(format!("{:?}", ctx.default_symbol_map));
+  global.write(format!(
+    "{:?}",
+    ctx
+      .head_inject
+      .as_ref()
+      .map(|head_inject| head_inject("./"))
+  ));
+  global.write(format!("{:?}", ctx.id_prefix));
+  global.write(format!("{:?}", ctx.messages));
+  global.write(format!("{:?}", ctx.versions));
+  global.write([ctx.class_hierarchy as u8]);
+  global.write([ctx.full_text_search as u8]);
+  global.write(format!("{:?}", ctx.base_url));
+  global.write(format!("{:?}", ctx.inventories));
+  global.write([ctx.expand_types as u8]);
+  for (name, filename) in &ctx.asset_filenames {
+    global.write(name);
+    global.write(filename);
+  }
+  for specifier in ctx.source_files.keys() {
+    global.write(specifier.as_str());
+  }
+
+  for (short_path, doc_nodes) in &ctx.doc_nodes {
+    global.write(&short_path.path);
+    for doc_node in flatten_namespaces(doc_nodes) {
+      global.write(doc_node.get_qualified_name());
+      global.write(format!("{:?}", doc_node.kind));
+      global.write(format!("{:?}", doc_node.declaration_kind));
+      for tag in &doc_node.js_doc.tags {
+        match tag {
+          JsDocTag::Category { doc } => global.write(&**doc),
+          // the content of the other tags only affects their symbol's pages
+          _ => global.write(tag_kind(tag)),
+        }
+      }
+    }
+
+    hashes.insert(
+      module_input(short_path),
+      stable_hash(serde_json::to_string(doc_nodes).unwrap_or_default()),
+    );
+  }
+
+  for source_file in ctx.source_files.values() {
+    hashes.insert(
+      source_input(&source_file.short_path),
+      stable_hash(&*source_file.text),
+    );
+  }
+
+  hashes.insert(GLOBAL_INPUT.to_string(), global.finish());
+
+  hashes
+}
+
+/// The serialized kind of the tag, eg. `deprecated`.
+fn tag_kind(tag: &JsDocTag) -> String {
+  serde_json::to_value(tag)
+    .ok()
+    .and_then(|tag| Some(tag.get("kind")?.as_str()?.to_string()))
+    .unwrap_or_default()
+}
+
+/// Tracks the files of an incremental build, against the previous build.
+pub(crate) struct Tracker<'a> {
+  previous: Option<&'a BuildManifest>,
+  hashes: HashMap<String, String>,
+  manifest: BuildManifest,
+  added: Vec<String>,
+  changed: Vec<String>,
+}
+
+impl<'a> Tracker<'a> {
+  pub fn new(ctx: &GenerateCtx, previous: Option<&'a BuildManifest>) -> Self {
+    Self {
+      previous,
+      hashes: hash_inputs(ctx),
+      manifest: BuildManifest::default(),
+      added: vec![],
+      changed: vec![],
+    }
+  }
+
+  fn input_hashes(&self, inputs: &[String]) -> BTreeMap<String, String> {
+    inputs
+      .iter()
+      .map(|input| {
+        (
+          input.clone(),
+          self.hashes.get(input).cloned().unwrap_or_default(),
+        )
+      })
+      .collect()
+  }
+
+  /// Keeps the file of the previous build if its inputs are unchanged.
+  pub fn keep_unchanged(&mut self, path: &str, inputs: &[String]) -> bool {
+    let Some(entry) = self
+      .previous
+      .and_then(|previous| previous.files.get(path))
+      .filter(|entry| {
+        !entry.inputs.is_empty() && entry.inputs == self.input_hashes(inputs)
+      })
+    else {
+      return false;
+    };
+
+    self.manifest.files.insert(path.to_string(), entry.clone());
+    true
+  }
+
+  /// Records the rendered file, and returns whether it has to be written.
+  pub fn record(
+    &mut self,
+    path: &str,
+    content: &str,
+    inputs: &[String],
+    page: bool,
+  ) -> bool {
+    let entry = ManifestEntry {
+      hash: stable_hash(content),
+      inputs: self.input_hashes(inputs),
+      page,
+    };
+
+    let write =
+      match self.previous.and_then(|previous| previous.files.get(path)) {
+        Some(previous) if previous.hash == entry.hash => false,
+        Some(_) => {
+          self.changed.push(path.to_string());
+          true
+        }
+        None => {
+          self.added.push(path.to_string());
+          true
+        }
+      };
+
+    self.manifest.files.insert(path.to_string(), entry);
+    write
+  }
+
+  pub fn finish(self) -> IncrementalOutput {
+    let removed = self
+      .previous
+      .map(|previous| {
+        previous
+          .files
+          .keys()
+          .filter(|path| !self.manifest.files.contains_key(*path))
+          .cloned()
+          .collect()
+      })
+      .unwrap_or_default();
+
+    IncrementalOutput {
+      manifest: self.manifest,
+      added: self.added,
+      changed: self.changed,
+      removed,
+    }
+  }
+}
@@ -0,0 +1,179 @@
+use super::DocNodeKindCtx;
+use super::GenerateCtx;
+use super::UrlResolveKind;
+use super::partition;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+pub const INVENTORY_FILENAME: &str = "inventory.json";
+
+/// A machine-readable list of the symbols documented by a package, with the
+/// URLs of their pages, emitted as `inventory.json` next to the pages.
+///
+/// The inventories of other packages can be passed as
+/// [`GenerateOptions::inventories`], so that the symbols imported from these
+/// packages link to their documentation.
+///
+/// [`GenerateOptions::inventories`]: super::GenerateOptions::inventories
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Inventory {
+  /// The URL the hrefs are relative to. Set from
+  /// [`GenerateOptions::base_url`], and must be set to link to the symbols
+  /// of the inventory.
+  ///
+  /// [`GenerateOptions::base_url`]: super::GenerateOptions::base_url
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub base_url: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub package_name: Option<String>,
+  /// The modules by the subpath they are imported from, eg. `posix` for
+  /// `jsr:@std/path/posix`, or an empty string for the main module.
+  pub modules: IndexMap<String, InventoryModule>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InventoryModule {
+  pub href: String,
+  /// The symbols by their qualified name, eg. `Foo` or `Foo.prototype.bar`.
+  pub symbols: IndexMap<String, InventorySymbol>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventorySymbol {
+  pub href: String,
+  /// The kinds of the declarations of the symbol, eg. `Class` or `Method`.
+  pub kind: Vec<String>,
+}
+
+impl Inventory {
+  pub fn new(ctx: &GenerateCtx) -> Self {
+    fn resolve(ctx: &GenerateCtx, target: UrlResolveKind) -> String {
+      ctx
+        .resolve_path(UrlResolveKind::Root, target)
+        .trim_start_matches("./")
+        .to_string()
+    }
+
+    let modules = ctx
+      .doc_nodes
+      .iter()
+      .map(|(short_path, doc_nodes)| {
+        let mut partitions = partition::partition_nodes_by_name(
+          ctx,
+          doc_nodes.iter().map(Cow::Borrowed),
+          true,
+        );
+
+        let mut drilldown_partitions = IndexMap::new();
+        for nodes in partitions.values() {
+          for node in nodes {
+            if let Some(drilldown_symbols) = node.get_drilldown_symbols() {
+              drilldown_partitions.extend(partition::partition_nodes_by_name(
+                ctx,
+                drilldown_symbols.map(Cow::Owned),
+                false,
+              ));
+            }
+          }
+        }
+        partitions.extend(drilldown_partitions);
+
+        let symbols = partitions
+          .into_iter()
+          .map(|(name, nodes)| {
+            let symbol = InventorySymbol {
+              href: resolve(
+                ctx,
+                UrlResolveKind::Symbol {
+                  file: short_path,
+                  symbol: &name,
+                },
+              ),
+              kind: nodes
+                .iter()
+                .map(|node| DocNodeKindCtx::from(node.kind).kind.to_string())
+                .collect(),
+            };
+            (name, symbol)
+          })
+          .collect();
+
+        let subpath = if short_path.is_main {
+          String::new()
+        } else {
+          short_path.path.clone()
+        };
+        let module = InventoryModule {
+          href: resolve(ctx, short_path.as_resolve_kind()),
+          symbols,
+        };
+        (subpath, module)
+      })
+      .collect();
+
+    Self {
+      base_url: ctx.base_url.clone(),
+      package_name: ctx.package_name.clone(),
+      modules,
+    }
+  }
+
+  /// Resolves the symbol imported from the module of the inventory at the
+  /// given subpath, falling back to the closest documented parent of the
+  /// symbol, eg. the class of a method.
+  fn resolve(&self, subpath: &str, symbol: &[String]) -> Option<String> {
+    let base_url = self.base_url.as_ref()?;
+    let module = self.modules.get(subpath)?;
+
+    let href = (1..=symbol.len())
+      .rev()
+      .find_map(|len| module.symbols.get(&symbol[..len].join(".")))
+      .map(|symbol| &symbol.href)?;
+
+    let separator = if base_url.ends_with('/') { "" } else { "/" };
+    Some(format!("{base_url}{separator}{href}"))
+  }
+}
+
+/// Resolves a symbol imported from another package through the inventory
+/// with the longest specifier prefix matching the import.
+///
+/// The version following the prefix is ignored, so that both
+/// `jsr:@std/path/posix` and `jsr:@std/path@^1.0.0/posix` resolve to the
+/// `posix` module of the inventory for `jsr:@std/path`.
+pub(crate) fn resolve_import_href(
+  ctx: &GenerateCtx,
+  symbol: &[String],
+  src: &str,
+) -> Option<String> {
+  let (_, subpath, inventory) = ctx
+    .inventories
+    .as_ref()?
+    .iter()
+    .filter_map(|(prefix, inventory)| {
+      Some((prefix.len(), package_subpath(src, prefix)?, inventory))
+    })
+    .max_by_key(|(len, _, _)| *len)?;
+
+  inventory.resolve(subpath, symbol)
+}
+
+/// The subpath of an import specifier within the package of the prefix, if
+/// the prefix ends at a segment boundary of the specifier, so that
+/// `jsr:@std/path` matches `jsr:@std/path/posix` but not `jsr:@std/paths`.
+fn package_subpath<'a>(src: &'a str, prefix: &str) -> Option<&'a str> {
+  let rest = src.strip_prefix(prefix)?;
+  if prefix.ends_with('/') {
+    return Some(rest);
+  }
+
+  match rest.chars().next() {
+    None => Some(""),
+    Some('/') => Some(&rest[1..]),
+    Some('@') => Some(rest.split_once('/').map_or("", |(_, subpath)| subpath)),
+    Some(_) => None,
+  }
+}
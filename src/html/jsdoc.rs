@@ -1,12 +1,12 @@
 use super::render_context::RenderContext;
 use super::util::*;
+use crate::html::Shared;
 use crate::html::ShortPath;
 use crate::js_doc::JsDoc;
 use crate::js_doc::JsDocTag;
 use crate::node::DocNodeDef;
 use serde::Serialize;
 use std::borrow::Cow;
-use std::rc::Rc;
 
 lazy_static! {
   static ref JSDOC_LINK_RE: regex::Regex = regex::Regex::new(
@@ -130,7 +130,7 @@ fn parse_links<'a>(
   })
 }
 
-fn split_markdown_title(md: &str) -> (Option<&str>, Option<&str>) {
+pub(crate) fn split_markdown_title(md: &str) -> (Option<&str>, Option<&str>) {
   let newline = md.find("\n\n").unwrap_or(usize::MAX);
   let codeblock = md.find("```").unwrap_or(usize::MAX);
 
@@ -148,7 +148,10 @@ pub struct MarkdownToHTMLOptions {
   pub no_toc: bool,
 }
 
-pub type MarkdownStripper = std::rc::Rc<dyn (Fn(&str) -> String)>;
+#[cfg(feature = "parallel")]
+pub type MarkdownStripper = Shared<dyn (Fn(&str) -> String) + Send + Sync>;
+#[cfg(not(feature = "parallel"))]
+pub type MarkdownStripper = Shared<dyn (Fn(&str) -> String)>;
 
 pub fn strip(render_ctx: &RenderContext, md: &str) -> String {
   let md = parse_links(md, render_ctx, true);
@@ -156,14 +159,36 @@ pub fn strip(render_ctx: &RenderContext, md: &str) -> String {
   (render_ctx.ctx.markdown_stripper)(&md)
 }
 
+/// The first paragraph of the markdown as plain text on a single line, eg. for
+/// the meta description of a page.
+pub(crate) fn strip_summary(
+  render_ctx: &RenderContext,
+  md: &str,
+) -> Option<String> {
+  let (title, body) = split_markdown_title(md);
+  let summary = strip(render_ctx, title.or(body)?)
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+
+  (!summary.is_empty()).then_some(summary)
+}
+
 #[cfg(not(feature = "rust"))]
 pub type Anchorizer<'a> = &'a js_sys::Function;
 #[cfg(feature = "rust")]
 pub type Anchorizer =
   std::sync::Arc<dyn Fn(String, u8) -> String + Send + Sync>;
 
+#[cfg(feature = "parallel")]
+pub type MarkdownRenderer = Shared<
+  dyn (Fn(&str, bool, Option<ShortPath>, Anchorizer) -> Option<String>)
+    + Send
+    + Sync,
+>;
+#[cfg(not(feature = "parallel"))]
 pub type MarkdownRenderer =
-  Rc<dyn (Fn(&str, bool, Option<ShortPath>, Anchorizer) -> Option<String>)>;
+  Shared<dyn (Fn(&str, bool, Option<ShortPath>, Anchorizer) -> Option<String>)>;
 
 pub fn markdown_to_html(
   render_ctx: &RenderContext,
@@ -272,7 +297,7 @@ pub(crate) fn jsdoc_examples(
   if !examples.is_empty() {
     Some(SectionCtx::new(
       ctx,
-      "Examples",
+      &ctx.ctx.messages.examples,
       SectionContentCtx::Example(examples),
     ))
   } else {
@@ -403,13 +428,13 @@ mod test {
   use crate::html::GenerateCtx;
   use crate::html::GenerateOptions;
   use crate::html::HrefResolver;
+  use crate::html::Shared;
   use crate::html::UsageComposer;
   use crate::html::UsageComposerEntry;
   use crate::html::href_path_resolve;
   use crate::html::jsdoc::parse_links;
   use deno_ast::ModuleSpecifier;
   use indexmap::IndexMap;
-  use std::rc::Rc;
 
   use crate::html::RenderContext;
   use crate::html::UrlResolveKind;
@@ -485,8 +510,8 @@ mod test {
       GenerateOptions {
         package_name: None,
         main_entrypoint: None,
-        href_resolver: Rc::new(EmptyResolver),
-        usage_composer: Rc::new(EmptyResolver),
+        href_resolver: Shared::new(EmptyResolver),
+        usage_composer: Shared::new(EmptyResolver),
         rewrite_map: None,
         category_docs: None,
         disable_search: false,
@@ -495,9 +520,18 @@ mod test {
         markdown_renderer: crate::html::comrak::create_renderer(
           None, None, None,
         ),
-        markdown_stripper: Rc::new(crate::html::comrak::strip),
+        markdown_stripper: Shared::new(crate::html::comrak::strip),
         head_inject: None,
         id_prefix: None,
+        template_overrides: None,
+        messages: None,
+        versions: None,
+        source_files: None,
+        class_hierarchy: false,
+        full_text_search: false,
+        base_url: None,
+        inventories: None,
+        expand_types: false,
       },
       Default::default(),
       Default::default(),
@@ -10,31 +10,68 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::rc::Rc;
 use std::sync::Arc;
 
+mod hierarchy;
+mod i18n;
+mod incremental;
+mod inventory;
 pub mod jsdoc;
+mod output;
 pub mod pages;
 mod parameters;
 pub mod partition;
+mod references;
 mod render_context;
 pub mod search;
+mod seo;
+mod single_page;
+mod source;
 mod symbols;
+mod theme;
 mod types;
 mod usage;
 pub mod util;
+mod versions;
 
 #[cfg(feature = "comrak")]
 pub mod comrak;
 
 use crate::html::pages::SymbolPage;
 use crate::js_doc::JsDocTag;
+pub use hierarchy::HIERARCHY_FILENAME;
+pub use hierarchy::HierarchyCtx;
+pub use hierarchy::HierarchyPageCtx;
+pub use i18n::Messages;
+pub use incremental::BuildManifest;
+pub use incremental::IncrementalOutput;
+pub use incremental::ManifestEntry;
+pub use inventory::INVENTORY_FILENAME;
+pub use inventory::Inventory;
+pub use inventory::InventoryModule;
+pub use inventory::InventorySymbol;
+pub use output::DirectorySink;
+pub use output::OutputSink;
+pub use output::PageProgress;
+pub use output::ProgressSink;
+#[cfg(feature = "zip")]
+pub use output::ZipSink;
 pub use pages::generate_symbol_pages_for_module;
+pub use references::ReverseIndex;
+pub use references::TypeReferences;
 pub use render_context::RenderContext;
 pub use search::generate_search_index;
+pub use single_page::SinglePageCtx;
+pub use single_page::SinglePageHrefResolver;
+pub use source::SourceFile;
+pub use source::SourcePageCtx;
+pub use source::collect_source_files;
 pub use symbols::SymbolContentCtx;
 pub use symbols::SymbolGroupCtx;
 pub use symbols::namespace;
+pub use theme::SharedHelper;
+pub use theme::TemplateOverrideError;
+pub use theme::TemplateOverrides;
 pub use usage::UsageComposer;
 pub use usage::UsageComposerEntry;
 pub use usage::UsageToMd;
@@ -48,7 +85,12 @@ pub use util::TopSymbolsCtx;
 pub use util::UrlResolveKind;
 pub use util::compute_namespaced_symbols;
 pub use util::href_path_resolve;
+pub use util::href_path_resolve_localized;
 pub use util::qualify_drilldown_name;
+pub use versions::DocVersion;
+pub use versions::VersionOptions;
+pub use versions::VersionSwitcherCtx;
+pub use versions::collect_version_symbols;
 
 pub const STYLESHEET: &str = include_str!("./templates/styles.gen.css");
 pub const STYLESHEET_FILENAME: &str = "styles.css";
@@ -66,9 +108,6 @@ const SEARCH_INDEX_FILENAME: &str = "search_index.js";
 pub const SCRIPT_JS: &str = include_str!("./templates/script.js");
 pub const SCRIPT_FILENAME: &str = "script.js";
 
-const FUSE_JS: &str = include_str!("./templates/pages/fuse.js");
-const FUSE_FILENAME: &str = "fuse.js";
-
 const SEARCH_JS: &str = include_str!("./templates/pages/search.js");
 const SEARCH_FILENAME: &str = "search.js";
 
@@ -90,6 +129,11 @@ fn setup_hbs() -> Result<Handlebars<'static>, anyhow::Error> {
   handlebars_helper!(print: |a: Json| println!("{a:#?}"));
   reg.register_helper("print", Box::new(print));
 
+  reg.register_helper(
+    "t",
+    Box::new(i18n::TranslateHelper::new(&Messages::default())),
+  );
+
   reg.register_template_string(
     ToCCtx::TEMPLATE,
     include_str!("./templates/toc.hbs"),
@@ -174,7 +218,27 @@ fn setup_hbs() -> Result<Handlebars<'static>, anyhow::Error> {
     pages::CategoriesPanelCtx::TEMPLATE,
     include_str!("./templates/category_panel.hbs"),
   )?;
+  reg.register_template_string(
+    SourcePageCtx::TEMPLATE,
+    include_str!("./templates/pages/source.hbs"),
+  )?;
+  reg.register_template_string(
+    HierarchyCtx::TEMPLATE,
+    include_str!("./templates/hierarchy.hbs"),
+  )?;
+  reg.register_template_string(
+    HierarchyPageCtx::TEMPLATE,
+    include_str!("./templates/pages/hierarchy.hbs"),
+  )?;
+  reg.register_template_string(
+    VersionSwitcherCtx::TEMPLATE,
+    include_str!("./templates/version_switcher.hbs"),
+  )?;
   reg.register_template_string("see", include_str!("./templates/see.hbs"))?;
+  reg.register_template_string(
+    types::ExpandedTypeCtx::TEMPLATE,
+    include_str!("./templates/expanded_type.hbs"),
+  )?;
 
   // pages
   reg.register_template_string(
@@ -193,6 +257,10 @@ fn setup_hbs() -> Result<Handlebars<'static>, anyhow::Error> {
     pages::IndexCtx::TEMPLATE,
     include_str!("./templates/pages/index.hbs"),
   )?;
+  reg.register_template_string(
+    SinglePageCtx::TEMPLATE,
+    include_str!("./templates/pages/single_page.hbs"),
+  )?;
   reg.register_template_string(
     "pages/top_nav",
     include_str!("./templates/pages/top_nav.hbs"),
@@ -247,7 +315,29 @@ lazy_static! {
   pub static ref HANDLEBARS: Handlebars<'static> = setup_hbs().unwrap();
 }
 
-pub type HeadInject = Rc<dyn Fn(&str) -> String>;
+#[cfg(not(feature = "parallel"))]
+pub use std::rc::Rc as Shared;
+/// The reference counted pointer used by the context. With the `parallel`
+/// feature it is an [`Arc`], so that the context can be shared between the
+/// threads rendering the pages, otherwise an [`Rc`](std::rc::Rc).
+#[cfg(feature = "parallel")]
+pub use std::sync::Arc as Shared;
+
+/// `Send + Sync` with the `parallel` feature, for the resolvers shared between
+/// threads.
+#[cfg(feature = "parallel")]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync + ?Sized> MaybeSendSync for T {}
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSendSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T: ?Sized> MaybeSendSync for T {}
+
+#[cfg(feature = "parallel")]
+pub type HeadInject = Shared<dyn Fn(&str) -> String + Send + Sync>;
+#[cfg(not(feature = "parallel"))]
+pub type HeadInject = Shared<dyn Fn(&str) -> String>;
 
 #[derive(Clone)]
 pub struct GenerateOptions {
@@ -257,8 +347,8 @@ pub struct GenerateOptions {
   /// If only a single file is specified during generation, this will always
   /// default to that file.
   pub main_entrypoint: Option<ModuleSpecifier>,
-  pub href_resolver: Rc<dyn HrefResolver>,
-  pub usage_composer: Rc<dyn UsageComposer>,
+  pub href_resolver: Shared<dyn HrefResolver>,
+  pub usage_composer: Shared<dyn UsageComposer>,
   pub rewrite_map: Option<IndexMap<ModuleSpecifier, String>>,
   pub category_docs: Option<IndexMap<String, Option<String>>>,
   pub disable_search: bool,
@@ -268,17 +358,48 @@ pub struct GenerateOptions {
   pub markdown_stripper: jsdoc::MarkdownStripper,
   pub head_inject: Option<HeadInject>,
   pub id_prefix: Option<String>,
+  /// Replacements for the built-in templates, and additional partials and
+  /// helpers. These are validated when creating the [`GenerateCtx`].
+  pub template_overrides: Option<TemplateOverrides>,
+  /// The message catalog for the user-facing strings. Defaults to English.
+  pub messages: Option<Messages>,
+  /// The current version and the other versions to link to from the version
+  /// switcher in the top navigation.
+  pub versions: Option<VersionOptions>,
+  /// The source text of modules, keyed by specifier. When set, a highlighted
+  /// source page is generated for each module, and source links point to
+  /// these pages instead of using [`HrefResolver::resolve_source`].
+  pub source_files: Option<IndexMap<ModuleSpecifier, Arc<str>>>,
+  /// Whether to render a diagram of the ancestors and descendants on class
+  /// and interface pages, and to generate a page with all the hierarchies.
+  pub class_hierarchy: bool,
+  /// Whether to index the full text of the documentation for the search,
+  /// including parameters, return values, throws, examples and the sections
+  /// of module docs, linking to their anchors.
+  pub full_text_search: bool,
+  /// The absolute URL the documentation is published at, eg.
+  /// `https://example.com/docs/`. When set, every page carries a canonical
+  /// link, and a `sitemap.xml` and `robots.txt` are generated.
+  pub base_url: Option<String>,
+  /// The inventories of other packages, keyed by the specifier prefix of the
+  /// package, eg. `jsr:@std/path` or `npm:zod`. Symbols imported from these
+  /// packages link to their documentation.
+  pub inventories: Option<IndexMap<String, Inventory>>,
+  /// Whether to render a disclosure with the members of the types of type
+  /// aliases and variables which reference other type aliases, interfaces or
+  /// the `Partial`, `Readonly`, `Pick`, `Omit` and `Record` utility types.
+  pub expand_types: bool,
 }
 
 #[non_exhaustive]
 pub struct GenerateCtx {
   pub package_name: Option<String>,
   pub common_ancestor: Option<PathBuf>,
-  pub doc_nodes: IndexMap<Rc<ShortPath>, Vec<DocNodeWithContext>>,
-  pub href_resolver: Rc<dyn HrefResolver>,
-  pub usage_composer: Rc<dyn UsageComposer>,
+  pub doc_nodes: IndexMap<Shared<ShortPath>, Vec<DocNodeWithContext>>,
+  pub href_resolver: Shared<dyn HrefResolver>,
+  pub usage_composer: Shared<dyn UsageComposer>,
   pub rewrite_map: Option<IndexMap<ModuleSpecifier, String>>,
-  pub main_entrypoint: Option<Rc<ShortPath>>,
+  pub main_entrypoint: Option<Shared<ShortPath>>,
   pub file_mode: FileMode,
   pub category_docs: Option<IndexMap<String, Option<String>>>,
   pub disable_search: bool,
@@ -288,6 +409,22 @@ pub struct GenerateCtx {
   pub markdown_stripper: jsdoc::MarkdownStripper,
   pub head_inject: Option<HeadInject>,
   pub id_prefix: Option<String>,
+  pub messages: Messages,
+  pub versions: Option<VersionOptions>,
+  pub source_files: IndexMap<ModuleSpecifier, SourceFile>,
+  /// The symbols referencing each class and interface.
+  pub reverse_index: ReverseIndex,
+  pub class_hierarchy: bool,
+  pub full_text_search: bool,
+  pub base_url: Option<String>,
+  pub inventories: Option<IndexMap<String, Inventory>>,
+  pub expand_types: bool,
+  handlebars: Option<Shared<Handlebars<'static>>>,
+  /// The hashed filenames of the assets, in incremental builds.
+  asset_filenames: IndexMap<String, String>,
+  /// The module of each top-level node, keyed by the address of the node,
+  /// when expanding types.
+  node_modules: HashMap<usize, Shared<ShortPath>>,
 }
 
 impl GenerateCtx {
@@ -297,12 +434,26 @@ impl GenerateCtx {
     file_mode: FileMode,
     doc_nodes_by_url: IndexMap<ModuleSpecifier, Vec<DocNode>>,
   ) -> Result<Self, anyhow::Error> {
+    let messages = options.messages.unwrap_or_default();
+    let handlebars = if options.template_overrides.is_some()
+      || messages != Messages::default()
+    {
+      let mut reg = HANDLEBARS.clone();
+      reg.register_helper("t", Box::new(i18n::TranslateHelper::new(&messages)));
+      if let Some(overrides) = &options.template_overrides {
+        theme::apply_overrides(&mut reg, overrides)?;
+      }
+      Some(Shared::new(reg))
+    } else {
+      None
+    };
+
     let mut main_entrypoint = None;
 
     let doc_nodes = doc_nodes_by_url
       .into_iter()
       .map(|(specifier, nodes)| {
-        let short_path = Rc::new(ShortPath::new(
+        let short_path = Shared::new(ShortPath::new(
           specifier,
           options.main_entrypoint.as_ref(),
           options.rewrite_map.as_ref(),
@@ -365,7 +516,7 @@ impl GenerateCtx {
 
             DocNodeWithContext {
               origin: short_path.clone(),
-              ns_qualifiers: Rc::new([]),
+              ns_qualifiers: Shared::new([]),
               kind: DocNodeKind::from_node(&node),
               inner: Arc::new(node),
               drilldown_name: None,
@@ -376,7 +527,8 @@ impl GenerateCtx {
           .map(|mut node| {
             fn handle_node(node: &mut DocNodeWithContext) {
               let children = if let Some(ns) = node.namespace_def() {
-                let subqualifier: Rc<[String]> = node.sub_qualifier().into();
+                let subqualifier: Shared<[String]> =
+                  node.sub_qualifier().into();
                 Some(
                   ns.elements
                     .iter()
@@ -407,6 +559,55 @@ impl GenerateCtx {
       })
       .collect::<IndexMap<_, _>>();
 
+    let source_files = options
+      .source_files
+      .unwrap_or_default()
+      .into_iter()
+      .filter_map(|(specifier, text)| {
+        let short_path = doc_nodes
+          .keys()
+          .find(|short_path| short_path.specifier == specifier)
+          .cloned()
+          .or_else(|| {
+            // modules outside of the common ancestor have no place in the
+            // output
+            let in_output =
+              options.rewrite_map.as_ref().is_some_and(|rewrite_map| {
+                rewrite_map.contains_key(&specifier)
+              }) || common_ancestor.as_ref().is_some_and(|common_ancestor| {
+                deno_path_util::url_to_file_path(&specifier)
+                  .is_ok_and(|path| path.starts_with(common_ancestor))
+              });
+
+            in_output.then(|| {
+              Shared::new(ShortPath::new(
+                specifier.clone(),
+                options.main_entrypoint.as_ref(),
+                options.rewrite_map.as_ref(),
+                common_ancestor.as_ref(),
+              ))
+            })
+          })?;
+
+        Some((specifier, SourceFile { short_path, text }))
+      })
+      .collect();
+
+    let reverse_index = ReverseIndex::new(&doc_nodes);
+
+    let node_modules = if options.expand_types {
+      doc_nodes
+        .iter()
+        .flat_map(|(short_path, nodes)| {
+          nodes
+            .iter()
+            .map(|node| (Arc::as_ptr(&node.inner) as usize, short_path.clone()))
+        })
+        .collect()
+    } else {
+      HashMap::new()
+    };
+
     Ok(Self {
       package_name: options.package_name,
       common_ancestor,
@@ -424,9 +625,30 @@ impl GenerateCtx {
       markdown_stripper: options.markdown_stripper,
       head_inject: options.head_inject,
       id_prefix: options.id_prefix,
+      messages,
+      versions: options.versions,
+      source_files,
+      reverse_index,
+      class_hierarchy: options.class_hierarchy,
+      full_text_search: options.full_text_search,
+      base_url: options.base_url,
+      inventories: options.inventories,
+      expand_types: options.expand_types,
+      handlebars,
+      asset_filenames: IndexMap::new(),
+      node_modules,
     })
   }
 
+  /// Gets the module of a top-level node of [`Self::doc_nodes`], if types
+  /// are expanded.
+  pub(crate) fn node_module(&self, node: &DocNode) -> Option<&ShortPath> {
+    self
+      .node_modules
+      .get(&(node as *const DocNode as usize))
+      .map(|short_path| &**short_path)
+  }
+
   pub fn create_basic(
     mut options: GenerateOptions,
     doc_nodes_by_url: IndexMap<ModuleSpecifier, Vec<DocNode>>,
@@ -453,12 +675,48 @@ impl GenerateCtx {
     GenerateCtx::new(options, common_ancestor, file_mode, doc_nodes_by_url)
   }
 
+  /// The filename under which the asset is written, which is hashed in
+  /// incremental builds.
+  pub fn asset_filename<'a>(&'a self, filename: &'a str) -> &'a str {
+    self
+      .asset_filenames
+      .get(filename)
+      .map(String::as_str)
+      .unwrap_or(filename)
+  }
+
   pub fn render<T: serde::Serialize>(
     &self,
     template: &str,
     data: &T,
-  ) -> String {
-    HANDLEBARS.render(template, data).unwrap()
+  ) -> Result<String, anyhow::Error> {
+    self
+      .handlebars
+      .as_deref()
+      .unwrap_or(&HANDLEBARS)
+      .render(template, data)
+      .map_err(theme::render_error)
+  }
+
+  /// Resolves the link to the source of a declaration, preferring the
+  /// generated source pages over [`HrefResolver::resolve_source`].
+  pub fn resolve_source(
+    &self,
+    current: UrlResolveKind,
+    location: &crate::Location,
+  ) -> Option<String> {
+    if let Ok(specifier) = ModuleSpecifier::parse(&location.filename)
+      && let Some(source_file) = self.source_files.get(&specifier)
+    {
+      return Some(format!(
+        "{}{}.html#L{}",
+        self.resolve_path(current, UrlResolveKind::Root),
+        source_file.path(),
+        location.line
+      ));
+    }
+
+    self.href_resolver.resolve_source(location)
   }
 
   pub fn resolve_path(
@@ -746,8 +1004,8 @@ impl DocNodeKind {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DocNodeWithContext {
-  pub origin: Rc<ShortPath>,
-  pub ns_qualifiers: Rc<[String]>,
+  pub origin: Shared<ShortPath>,
+  pub ns_qualifiers: Shared<[String]>,
   pub kind: DocNodeKind,
   pub inner: Arc<DocNode>,
   pub drilldown_name: Option<Box<str>>,
@@ -771,7 +1029,7 @@ impl DocNodeWithContext {
   pub fn create_namespace_child(
     &self,
     doc_node: Arc<DocNode>,
-    qualifiers: Rc<[String]>,
+    qualifiers: Shared<[String]>,
   ) -> Self {
     let mut child = self.create_child(doc_node);
     child.ns_qualifiers = qualifiers;
@@ -968,49 +1226,134 @@ pub enum FileMode {
   SingleDts,
 }
 
+/// The index page of the main entrypoint, partitioned by category if it uses
+/// any, or an empty index page if there is no main entrypoint.
+fn main_index_ctx(ctx: &GenerateCtx) -> pages::IndexCtx {
+  let (partitions_for_entrypoint_nodes, uses_categories) =
+    if let Some(entrypoint) = ctx.main_entrypoint.as_ref() {
+      let nodes = ctx.doc_nodes.get(entrypoint).unwrap();
+      let categories = partition::partition_nodes_by_category(
+        ctx,
+        nodes.iter().map(Cow::Borrowed),
+        ctx.file_mode == FileMode::SingleDts,
+      );
+
+      if categories.len() == 1 && categories.contains_key("Uncategorized") {
+        (
+          partition::partition_nodes_by_kind(
+            ctx,
+            nodes.iter().map(Cow::Borrowed),
+            ctx.file_mode == FileMode::SingleDts,
+          ),
+          false,
+        )
+      } else {
+        (categories, true)
+      }
+    } else {
+      Default::default()
+    };
+
+  pages::IndexCtx::new(
+    ctx,
+    ctx.main_entrypoint.clone(),
+    partitions_for_entrypoint_nodes,
+    uses_categories,
+  )
+}
+
+/// Generates the documentation as a single self-contained HTML file, with the
+/// stylesheets and scripts inlined and the links between pages resolved to
+/// fragments of the page by a [`SinglePageHrefResolver`]. The search, the
+/// source pages and the version switcher are not available in this mode.
+pub fn generate_single_page(
+  mut ctx: GenerateCtx,
+) -> Result<String, anyhow::Error> {
+  ctx.href_resolver = Shared::new(SinglePageHrefResolver {
+    inner: ctx.href_resolver.clone(),
+    lang: ctx.messages.lang.clone(),
+  });
+  ctx.disable_search = true;
+  // the redirects and source pages point to other files
+  ctx.symbol_redirect_map = None;
+  ctx.source_files.clear();
+
+  let single_page = SinglePageCtx::new(&ctx);
+  ctx.render(SinglePageCtx::TEMPLATE, &single_page)
+}
+
 pub fn generate(
   ctx: GenerateCtx,
 ) -> Result<HashMap<String, String>, anyhow::Error> {
   let mut files = HashMap::new();
+  generate_to_sink(ctx, &mut files)?;
+  Ok(files)
+}
 
-  // Index page
+/// Like [`generate`], but writes each file to the sink as soon as it is
+/// rendered, instead of holding all of them in memory.
+pub fn generate_to_sink(
+  ctx: GenerateCtx,
+  sink: &mut dyn OutputSink,
+) -> Result<(), anyhow::Error> {
+  let search_index_files = search::get_search_index_files(&ctx, false)?;
+  generate_files(&ctx, &mut output::PageSink::new(sink), search_index_files)
+}
+
+/// Like [`generate_to_sink`], but only renders and writes the files which
+/// changed since the previous build, and emits the stylesheet, script and
+/// search index, including its chunks, under hashed filenames, for long-term
+/// caching.
+///
+/// The files of the previous build whose inputs are unchanged are neither
+/// rendered nor written to the sink, so the sink is expected to hold the
+/// output of the previous build, such as the same directory. The returned
+/// manifest is to be passed as the previous build to the next one.
+pub fn generate_incremental(
+  mut ctx: GenerateCtx,
+  previous: Option<&BuildManifest>,
+  sink: &mut dyn OutputSink,
+) -> Result<IncrementalOutput, anyhow::Error> {
+  let search_index_files = search::get_search_index_files(&ctx, true)?;
+
+  let mut hash_asset = |name: &'static str, content: &str| {
+    ctx.asset_filenames.insert(
+      name.to_string(),
+      incremental::hashed_filename(name, content),
+    );
+  };
+  hash_asset(STYLESHEET_FILENAME, STYLESHEET);
+  hash_asset(SCRIPT_FILENAME, SCRIPT_JS);
+  // the search index lists the hashed filenames of its chunks, so its hash
+  // changes along with any of them
+  if let Some((name, content)) = search_index_files.first()
+    && name == SEARCH_INDEX_FILENAME
   {
-    let (partitions_for_entrypoint_nodes, uses_categories) =
-      if let Some(entrypoint) = ctx.main_entrypoint.as_ref() {
-        let nodes = ctx.doc_nodes.get(entrypoint).unwrap();
-        let categories = partition::partition_nodes_by_category(
-          &ctx,
-          nodes.iter().map(Cow::Borrowed),
-          ctx.file_mode == FileMode::SingleDts,
-        );
+    hash_asset(SEARCH_INDEX_FILENAME, content);
+  }
 
-        if categories.len() == 1 && categories.contains_key("Uncategorized") {
-          (
-            partition::partition_nodes_by_kind(
-              &ctx,
-              nodes.iter().map(Cow::Borrowed),
-              ctx.file_mode == FileMode::SingleDts,
-            ),
-            false,
-          )
-        } else {
-          (categories, true)
-        }
-      } else {
-        Default::default()
-      };
+  let tracker = incremental::Tracker::new(&ctx, previous);
+  let mut sink = output::PageSink::new_incremental(sink, tracker);
+  generate_files(&ctx, &mut sink, search_index_files)?;
+  Ok(sink.finish().unwrap_or_default())
+}
 
-    let index = pages::IndexCtx::new(
-      &ctx,
-      ctx.main_entrypoint.clone(),
-      partitions_for_entrypoint_nodes,
-      uses_categories,
-    );
+fn generate_files(
+  ctx: &GenerateCtx,
+  sink: &mut output::PageSink,
+  search_index_files: Vec<(String, String)>,
+) -> Result<(), anyhow::Error> {
+  let all_modules_inputs = incremental::all_modules_inputs(ctx);
 
-    files.insert(
+  // Index page
+  if !sink.keep_unchanged("./index.html", &all_modules_inputs, true) {
+    let index = main_index_ctx(ctx);
+
+    sink.write_page(
       "./index.html".to_string(),
-      ctx.render(pages::IndexCtx::TEMPLATE, &index),
-    );
+      &ctx.render(pages::IndexCtx::TEMPLATE, &index)?,
+      &all_modules_inputs,
+    )?;
   }
 
   let all_doc_nodes = ctx
@@ -1021,47 +1364,57 @@ pub fn generate(
     .collect::<Vec<DocNodeWithContext>>();
 
   // All symbols (list of all symbols in all files)
-  {
+  if !sink.keep_unchanged("./all_symbols.html", &all_modules_inputs, true) {
     let partitions_by_kind = partition::partition_nodes_by_entrypoint(
-      &ctx,
+      ctx,
       all_doc_nodes.iter().map(Cow::Borrowed),
       true,
     );
 
-    let all_symbols = pages::AllSymbolsCtx::new(&ctx, partitions_by_kind);
+    let all_symbols = pages::AllSymbolsCtx::new(ctx, partitions_by_kind);
 
-    files.insert(
+    sink.write_page(
       "./all_symbols.html".to_string(),
-      ctx.render(pages::AllSymbolsCtx::TEMPLATE, &all_symbols),
-    );
+      &ctx.render(pages::AllSymbolsCtx::TEMPLATE, &all_symbols)?,
+      &all_modules_inputs,
+    )?;
   }
 
   // Category pages
   if ctx.file_mode == FileMode::SingleDts {
     let categories = partition::partition_nodes_by_category(
-      &ctx,
+      ctx,
       all_doc_nodes.iter().map(Cow::Borrowed),
       true,
     );
 
     if categories.len() != 1 {
       for (category, nodes) in &categories {
+        let file_name = format!(
+          "{}.html",
+          util::slugify_localized(category, &ctx.messages.lang)
+        );
+        if sink.keep_unchanged(&file_name, &all_modules_inputs, true) {
+          continue;
+        }
+
         let partitions = partition::partition_nodes_by_kind(
-          &ctx,
+          ctx,
           nodes.iter().map(Cow::Borrowed),
           false,
         );
 
         let index = pages::IndexCtx::new_category(
-          &ctx,
+          ctx,
           category,
           partitions,
           &all_doc_nodes,
         );
-        files.insert(
-          format!("{}.html", util::slugify(category)),
-          ctx.render(pages::IndexCtx::TEMPLATE, &index),
-        );
+        sink.write_page(
+          file_name,
+          &ctx.render(pages::IndexCtx::TEMPLATE, &index)?,
+          &all_modules_inputs,
+        )?;
       }
     }
   }
@@ -1069,42 +1422,59 @@ pub fn generate(
   // Pages for all discovered symbols
   {
     for (short_path, doc_nodes) in &ctx.doc_nodes {
-      let doc_nodes_by_kind = partition::partition_nodes_by_kind(
-        &ctx,
-        doc_nodes.iter().map(Cow::Borrowed),
-        ctx.file_mode == FileMode::SingleDts,
+      let symbol_inputs =
+        incremental::symbol_page_inputs(ctx, short_path, doc_nodes);
+      let symbol_pages = pages::generate_symbol_pages_for_module_filtered(
+        ctx,
+        short_path,
+        doc_nodes,
+        |name| {
+          let file_name = format!("{}/~/{name}.html", short_path.path);
+          if !sink.keep_unchanged(&file_name, &symbol_inputs, true) {
+            return false;
+          }
+          // the redirect of a class is generated with its page
+          sink.keep_unchanged(
+            &format!("{}/~/{name}.prototype.html", short_path.path),
+            &symbol_inputs,
+            false,
+          );
+          true
+        },
       );
 
-      let symbol_pages =
-        generate_symbol_pages_for_module(&ctx, short_path, doc_nodes);
-
-      files.extend(symbol_pages.into_iter().flat_map(|symbol_page| {
+      // the pages are rendered in parallel, but written in order to keep the
+      // output deterministic
+      let rendered_pages = crate::util::par_map(symbol_pages, |symbol_page| {
         match symbol_page {
           SymbolPage::Symbol {
             breadcrumbs_ctx,
             symbol_group_ctx,
             toc_ctx,
             categories_panel,
+            description,
           } => {
-            let root = ctx.resolve_path(
-              UrlResolveKind::Symbol {
-                file: short_path,
-                symbol: &symbol_group_ctx.name,
-              },
-              UrlResolveKind::Root,
-            );
+            let current_resolve = UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &symbol_group_ctx.name,
+            };
+            let root = ctx.resolve_path(current_resolve, UrlResolveKind::Root);
 
             let mut title_parts = breadcrumbs_ctx.to_strings();
             title_parts.reverse();
             // contains the package name, which we already render in the head
             title_parts.pop();
 
-            let html_head_ctx = pages::HtmlHeadCtx::new(
-              &ctx,
+            let mut html_head_ctx = pages::HtmlHeadCtx::new(
+              ctx,
+              current_resolve,
               &root,
               Some(&title_parts.join(" - ")),
               Some(short_path),
             );
+            html_head_ctx.description = description;
+            let version_switcher =
+              VersionSwitcherCtx::new(ctx, current_resolve);
 
             let file_name =
               format!("{}/~/{}.html", short_path.path, symbol_group_ctx.name);
@@ -1116,12 +1486,11 @@ pub fn generate(
               toc_ctx,
               disable_search: ctx.disable_search,
               categories_panel,
+              version_switcher,
             };
 
-            let symbol_page =
-              ctx.render(pages::SymbolPageCtx::TEMPLATE, &page_ctx);
-
-            vec![(file_name, symbol_page)]
+            let content = ctx.render(pages::SymbolPageCtx::TEMPLATE, &page_ctx);
+            content.map(|content| (file_name, content, true))
           }
           SymbolPage::Redirect {
             current_symbol,
@@ -1133,88 +1502,145 @@ pub fn generate(
             let file_name =
               format!("{}/~/{}.html", short_path.path, current_symbol);
 
-            vec![(file_name, ctx.render("pages/redirect", &redirect))]
+            // redirects are not listed in the sitemap
+            ctx
+              .render("pages/redirect", &redirect)
+              .map(|content| (file_name, content, false))
           }
         }
-      }));
+      });
 
-      if !short_path.is_main {
+      for rendered_page in rendered_pages {
+        let (file_name, content, is_page) = rendered_page?;
+        if is_page {
+          sink.write_page(file_name, &content, &symbol_inputs)?;
+        } else {
+          sink.write_unlisted(&file_name, &content, &symbol_inputs)?;
+        }
+      }
+
+      let index_file_name = format!("{}/index.html", short_path.path);
+      let index_inputs = [
+        incremental::GLOBAL_INPUT.to_string(),
+        incremental::module_input(short_path),
+      ];
+      if !short_path.is_main
+        && !sink.keep_unchanged(&index_file_name, &index_inputs, true)
+      {
+        let doc_nodes_by_kind = partition::partition_nodes_by_kind(
+          ctx,
+          doc_nodes.iter().map(Cow::Borrowed),
+          ctx.file_mode == FileMode::SingleDts,
+        );
         let index = pages::IndexCtx::new(
-          &ctx,
+          ctx,
           Some(short_path.clone()),
           doc_nodes_by_kind,
           false,
         );
 
-        files.insert(
-          format!("{}/index.html", short_path.path),
-          ctx.render(pages::IndexCtx::TEMPLATE, &index),
-        );
+        sink.write_page(
+          index_file_name,
+          &ctx.render(pages::IndexCtx::TEMPLATE, &index)?,
+          &index_inputs,
+        )?;
       }
     }
   }
 
-  files.insert(STYLESHEET_FILENAME.into(), STYLESHEET.into());
-  files.insert(
-    SEARCH_INDEX_FILENAME.into(),
-    search::get_search_index_file(&ctx)?,
-  );
-  files.insert(SCRIPT_FILENAME.into(), SCRIPT_JS.into());
+  // Source pages
+  for source_file in ctx.source_files.values() {
+    let file_name = format!("{}.html", source_file.path());
+    let inputs = [
+      incremental::GLOBAL_INPUT.to_string(),
+      incremental::source_input(&source_file.short_path),
+    ];
+    if sink.keep_unchanged(&file_name, &inputs, true) {
+      continue;
+    }
+
+    let source_page = SourcePageCtx::new(ctx, source_file);
+
+    sink.write_page(
+      file_name,
+      &ctx.render(SourcePageCtx::TEMPLATE, &source_page)?,
+      &inputs,
+    )?;
+  }
+
+  if ctx.class_hierarchy
+    && !sink.keep_unchanged(HIERARCHY_FILENAME, &all_modules_inputs, true)
+  {
+    let hierarchy_page = HierarchyPageCtx::new(ctx);
+
+    sink.write_page(
+      HIERARCHY_FILENAME.to_string(),
+      &ctx.render(HierarchyPageCtx::TEMPLATE, &hierarchy_page)?,
+      &all_modules_inputs,
+    )?;
+  }
 
-  files.insert(PAGE_STYLESHEET_FILENAME.into(), PAGE_STYLESHEET.into());
-  files.insert(RESET_STYLESHEET_FILENAME.into(), RESET_STYLESHEET.into());
-  files.insert(FUSE_FILENAME.into(), FUSE_JS.into());
-  files.insert(SEARCH_FILENAME.into(), SEARCH_JS.into());
-  files.insert(DARKMODE_TOGGLE_FILENAME.into(), DARKMODE_TOGGLE_JS.into());
+  let pages = sink.take_pages();
+  for (name, content) in
+    seo::get_seo_files(ctx, pages.iter().map(String::as_str))
+  {
+    sink.write(&name, &content)?;
+  }
+
+  sink.write(ctx.asset_filename(STYLESHEET_FILENAME), STYLESHEET)?;
+  for (name, content) in search_index_files {
+    sink.write(ctx.asset_filename(&name), &content)?;
+  }
+  sink.write(ctx.asset_filename(SCRIPT_FILENAME), SCRIPT_JS)?;
+  sink.write(
+    INVENTORY_FILENAME,
+    &serde_json::to_string(&Inventory::new(ctx))?,
+  )?;
+
+  sink.write(PAGE_STYLESHEET_FILENAME, PAGE_STYLESHEET)?;
+  sink.write(RESET_STYLESHEET_FILENAME, RESET_STYLESHEET)?;
+  sink.write(SEARCH_FILENAME, SEARCH_JS)?;
+  sink.write(DARKMODE_TOGGLE_FILENAME, DARKMODE_TOGGLE_JS)?;
   #[cfg(feature = "comrak")]
-  files.insert(
-    comrak::COMRAK_STYLESHEET_FILENAME.into(),
-    comrak::COMRAK_STYLESHEET.into(),
-  );
+  sink.write(
+    comrak::COMRAK_STYLESHEET_FILENAME,
+    comrak::COMRAK_STYLESHEET,
+  )?;
 
-  Ok(files)
+  Ok(())
 }
 
 pub fn generate_json(
   ctx: GenerateCtx,
 ) -> Result<HashMap<String, serde_json::Value>, anyhow::Error> {
   let mut files = HashMap::new();
+  generate_json_with(ctx, &mut |name, value| {
+    files.insert(name, value);
+    Ok(())
+  })?;
+  Ok(files)
+}
 
+/// Like [`generate_json`], but writes each file to the sink as soon as it is
+/// rendered, instead of holding all of them in memory.
+pub fn generate_json_to_sink(
+  ctx: GenerateCtx,
+  sink: &mut dyn OutputSink,
+) -> Result<(), anyhow::Error> {
+  generate_json_with(ctx, &mut |name, value| {
+    sink.write(&name, &serde_json::to_string(&value)?)
+  })
+}
+
+fn generate_json_with(
+  ctx: GenerateCtx,
+  emit: &mut dyn FnMut(String, serde_json::Value) -> Result<(), anyhow::Error>,
+) -> Result<(), anyhow::Error> {
   // Index page
   {
-    let (partitions_for_entrypoint_nodes, uses_categories) =
-      if let Some(entrypoint) = ctx.main_entrypoint.as_ref() {
-        let nodes = ctx.doc_nodes.get(entrypoint).unwrap();
-        let categories = partition::partition_nodes_by_category(
-          &ctx,
-          nodes.iter().map(Cow::Borrowed),
-          ctx.file_mode == FileMode::SingleDts,
-        );
+    let index = main_index_ctx(&ctx);
 
-        if categories.len() == 1 && categories.contains_key("Uncategorized") {
-          (
-            partition::partition_nodes_by_kind(
-              &ctx,
-              nodes.iter().map(Cow::Borrowed),
-              ctx.file_mode == FileMode::SingleDts,
-            ),
-            false,
-          )
-        } else {
-          (categories, true)
-        }
-      } else {
-        Default::default()
-      };
-
-    let index = pages::IndexCtx::new(
-      &ctx,
-      ctx.main_entrypoint.clone(),
-      partitions_for_entrypoint_nodes,
-      uses_categories,
-    );
-
-    files.insert("./index.json".to_string(), serde_json::to_value(index)?);
+    emit("./index.json".to_string(), serde_json::to_value(index)?)?;
   }
 
   let all_doc_nodes = ctx
@@ -1234,10 +1660,10 @@ pub fn generate_json(
 
     let all_symbols = pages::AllSymbolsCtx::new(&ctx, partitions_by_kind);
 
-    files.insert(
+    emit(
       "./all_symbols.json".to_string(),
       serde_json::to_value(all_symbols)?,
-    );
+    )?;
   }
 
   // Category pages
@@ -1262,10 +1688,13 @@ pub fn generate_json(
           partitions,
           &all_doc_nodes,
         );
-        files.insert(
-          format!("{}.json", util::slugify(category)),
+        emit(
+          format!(
+            "{}.json",
+            util::slugify_localized(category, &ctx.messages.lang)
+          ),
           serde_json::to_value(index)?,
-        );
+        )?;
       }
     }
   }
@@ -1282,33 +1711,36 @@ pub fn generate_json(
       let symbol_pages =
         generate_symbol_pages_for_module(&ctx, short_path, doc_nodes);
 
-      files.extend(symbol_pages.into_iter().flat_map(|symbol_page| {
+      for symbol_page in symbol_pages {
         match symbol_page {
           SymbolPage::Symbol {
             breadcrumbs_ctx,
             symbol_group_ctx,
             toc_ctx,
             categories_panel,
+            description,
           } => {
-            let root = ctx.resolve_path(
-              UrlResolveKind::Symbol {
-                file: short_path,
-                symbol: &symbol_group_ctx.name,
-              },
-              UrlResolveKind::Root,
-            );
+            let current_resolve = UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &symbol_group_ctx.name,
+            };
+            let root = ctx.resolve_path(current_resolve, UrlResolveKind::Root);
 
             let mut title_parts = breadcrumbs_ctx.to_strings();
             title_parts.reverse();
             // contains the package name, which we already render in the head
             title_parts.pop();
 
-            let html_head_ctx = pages::HtmlHeadCtx::new(
+            let mut html_head_ctx = pages::HtmlHeadCtx::new(
               &ctx,
+              current_resolve,
               &root,
               Some(&title_parts.join(" - ")),
               Some(short_path),
             );
+            html_head_ctx.description = description;
+            let version_switcher =
+              VersionSwitcherCtx::new(&ctx, current_resolve);
 
             let file_name =
               format!("{}/~/{}.json", short_path.path, symbol_group_ctx.name);
@@ -1320,9 +1752,10 @@ pub fn generate_json(
               toc_ctx,
               disable_search: ctx.disable_search,
               categories_panel,
+              version_switcher,
             };
 
-            vec![(file_name, serde_json::to_value(page_ctx).unwrap())]
+            emit(file_name, serde_json::to_value(page_ctx)?)?;
           }
           SymbolPage::Redirect {
             current_symbol,
@@ -1333,10 +1766,10 @@ pub fn generate_json(
             let file_name =
               format!("{}/~/{}.json", short_path.path, current_symbol);
 
-            vec![(file_name, redirect)]
+            emit(file_name, redirect)?;
           }
         }
-      }));
+      }
 
       if !short_path.is_main {
         let index = pages::IndexCtx::new(
@@ -1346,17 +1779,36 @@ pub fn generate_json(
           false,
         );
 
-        files.insert(
+        emit(
           format!("{}/index.json", short_path.path),
           serde_json::to_value(index)?,
-        );
+        )?;
       }
     }
   }
 
-  files.insert("search.json".into(), generate_search_index(&ctx));
+  // Source pages
+  for source_file in ctx.source_files.values() {
+    let source_page = SourcePageCtx::new(&ctx, source_file);
 
-  Ok(files)
+    emit(
+      format!("{}.json", source_file.path()),
+      serde_json::to_value(source_page)?,
+    )?;
+  }
+
+  if ctx.class_hierarchy {
+    let hierarchy_page = HierarchyPageCtx::new(&ctx);
+
+    emit(
+      "hierarchy.json".to_string(),
+      serde_json::to_value(hierarchy_page)?,
+    )?;
+  }
+
+  emit("search.json".into(), generate_search_index(&ctx))?;
+
+  Ok(())
 }
 
 pub fn find_common_ancestor<'a>(
@@ -1408,6 +1860,71 @@ pub fn find_common_ancestor<'a>(
 mod test {
   use super::*;
 
+  #[test]
+  fn builtin_templates_context_fields() {
+    for (name, template) in HANDLEBARS.get_templates() {
+      assert!(
+        theme::context_fields(name).is_some(),
+        "missing context fields of \"{name}\""
+      );
+      theme::check_fields(name, template, |_| false).unwrap();
+    }
+  }
+
+  #[test]
+  fn search_shard_key() {
+    assert_eq!(search::shard_key("readfile"), 'r');
+    assert_eq!(search::shard_key("ファイル"), 'フ');
+    assert_eq!(search::shard_key("_private"), '_');
+  }
+
+  #[test]
+  fn slugify_locales() {
+    assert_eq!(util::slugify("Web APIs"), "web-apis");
+    assert_eq!(util::slugify("Web\tAPIs（ＦＳ）"), "webapisｆｓ");
+    assert_eq!(
+      util::slugify_localized("Web\tAPIs（ＦＳ）", "en"),
+      "webapisｆｓ"
+    );
+    assert_eq!(
+      util::slugify_localized("Größe ändern", "de-DE"),
+      "groesse-aendern"
+    );
+    assert_eq!(
+      util::slugify_localized("Größe ändern", "en"),
+      "größe-ändern"
+    );
+    assert_eq!(util::slugify_localized("IŞIK İzmir", "tr"), "ışık-izmir");
+    assert_eq!(
+      util::slugify_localized("ファイル　システム（ＦＳ）", "ja"),
+      "ファイル-システムfs"
+    );
+  }
+
+  #[test]
+  fn category_href_lang() {
+    let category = UrlResolveKind::Category {
+      category: "Größe ändern",
+    };
+    assert_eq!(
+      href_path_resolve_localized(UrlResolveKind::Root, category, "de"),
+      "./groesse-aendern.html"
+    );
+    assert_eq!(
+      href_path_resolve(UrlResolveKind::AllSymbols, category),
+      "././größe-ändern.html"
+    );
+  }
+
+  #[test]
+  fn hashed_filename_stable() {
+    // the manifest and the asset filenames persist across toolchain upgrades
+    assert_eq!(
+      incremental::hashed_filename("styles.css", "body {}"),
+      "styles.f4cabedf1de01dc9.css"
+    );
+  }
+
   #[cfg(not(windows))]
   #[test]
   fn common_ancestor_root() {
@@ -0,0 +1,241 @@
+use super::incremental::IncrementalOutput;
+use super::incremental::Tracker;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+/// A destination for the files of [`generate_to_sink`] and
+/// [`generate_json_to_sink`], which receives each file as soon as it is
+/// rendered.
+///
+/// [`generate_to_sink`]: super::generate_to_sink
+/// [`generate_json_to_sink`]: super::generate_json_to_sink
+pub trait OutputSink {
+  /// Writes the file at the given path, relative to the root of the output.
+  fn write(&mut self, path: &str, content: &str) -> Result<(), anyhow::Error>;
+}
+
+/// Collects the files in memory, keyed by their path.
+impl OutputSink for HashMap<String, String> {
+  fn write(&mut self, path: &str, content: &str) -> Result<(), anyhow::Error> {
+    self.insert(path.to_string(), content.to_string());
+    Ok(())
+  }
+}
+
+/// Writes the files into a directory, creating the subdirectories as needed.
+pub struct DirectorySink {
+  path: PathBuf,
+}
+
+impl DirectorySink {
+  pub fn new(path: impl Into<PathBuf>) -> Self {
+    Self { path: path.into() }
+  }
+}
+
+impl OutputSink for DirectorySink {
+  fn write(&mut self, path: &str, content: &str) -> Result<(), anyhow::Error> {
+    let path = self.path.join(path);
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+  }
+}
+
+/// Writes the files into a zip archive. The archive is complete once
+/// [`ZipSink::finish`] has been called.
+#[cfg(feature = "zip")]
+pub struct ZipSink<W: std::io::Write + std::io::Seek> {
+  writer: zip::ZipWriter<W>,
+}
+
+#[cfg(feature = "zip")]
+impl<W: std::io::Write + std::io::Seek> ZipSink<W> {
+  pub fn new(writer: W) -> Self {
+    Self {
+      writer: zip::ZipWriter::new(writer),
+    }
+  }
+
+  /// Writes the central directory of the archive, and returns the writer.
+  pub fn finish(self) -> Result<W, anyhow::Error> {
+    Ok(self.writer.finish()?)
+  }
+}
+
+#[cfg(feature = "zip")]
+impl<W: std::io::Write + std::io::Seek> OutputSink for ZipSink<W> {
+  fn write(&mut self, path: &str, content: &str) -> Result<(), anyhow::Error> {
+    use std::io::Write;
+
+    self.writer.start_file(
+      path.trim_start_matches("./"),
+      zip::write::SimpleFileOptions::default(),
+    )?;
+    self.writer.write_all(content.as_bytes())?;
+    Ok(())
+  }
+}
+
+/// The progress reported by [`ProgressSink`] after each file.
+#[derive(Debug, Clone)]
+pub struct PageProgress<'a> {
+  pub path: &'a str,
+  /// The number of files written so far, including this one.
+  pub count: usize,
+  /// The time spent rendering the file, since the previous file was written.
+  pub render_time: Duration,
+  /// The time spent writing the file to the wrapped sink.
+  pub write_time: Duration,
+}
+
+/// Wraps another sink, and reports the progress with the time spent
+/// rendering and writing each file.
+pub struct ProgressSink<S, F> {
+  inner: S,
+  on_progress: F,
+  count: usize,
+  last_write: Instant,
+}
+
+impl<S: OutputSink, F: FnMut(PageProgress)> ProgressSink<S, F> {
+  pub fn new(inner: S, on_progress: F) -> Self {
+    Self {
+      inner,
+      on_progress,
+      count: 0,
+      last_write: Instant::now(),
+    }
+  }
+
+  pub fn into_inner(self) -> S {
+    self.inner
+  }
+}
+
+impl<S: OutputSink, F: FnMut(PageProgress)> OutputSink for ProgressSink<S, F> {
+  fn write(&mut self, path: &str, content: &str) -> Result<(), anyhow::Error> {
+    let start = Instant::now();
+    self.inner.write(path, content)?;
+    let end = Instant::now();
+
+    self.count += 1;
+    (self.on_progress)(PageProgress {
+      path,
+      count: self.count,
+      render_time: start - self.last_write,
+      write_time: end - start,
+    });
+    self.last_write = end;
+
+    Ok(())
+  }
+}
+
+/// Records the pages written to the sink, to be listed in the sitemap, and
+/// tracks the files of an incremental build.
+pub(crate) struct PageSink<'a> {
+  sink: &'a mut dyn OutputSink,
+  pages: Vec<String>,
+  tracker: Option<Tracker<'a>>,
+}
+
+impl<'a> PageSink<'a> {
+  pub fn new(sink: &'a mut dyn OutputSink) -> Self {
+    Self {
+      sink,
+      pages: vec![],
+      tracker: None,
+    }
+  }
+
+  pub fn new_incremental(
+    sink: &'a mut dyn OutputSink,
+    tracker: Tracker<'a>,
+  ) -> Self {
+    Self {
+      sink,
+      pages: vec![],
+      tracker: Some(tracker),
+    }
+  }
+
+  /// Writes a file which is not a page, such as an asset.
+  pub fn write(
+    &mut self,
+    path: &str,
+    content: &str,
+  ) -> Result<(), anyhow::Error> {
+    self.write_file(path, content, &[], false)
+  }
+
+  /// Writes a page which is not listed in the sitemap, such as a redirect.
+  pub fn write_unlisted(
+    &mut self,
+    path: &str,
+    content: &str,
+    inputs: &[String],
+  ) -> Result<(), anyhow::Error> {
+    self.write_file(path, content, inputs, false)
+  }
+
+  pub fn write_page(
+    &mut self,
+    path: String,
+    content: &str,
+    inputs: &[String],
+  ) -> Result<(), anyhow::Error> {
+    self.write_file(&path, content, inputs, true)?;
+    self.pages.push(path);
+    Ok(())
+  }
+
+  fn write_file(
+    &mut self,
+    path: &str,
+    content: &str,
+    inputs: &[String],
+    page: bool,
+  ) -> Result<(), anyhow::Error> {
+    if let Some(tracker) = &mut self.tracker
+      && !tracker.record(path, content, inputs, page)
+    {
+      return Ok(());
+    }
+
+    self.sink.write(path, content)
+  }
+
+  /// Keeps the file of the previous build, without rendering it again, if
+  /// its inputs are unchanged. Always false outside of incremental builds.
+  pub fn keep_unchanged(
+    &mut self,
+    path: &str,
+    inputs: &[String],
+    page: bool,
+  ) -> bool {
+    let Some(tracker) = &mut self.tracker else {
+      return false;
+    };
+    if !tracker.keep_unchanged(path, inputs) {
+      return false;
+    }
+
+    if page {
+      self.pages.push(path.to_string());
+    }
+    true
+  }
+
+  pub fn take_pages(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.pages)
+  }
+
+  pub fn finish(self) -> Option<IncrementalOutput> {
+    self.tracker.map(Tracker::finish)
+  }
+}
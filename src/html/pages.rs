@@ -11,12 +11,12 @@ use super::util;
 use super::util::AnchorCtx;
 use super::util::BreadcrumbsCtx;
 use super::util::SectionHeaderCtx;
+use super::versions::VersionSwitcherCtx;
+use crate::html::Shared;
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::rc::Rc;
 
 use super::DARKMODE_TOGGLE_FILENAME;
-use super::FUSE_FILENAME;
 use super::PAGE_STYLESHEET_FILENAME;
 use super::RESET_STYLESHEET_FILENAME;
 use super::SCRIPT_FILENAME;
@@ -39,10 +39,14 @@ pub struct HtmlHeadCtx {
   reset_stylesheet_url: String,
   url_search_index: String,
   script_js: String,
-  fuse_js: String,
   search_js: String,
   darkmode_toggle_js: String,
   head_inject: Option<String>,
+  pub(crate) canonical_url: Option<String>,
+  /// The plain text summary of the page, for the meta description and the
+  /// Open Graph and Twitter tags.
+  pub(crate) description: Option<String>,
+  site_name: Option<String>,
   disable_search: bool,
 }
 
@@ -51,33 +55,44 @@ impl HtmlHeadCtx {
 
   pub fn new(
     ctx: &GenerateCtx,
+    current: UrlResolveKind,
     root: &str,
     page: Option<&str>,
     current_file: Option<&ShortPath>,
   ) -> Self {
     Self {
       title: format!(
-        "{}{}documentation",
+        "{}{}",
         page.map(|page| format!("{page} - ")).unwrap_or_default(),
         ctx
-          .package_name
-          .as_ref()
-          .map(|package_name| format!("{package_name} "))
-          .unwrap_or_default()
+          .messages
+          .documentation_title(ctx.package_name.as_deref())
       ),
       current_file: current_file
         .map(|current_file| &*current_file.path)
         .unwrap_or_default()
         .to_string(),
-      stylesheet_url: format!("{root}{STYLESHEET_FILENAME}"),
+      stylesheet_url: format!(
+        "{root}{}",
+        ctx.asset_filename(STYLESHEET_FILENAME)
+      ),
       page_stylesheet_url: format!("{root}{PAGE_STYLESHEET_FILENAME}"),
       reset_stylesheet_url: format!("{root}{RESET_STYLESHEET_FILENAME}"),
-      url_search_index: format!("{root}{SEARCH_INDEX_FILENAME}"),
-      script_js: format!("{root}{SCRIPT_FILENAME}"),
-      fuse_js: format!("{root}{FUSE_FILENAME}"),
+      url_search_index: format!(
+        "{root}{}",
+        ctx.asset_filename(SEARCH_INDEX_FILENAME)
+      ),
+      script_js: format!("{root}{}", ctx.asset_filename(SCRIPT_FILENAME)),
       search_js: format!("{root}{SEARCH_FILENAME}"),
       darkmode_toggle_js: format!("{root}{DARKMODE_TOGGLE_FILENAME}"),
       head_inject: ctx.head_inject.clone().map(|head_inject| head_inject(root)),
+      canonical_url: super::seo::canonical_url(
+        ctx,
+        &ctx.resolve_path(current, UrlResolveKind::Root),
+        &super::versions::page_path(ctx, current),
+      ),
+      description: None,
+      site_name: ctx.package_name.clone(),
       disable_search: ctx.disable_search,
     }
   }
@@ -208,6 +223,7 @@ pub struct IndexCtx {
   pub toc_ctx: util::ToCCtx,
   pub disable_search: bool,
   pub categories_panel: Option<CategoriesPanelCtx>,
+  pub version_switcher: Option<VersionSwitcherCtx>,
 }
 
 impl IndexCtx {
@@ -216,7 +232,7 @@ impl IndexCtx {
   /// short_path is None in the case this is a root index page but there is no main entrypoint
   pub fn new(
     ctx: &GenerateCtx,
-    short_path: Option<Rc<ShortPath>>,
+    short_path: Option<Shared<ShortPath>>,
     partitions: partition::Partitions<String>,
     uses_categories: bool,
   ) -> Self {
@@ -242,8 +258,9 @@ impl IndexCtx {
     let root =
       ctx.resolve_path(render_ctx.get_current_resolve(), UrlResolveKind::Root);
 
-    let html_head_ctx = HtmlHeadCtx::new(
+    let mut html_head_ctx = HtmlHeadCtx::new(
       ctx,
+      render_ctx.get_current_resolve(),
       &root,
       short_path.as_ref().and_then(|short_path| {
         if short_path.is_main {
@@ -254,6 +271,11 @@ impl IndexCtx {
       }),
       None,
     );
+    html_head_ctx.description = doc_nodes
+      .iter()
+      .find(|node| matches!(node.def, DocNodeDef::ModuleDoc))
+      .and_then(|node| node.js_doc.doc.as_deref())
+      .and_then(|doc| super::jsdoc::strip_summary(&render_ctx, doc));
 
     let overview = match ctx.file_mode {
       FileMode::Dts if short_path.is_none() => {
@@ -358,6 +380,8 @@ impl IndexCtx {
     };
 
     let breadcrumbs_ctx = render_ctx.get_breadcrumbs();
+    let version_switcher =
+      VersionSwitcherCtx::new(ctx, render_ctx.get_current_resolve());
 
     let dts_mode = matches!(ctx.file_mode, FileMode::SingleDts | FileMode::Dts);
 
@@ -384,6 +408,7 @@ impl IndexCtx {
       toc_ctx,
       disable_search: ctx.disable_search,
       categories_panel,
+      version_switcher,
     }
   }
 
@@ -425,9 +450,22 @@ impl IndexCtx {
       UrlResolveKind::Root,
     );
 
-    let html_head_ctx = HtmlHeadCtx::new(ctx, &root, Some(name), None);
+    let mut html_head_ctx = HtmlHeadCtx::new(
+      ctx,
+      render_ctx.get_current_resolve(),
+      &root,
+      Some(name),
+      None,
+    );
+    html_head_ctx.description = ctx
+      .category_docs
+      .as_ref()
+      .and_then(|category_docs| category_docs.get(name)?.as_deref())
+      .and_then(|doc| super::jsdoc::strip_summary(&render_ctx, doc));
 
     let breadcrumbs_ctx = render_ctx.get_breadcrumbs();
+    let version_switcher =
+      VersionSwitcherCtx::new(ctx, render_ctx.get_current_resolve());
 
     let categories_panel = CategoriesPanelCtx::new(&render_ctx, Some(name));
     let usage = UsagesCtx::new(&render_ctx, &[]);
@@ -451,6 +489,7 @@ impl IndexCtx {
       toc_ctx,
       disable_search: ctx.disable_search,
       categories_panel,
+      version_switcher,
     }
   }
 }
@@ -463,6 +502,7 @@ pub struct AllSymbolsCtx {
   pub breadcrumbs_ctx: BreadcrumbsCtx,
   pub disable_search: bool,
   pub categories_panel: Option<CategoriesPanelCtx>,
+  pub version_switcher: Option<VersionSwitcherCtx>,
 }
 
 impl AllSymbolsCtx {
@@ -470,7 +510,7 @@ impl AllSymbolsCtx {
 
   pub fn new(
     ctx: &GenerateCtx,
-    partitions: partition::Partitions<Rc<ShortPath>>,
+    partitions: partition::Partitions<Shared<ShortPath>>,
   ) -> Self {
     let render_ctx = RenderContext::new(ctx, &[], UrlResolveKind::AllSymbols);
 
@@ -484,9 +524,17 @@ impl AllSymbolsCtx {
       }),
     );
 
-    let html_head_ctx = HtmlHeadCtx::new(ctx, "./", Some("All Symbols"), None);
+    let html_head_ctx = HtmlHeadCtx::new(
+      ctx,
+      UrlResolveKind::AllSymbols,
+      "./",
+      Some(&ctx.messages.all_symbols),
+      None,
+    );
 
     let categories_panel = CategoriesPanelCtx::new(&render_ctx, None);
+    let version_switcher =
+      VersionSwitcherCtx::new(ctx, UrlResolveKind::AllSymbols);
 
     AllSymbolsCtx {
       html_head_ctx,
@@ -498,6 +546,7 @@ impl AllSymbolsCtx {
       breadcrumbs_ctx: render_ctx.get_breadcrumbs(),
       disable_search: ctx.disable_search,
       categories_panel,
+      version_switcher,
     }
   }
 }
@@ -508,6 +557,8 @@ pub enum SymbolPage {
     symbol_group_ctx: SymbolGroupCtx,
     toc_ctx: Box<util::ToCCtx>,
     categories_panel: Option<CategoriesPanelCtx>,
+    /// The plain text summary of the symbol's documentation.
+    description: Option<String>,
   },
   Redirect {
     current_symbol: String,
@@ -519,6 +570,22 @@ pub fn generate_symbol_pages_for_module(
   ctx: &GenerateCtx,
   short_path: &ShortPath,
   module_doc_nodes: &[DocNodeWithContext],
+) -> Vec<SymbolPage> {
+  generate_symbol_pages_for_module_filtered(
+    ctx,
+    short_path,
+    module_doc_nodes,
+    |_| false,
+  )
+}
+
+/// Like [`generate_symbol_pages_for_module`], but without the pages of the
+/// symbols for which `skip` returns true, along with their redirects.
+pub(crate) fn generate_symbol_pages_for_module_filtered(
+  ctx: &GenerateCtx,
+  short_path: &ShortPath,
+  module_doc_nodes: &[DocNodeWithContext],
+  mut skip: impl FnMut(&str) -> bool,
 ) -> Vec<SymbolPage> {
   let mut name_partitions = partition::partition_nodes_by_name(
     ctx,
@@ -540,47 +607,59 @@ pub fn generate_symbol_pages_for_module(
   }
   name_partitions.extend(drilldown_partitions);
 
-  let mut generated_pages = Vec::with_capacity(name_partitions.values().len());
-
   let render_ctx = RenderContext::new(
     ctx,
     module_doc_nodes,
     UrlResolveKind::File { file: short_path },
   );
 
-  for (name, doc_nodes) in name_partitions {
-    let (breadcrumbs_ctx, symbol_group_ctx, toc_ctx, categories_panel) =
-      render_symbol_page(&render_ctx, short_path, &name, &doc_nodes);
+  let name_partitions = name_partitions
+    .into_iter()
+    .filter(|(name, _)| !skip(name))
+    .collect::<Vec<_>>();
+  let generated_pages =
+    crate::util::par_map(name_partitions, |(name, doc_nodes)| {
+      let mut generated_pages = Vec::with_capacity(2);
+
+      let (breadcrumbs_ctx, symbol_group_ctx, toc_ctx, categories_panel) =
+        render_symbol_page(&render_ctx, short_path, &name, &doc_nodes);
+      let description = doc_nodes
+        .iter()
+        .find_map(|doc_node| doc_node.js_doc.doc.as_deref())
+        .and_then(|doc| super::jsdoc::strip_summary(&render_ctx, doc));
+
+      generated_pages.push(SymbolPage::Symbol {
+        breadcrumbs_ctx,
+        symbol_group_ctx,
+        toc_ctx: Box::new(toc_ctx),
+        categories_panel,
+        description,
+      });
 
-    generated_pages.push(SymbolPage::Symbol {
-      breadcrumbs_ctx,
-      symbol_group_ctx,
-      toc_ctx: Box::new(toc_ctx),
-      categories_panel,
-    });
+      if doc_nodes
+        .iter()
+        .any(|doc_node| matches!(doc_node.def, DocNodeDef::Class { .. }))
+      {
+        let prototype_name = format!("{name}.prototype");
+        generated_pages.push(SymbolPage::Redirect {
+          href: ctx.resolve_path(
+            UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &prototype_name,
+            },
+            UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &name,
+            },
+          ),
+          current_symbol: prototype_name,
+        });
+      }
 
-    if doc_nodes
-      .iter()
-      .any(|doc_node| matches!(doc_node.def, DocNodeDef::Class { .. }))
-    {
-      let prototype_name = format!("{name}.prototype");
-      generated_pages.push(SymbolPage::Redirect {
-        href: ctx.resolve_path(
-          UrlResolveKind::Symbol {
-            file: short_path,
-            symbol: &prototype_name,
-          },
-          UrlResolveKind::Symbol {
-            file: short_path,
-            symbol: &name,
-          },
-        ),
-        current_symbol: prototype_name,
-      });
-    }
-  }
+      generated_pages
+    });
 
-  generated_pages
+  generated_pages.into_iter().flatten().collect()
 }
 
 #[derive(Debug, Serialize)]
@@ -592,6 +671,7 @@ pub struct SymbolPageCtx {
   pub toc_ctx: Box<util::ToCCtx>,
   pub disable_search: bool,
   pub categories_panel: Option<CategoriesPanelCtx>,
+  pub version_switcher: Option<VersionSwitcherCtx>,
 }
 
 impl SymbolPageCtx {
@@ -1,12 +1,12 @@
 use super::DocNodeWithContext;
 use super::GenerateCtx;
 use super::ShortPath;
+use crate::html::Shared;
 use crate::js_doc::JsDocTag;
 use crate::node::DocNodeDef;
 use indexmap::IndexMap;
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::rc::Rc;
 
 pub type Partitions<T> = IndexMap<T, Vec<DocNodeWithContext>>;
 
@@ -205,7 +205,7 @@ pub fn partition_nodes_by_entrypoint<'a>(
   ctx: &GenerateCtx,
   doc_nodes: impl Iterator<Item = Cow<'a, DocNodeWithContext>> + 'a,
   flatten_namespaces: bool,
-) -> Partitions<Rc<ShortPath>> {
+) -> Partitions<Shared<ShortPath>> {
   let mut partitions = create_partitioner(
     ctx,
     doc_nodes,
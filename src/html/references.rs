@@ -0,0 +1,254 @@
+use super::DocNodeWithContext;
+use super::ShortPath;
+use super::render_context::RenderContext;
+use super::symbols::namespace::render_symbol_list;
+use super::util::SectionCtx;
+use crate::html::Shared;
+use crate::node::DocNodeDef;
+use crate::ts_type::TsTypeRefDef;
+use crate::visit::Visit;
+use crate::visit::walk_ts_type_ref_def;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// The symbols which reference a class or interface.
+#[derive(Debug, Default, Clone)]
+pub struct TypeReferences {
+  /// Classes which implement the interface.
+  pub implemented_by: Vec<DocNodeWithContext>,
+  /// Interfaces which extend the class or interface.
+  pub extended_by: Vec<DocNodeWithContext>,
+  /// Classes which extend the class.
+  pub subclasses: Vec<DocNodeWithContext>,
+  /// Functions with a parameter of the type.
+  pub accepted_by: Vec<DocNodeWithContext>,
+  /// Functions which return the type.
+  pub returned_by: Vec<DocNodeWithContext>,
+}
+
+/// A class or interface which a class or interface extends or implements.
+#[derive(Debug, Clone)]
+pub struct Supertype {
+  /// The name as written in the `extends` or `implements` clause.
+  pub name: String,
+  /// The documented symbol, or `None` for types outside of the
+  /// documentation, such as `Error`.
+  pub node: Option<DocNodeWithContext>,
+  pub implements: bool,
+}
+
+/// The declaration of a symbol, which is shared by all the modules exporting
+/// it.
+pub(crate) type DeclarationKey = (Box<str>, usize);
+
+pub(crate) fn declaration_key(node: &DocNodeWithContext) -> DeclarationKey {
+  (node.location.filename.clone(), node.location.byte_index)
+}
+
+/// An index from classes and interfaces to the symbols which reference them,
+/// built once for all modules.
+#[derive(Debug, Default)]
+pub struct ReverseIndex {
+  references: HashMap<DeclarationKey, TypeReferences>,
+  supertypes: HashMap<DeclarationKey, Vec<Supertype>>,
+}
+
+impl ReverseIndex {
+  pub fn new(
+    doc_nodes: &IndexMap<Shared<ShortPath>, Vec<DocNodeWithContext>>,
+  ) -> Self {
+    let all_nodes = doc_nodes
+      .values()
+      .flat_map(|nodes| flatten_namespaces(nodes))
+      .collect::<Vec<_>>();
+
+    let mut types = HashMap::<String, Vec<&DocNodeWithContext>>::new();
+    for node in &all_nodes {
+      if matches!(
+        node.def,
+        DocNodeDef::Class { .. } | DocNodeDef::Interface { .. }
+      ) {
+        types
+          .entry(node.get_qualified_name())
+          .or_default()
+          .push(node);
+      }
+    }
+
+    // prefer the type from the module of the referencing symbol
+    let resolve_node = |from: &DocNodeWithContext, name: &str| {
+      let candidates = types.get(name)?;
+      candidates
+        .iter()
+        .find(|candidate| candidate.origin == from.origin)
+        .or_else(|| candidates.first())
+        .copied()
+    };
+    let resolve = |from: &DocNodeWithContext, name: &str| {
+      resolve_node(from, name).map(declaration_key)
+    };
+
+    let mut index = ReverseIndex::default();
+    let mut add =
+      |target: Option<DeclarationKey>,
+       node: &DocNodeWithContext,
+       list: fn(&mut TypeReferences) -> &mut Vec<DocNodeWithContext>| {
+        let Some(target) = target else {
+          return;
+        };
+        let list = list(index.references.entry(target).or_default());
+        // a symbol exported from several modules is only listed once
+        let key = declaration_key(node);
+        if !list.iter().any(|listed| declaration_key(listed) == key) {
+          list.push(node.clone());
+        }
+      };
+
+    for node in &all_nodes {
+      let supertype_names = match &node.def {
+        DocNodeDef::Class { class_def } => class_def
+          .extends
+          .iter()
+          .map(|name| (name.to_string(), false))
+          .chain(class_def.implements.iter().flat_map(|implements| {
+            collect_type_refs(|c| c.visit_ts_type_def(implements))
+              .into_iter()
+              .map(|name| (name, true))
+          }))
+          .collect::<Vec<_>>(),
+        DocNodeDef::Interface { interface_def } => interface_def
+          .extends
+          .iter()
+          .flat_map(|extends| {
+            collect_type_refs(|c| c.visit_ts_type_def(extends))
+              .into_iter()
+              .map(|name| (name, false))
+          })
+          .collect(),
+        _ => vec![],
+      };
+      if !supertype_names.is_empty() {
+        index
+          .supertypes
+          .entry(declaration_key(node))
+          .or_insert_with(|| {
+            supertype_names
+              .into_iter()
+              .map(|(name, implements)| Supertype {
+                node: resolve_node(node, &name).cloned(),
+                name,
+                implements,
+              })
+              .collect()
+          });
+      }
+
+      match &node.def {
+        DocNodeDef::Class { class_def } => {
+          if let Some(extends) = &class_def.extends {
+            add(resolve(node, extends), node, |r| &mut r.subclasses);
+          }
+          for implements in &class_def.implements {
+            for name in collect_type_refs(|c| c.visit_ts_type_def(implements)) {
+              add(resolve(node, &name), node, |r| &mut r.implemented_by);
+            }
+          }
+        }
+        DocNodeDef::Interface { interface_def } => {
+          for extends in &interface_def.extends {
+            for name in collect_type_refs(|c| c.visit_ts_type_def(extends)) {
+              add(resolve(node, &name), node, |r| &mut r.extended_by);
+            }
+          }
+        }
+        DocNodeDef::Function { function_def } => {
+          let params = collect_type_refs(|c| {
+            for param in &function_def.params {
+              c.visit_param_def(param);
+            }
+          });
+          for name in params {
+            add(resolve(node, &name), node, |r| &mut r.accepted_by);
+          }
+          if let Some(return_type) = &function_def.return_type {
+            for name in collect_type_refs(|c| c.visit_ts_type_def(return_type))
+            {
+              add(resolve(node, &name), node, |r| &mut r.returned_by);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+
+    index
+  }
+
+  pub fn get(&self, node: &DocNodeWithContext) -> Option<&TypeReferences> {
+    self.references.get(&declaration_key(node))
+  }
+
+  /// The classes and interfaces which the class or interface extends or
+  /// implements.
+  pub fn supertypes(&self, node: &DocNodeWithContext) -> &[Supertype] {
+    self
+      .supertypes
+      .get(&declaration_key(node))
+      .map(Vec::as_slice)
+      .unwrap_or_default()
+  }
+}
+
+pub(crate) fn flatten_namespaces(
+  nodes: &[DocNodeWithContext],
+) -> Vec<&DocNodeWithContext> {
+  let mut flattened = vec![];
+  for node in nodes {
+    flattened.push(node);
+    if let Some(children) = &node.namespace_children {
+      flattened.extend(flatten_namespaces(children));
+    }
+  }
+  flattened
+}
+
+#[derive(Default)]
+struct TypeRefCollector(Vec<String>);
+
+impl Visit for TypeRefCollector {
+  fn visit_ts_type_ref_def(&mut self, node: &TsTypeRefDef) {
+    if !self.0.contains(&node.type_name) {
+      self.0.push(node.type_name.clone());
+    }
+    walk_ts_type_ref_def(self, node);
+  }
+}
+
+fn collect_type_refs(visit: impl FnOnce(&mut TypeRefCollector)) -> Vec<String> {
+  let mut collector = TypeRefCollector::default();
+  visit(&mut collector);
+  collector.0
+}
+
+/// Renders the sections listing the symbols which reference the class or
+/// interface.
+pub(crate) fn render_type_references(
+  ctx: &RenderContext,
+  doc_node: &DocNodeWithContext,
+) -> Vec<SectionCtx> {
+  let Some(references) = ctx.ctx.reverse_index.get(doc_node) else {
+    return vec![];
+  };
+  let messages = &ctx.ctx.messages;
+
+  [
+    (&messages.implemented_by, &references.implemented_by),
+    (&messages.extended_by, &references.extended_by),
+    (&messages.subclasses, &references.subclasses),
+    (&messages.accepted_by, &references.accepted_by),
+    (&messages.returned_by, &references.returned_by),
+  ]
+  .into_iter()
+  .filter_map(|(title, nodes)| render_symbol_list(ctx, title, nodes))
+  .collect()
+}
@@ -1,5 +1,6 @@
 use crate::html::DocNodeWithContext;
 use crate::html::GenerateCtx;
+use crate::html::Shared;
 use crate::html::UrlResolveKind;
 use crate::html::util::BreadcrumbCtx;
 use crate::html::util::BreadcrumbsCtx;
@@ -10,7 +11,6 @@ use serde::Serialize;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -18,11 +18,11 @@ use std::sync::Mutex;
 pub struct RenderContext<'ctx> {
   pub ctx: &'ctx GenerateCtx,
   scoped_symbols: NamespacedSymbols,
-  current_imports: Rc<HashMap<String, String>>,
-  current_type_params: Rc<HashSet<&'ctx str>>,
+  current_imports: Shared<HashMap<String, String>>,
+  current_type_params: Shared<HashSet<&'ctx str>>,
   current_resolve: UrlResolveKind<'ctx>,
   /// A vector of parts of the current namespace, eg. `vec!["Deno", "errors"]`.
-  namespace_parts: Rc<[String]>,
+  namespace_parts: Shared<[String]>,
   /// Only some when in `FileMode::SingleDts` and using categories
   pub category: Option<&'ctx str>,
   pub toc: HeadingToCAdapter,
@@ -37,10 +37,10 @@ impl<'ctx> RenderContext<'ctx> {
     Self {
       ctx,
       scoped_symbols: NamespacedSymbols::new(ctx, doc_nodes),
-      current_imports: Rc::new(get_current_imports(doc_nodes)),
+      current_imports: Shared::new(get_current_imports(doc_nodes)),
       current_type_params: Default::default(),
       current_resolve,
-      namespace_parts: Rc::new([]),
+      namespace_parts: Shared::new([]),
       category: None,
       toc: Default::default(),
     }
@@ -51,12 +51,12 @@ impl<'ctx> RenderContext<'ctx> {
     current_type_params: HashSet<&'ctx str>,
   ) -> Self {
     Self {
-      current_type_params: Rc::new(current_type_params),
+      current_type_params: Shared::new(current_type_params),
       ..self.clone()
     }
   }
 
-  pub fn with_namespace(&self, namespace_parts: Rc<[String]>) -> Self {
+  pub fn with_namespace(&self, namespace_parts: Shared<[String]>) -> Self {
     Self {
       namespace_parts,
       ..self.clone()
@@ -158,10 +158,17 @@ impl<'ctx> RenderContext<'ctx> {
         ));
       }
 
-      return self
-        .ctx
-        .href_resolver
-        .resolve_import_href(&target_symbol_parts, src);
+      return super::inventory::resolve_import_href(
+        self.ctx,
+        &target_symbol_parts,
+        src,
+      )
+      .or_else(|| {
+        self
+          .ctx
+          .href_resolver
+          .resolve_import_href(&target_symbol_parts, src)
+      });
     }
 
     self
@@ -171,8 +178,11 @@ impl<'ctx> RenderContext<'ctx> {
   }
 
   pub fn get_breadcrumbs(&self) -> BreadcrumbsCtx {
-    let index_name =
-      self.ctx.package_name.clone().unwrap_or("index".to_string());
+    let index_name = self
+      .ctx
+      .package_name
+      .clone()
+      .unwrap_or_else(|| self.ctx.messages.breadcrumb_index.clone());
 
     let parts = match self.current_resolve {
       UrlResolveKind::Root => vec![BreadcrumbCtx {
@@ -192,7 +202,7 @@ impl<'ctx> RenderContext<'ctx> {
             is_first_symbol: false,
           },
           BreadcrumbCtx {
-            name: "all symbols".to_string(),
+            name: self.ctx.messages.breadcrumb_all_symbols.clone(),
             href: "".to_string(),
             is_symbol: false,
             is_first_symbol: false,
@@ -211,7 +221,10 @@ impl<'ctx> RenderContext<'ctx> {
           },
           BreadcrumbCtx {
             name: category.to_owned(),
-            href: super::util::slugify(category),
+            href: super::util::slugify_localized(
+              category,
+              &self.ctx.messages.lang,
+            ),
             is_symbol: false,
             is_first_symbol: false,
           },
@@ -590,8 +603,8 @@ mod test {
       GenerateOptions {
         package_name: None,
         main_entrypoint: None,
-        href_resolver: Rc::new(TestResolver),
-        usage_composer: Rc::new(TestResolver),
+        href_resolver: Shared::new(TestResolver),
+        usage_composer: Shared::new(TestResolver),
         rewrite_map: None,
         category_docs: None,
         disable_search: false,
@@ -600,9 +613,18 @@ mod test {
         markdown_renderer: crate::html::comrak::create_renderer(
           None, None, None,
         ),
-        markdown_stripper: Rc::new(crate::html::comrak::strip),
+        markdown_stripper: Shared::new(crate::html::comrak::strip),
         head_inject: None,
         id_prefix: None,
+        template_overrides: None,
+        messages: None,
+        versions: None,
+        source_files: None,
+        class_hierarchy: false,
+        full_text_search: false,
+        base_url: None,
+        inventories: None,
+        expand_types: false,
       },
       None,
       Default::default(),
@@ -627,4 +649,122 @@ mod test {
     );
     assert_eq!(render_ctx.lookup_symbol_href("foo").unwrap(), "b/foo");
   }
+  #[test]
+  fn lookup_inventory_href() {
+    let doc_nodes_by_url = indexmap::IndexMap::from([(
+      ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      ["Foo", "bar", "Baz"]
+        .into_iter()
+        .map(|name| DocNode {
+          name: name.into(),
+          is_default: None,
+          location: Location {
+            filename: "a".into(),
+            line: 0,
+            col: 0,
+            byte_index: 0,
+          },
+          declaration_kind: DeclarationKind::Private,
+          js_doc: Default::default(),
+          def: crate::node::DocNodeDef::Import {
+            import_def: ImportDef {
+              src: match name {
+                "Foo" => "jsr:@scope/a@^1.0.0".to_string(),
+                "bar" => "jsr:@scope/a/b".to_string(),
+                _ => "jsr:@scope/ab".to_string(),
+              },
+              imported: Some(name.to_string()),
+            },
+          },
+        })
+        .collect(),
+    )]);
+
+    let inventory = crate::html::Inventory {
+      base_url: Some("https://example.com/a/".to_string()),
+      package_name: None,
+      modules: IndexMap::from([
+        (
+          "".to_string(),
+          crate::html::InventoryModule {
+            href: "index.html".to_string(),
+            symbols: IndexMap::from([(
+              "Foo".to_string(),
+              crate::html::InventorySymbol {
+                href: "~/Foo.html".to_string(),
+                kind: vec!["Class".to_string()],
+              },
+            )]),
+          },
+        ),
+        (
+          "b".to_string(),
+          crate::html::InventoryModule {
+            href: "b/index.html".to_string(),
+            symbols: IndexMap::from([(
+              "Baz".to_string(),
+              crate::html::InventorySymbol {
+                href: "b/~/Baz.html".to_string(),
+                kind: vec!["Class".to_string()],
+              },
+            )]),
+          },
+        ),
+      ]),
+    };
+
+    let ctx = GenerateCtx::new(
+      GenerateOptions {
+        package_name: None,
+        main_entrypoint: None,
+        href_resolver: Shared::new(TestResolver),
+        usage_composer: Shared::new(TestResolver),
+        rewrite_map: None,
+        category_docs: None,
+        disable_search: false,
+        symbol_redirect_map: None,
+        default_symbol_map: None,
+        markdown_renderer: crate::html::comrak::create_renderer(
+          None, None, None,
+        ),
+        markdown_stripper: Shared::new(crate::html::comrak::strip),
+        head_inject: None,
+        id_prefix: None,
+        template_overrides: None,
+        messages: None,
+        versions: None,
+        source_files: None,
+        class_hierarchy: false,
+        full_text_search: false,
+        base_url: None,
+        inventories: Some(IndexMap::from([(
+          "jsr:@scope/a".to_string(),
+          inventory,
+        )])),
+        expand_types: false,
+      },
+      None,
+      Default::default(),
+      doc_nodes_by_url,
+    )
+    .unwrap();
+
+    let (_, doc_nodes) = ctx.doc_nodes.first().unwrap();
+    let render_ctx = RenderContext::new(&ctx, doc_nodes, UrlResolveKind::Root);
+
+    assert_eq!(
+      render_ctx.lookup_symbol_href("Foo").unwrap(),
+      "https://example.com/a/~/Foo.html"
+    );
+    // symbols missing from the inventory fall back to the href resolver
+    assert_eq!(
+      render_ctx.lookup_symbol_href("bar").unwrap(),
+      "jsr:@scope/a/b/bar"
+    );
+    // and so do the symbols of packages only sharing a prefix of the name
+    assert_eq!(
+      render_ctx.lookup_symbol_href("Baz").unwrap(),
+      "jsr:@scope/ab/Baz"
+    );
+  }
 }
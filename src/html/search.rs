@@ -2,13 +2,21 @@ use super::DocNodeKindCtx;
 use super::DocNodeWithContext;
 use super::GenerateCtx;
 use super::RenderContext;
+use super::ShortPath;
 use super::UrlResolveKind;
+use super::jsdoc::split_markdown_title;
+use super::parameters::param_name;
+use super::render_context::Anchorizer;
 use crate::html::util::Id;
 use crate::html::util::IdBuilder;
 use crate::html::util::IdKind;
 use crate::js_doc::JsDocTag;
+use crate::node::DocNodeDef;
+use indexmap::IndexMap;
+use indexmap::IndexSet;
 use serde::Serialize;
 use serde_json::json;
+use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -125,6 +133,19 @@ pub fn doc_nodes_into_search_index_node(
 }
 
 pub fn generate_search_index(ctx: &GenerateCtx) -> serde_json::Value {
+  let mut search_index = json!({
+    "kind": "search",
+    "nodes": collect_search_index_nodes(ctx)
+  });
+
+  if ctx.full_text_search {
+    search_index["entries"] = json!(collect_full_text_entries(ctx));
+  }
+
+  search_index
+}
+
+fn collect_search_index_nodes(ctx: &GenerateCtx) -> Vec<SearchIndexNode> {
   let doc_nodes = ctx
     .doc_nodes
     .values()
@@ -144,25 +165,788 @@ pub fn generate_search_index(ctx: &GenerateCtx) -> serde_json::Value {
 
   doc_nodes.sort_by(|a, b| a.file.cmp(&b.file));
 
-  let search_index = json!({
-    "kind": "search",
-    "nodes": doc_nodes
-  });
+  doc_nodes
+}
 
-  search_index
+/// A part of the documentation of a symbol or module, in the full-text search
+/// index.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullTextEntry {
+  /// The symbol or module which the documentation belongs to.
+  pub name: Box<str>,
+  /// The part of the documentation, eg. a parameter or a heading. Empty for
+  /// the description.
+  #[serde(skip_serializing_if = "is_empty", default)]
+  pub section: Box<str>,
+  /// The URL relative to the root, including the anchor of the part.
+  pub url: Box<str>,
+  pub text: Box<str>,
+}
+
+fn url_with_anchor(url: &str, anchor: Option<&str>) -> Box<str> {
+  let mut url = url.to_string();
+  if url.is_empty() || url.ends_with('/') {
+    url.push_str("index.html");
+  }
+  if let Some(anchor) = anchor {
+    url.push('#');
+    url.push_str(anchor);
+  }
+  url.into_boxed_str()
+}
+
+/// Strips the markdown block by block, so that the text of the blocks is
+/// separated, and keeps the contents of code blocks.
+fn strip_blocks(ctx: &RenderContext, md: &str) -> String {
+  let mut parts = vec![];
+  let mut block = String::new();
+  let mut fence = None;
+
+  let flush = |block: &mut String, parts: &mut Vec<String>| {
+    if !block.trim().is_empty() {
+      parts.push(super::jsdoc::strip(ctx, block));
+    }
+    block.clear();
+  };
+
+  for line in md.lines() {
+    let trimmed = line.trim_start();
+    let fence_marker = ["```", "~~~"]
+      .into_iter()
+      .find(|marker| trimmed.starts_with(marker));
+
+    match (fence, fence_marker) {
+      (None, Some(marker)) => {
+        flush(&mut block, &mut parts);
+        fence = Some(marker);
+      }
+      (Some(open), Some(marker)) if open == marker => {
+        parts.push(std::mem::take(&mut block));
+        fence = None;
+      }
+      (Some(_), _) => {
+        block.push_str(line);
+        block.push('\n');
+      }
+      (None, None) if trimmed.is_empty() => flush(&mut block, &mut parts),
+      (None, None) => {
+        block.push_str(line);
+        block.push('\n');
+      }
+    }
+  }
+  if fence.is_some() {
+    parts.push(block);
+  } else {
+    flush(&mut block, &mut parts);
+  }
+
+  parts
+    .iter()
+    .flat_map(|part| part.split_whitespace())
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn doc_nodes_into_full_text_entries(
+  ctx: &RenderContext,
+  doc_nodes: &[DocNodeWithContext],
+  name: &str,
+) -> Vec<FullTextEntry> {
+  let messages = &ctx.ctx.messages;
+  let url = ctx.ctx.resolve_path(
+    UrlResolveKind::Root,
+    UrlResolveKind::Symbol {
+      file: &doc_nodes[0].origin,
+      symbol: name,
+    },
+  );
+
+  // named entries and examples are anchorized on the page in the same way
+  let mut anchorizer = Anchorizer::default();
+  let mut entries = vec![];
+  let mut push = |section: &str, anchor: Option<&str>, md: &str| {
+    let text = strip_blocks(ctx, md);
+    if !text.is_empty() {
+      entries.push(FullTextEntry {
+        name: name.into(),
+        section: section.into(),
+        url: url_with_anchor(&url, anchor),
+        text: text.into_boxed_str(),
+      });
+    }
+  };
+
+  for (i, doc_node) in doc_nodes.iter().enumerate() {
+    let function_def = doc_node.function_def();
+    let overload_id = IdBuilder::new(ctx.ctx)
+      .kind(IdKind::Function)
+      .name(doc_node.get_name())
+      .index(i)
+      .build();
+
+    if let Some(doc) = &doc_node.js_doc.doc {
+      let anchor = (function_def.is_some() && doc_nodes.len() > 1)
+        .then_some(overload_id.as_str());
+      push("", anchor, doc);
+    }
+
+    if let Some(function_def) = function_def {
+      for tag in &doc_node.js_doc.tags {
+        if let JsDocTag::Param {
+          name: param,
+          doc: Some(doc),
+          ..
+        } = tag
+        {
+          let anchor = function_def
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| param_name(param, i))
+            .find(|(name, _)| name == param.as_ref())
+            .map(|(_, str_name)| {
+              let id = IdBuilder::new(ctx.ctx)
+                .component(&overload_id)
+                .kind(IdKind::Parameter)
+                .name(&str_name)
+                .build();
+              anchorizer.anchorize(id.as_str())
+            });
+          push(
+            &format!("{}: {param}", messages.parameters),
+            anchor.as_deref(),
+            doc,
+          );
+        }
+      }
+
+      let return_doc = doc_node.js_doc.tags.iter().find_map(|tag| {
+        if let JsDocTag::Return { doc, .. } = tag {
+          doc.as_deref()
+        } else {
+          None
+        }
+      });
+      if let Some(doc) = return_doc {
+        let anchor = function_def.return_type.is_some().then(|| {
+          let id = IdBuilder::new(ctx.ctx)
+            .component(overload_id.as_str())
+            .kind(IdKind::Return)
+            .build();
+          id.as_str().to_string()
+        });
+        push(&messages.return_type, anchor.as_deref(), doc);
+      }
+
+      let throws = doc_node.js_doc.tags.iter().filter_map(|tag| {
+        if let JsDocTag::Throws { type_ref, doc } = tag
+          && (type_ref.is_some() || doc.is_some())
+        {
+          Some(doc)
+        } else {
+          None
+        }
+      });
+      for (i, doc) in throws.enumerate() {
+        if let Some(doc) = doc {
+          let id = IdBuilder::new(ctx.ctx)
+            .component(overload_id.as_str())
+            .kind(IdKind::Throws)
+            .index(i)
+            .build();
+          push(&messages.throws, Some(id.as_str()), doc);
+        }
+      }
+    }
+
+    let examples = doc_node.js_doc.tags.iter().filter_map(|tag| {
+      if let JsDocTag::Example { doc } = tag {
+        Some(doc)
+      } else {
+        None
+      }
+    });
+    for (i, example) in examples.enumerate() {
+      let (title, body) = split_markdown_title(example);
+      let title = title
+        .map(|title| super::jsdoc::strip(ctx, title))
+        .unwrap_or_else(|| format!("Example {}", i + 1));
+      let id = IdBuilder::new(ctx.ctx)
+        .kind(IdKind::Example)
+        .index(i)
+        .build();
+      let anchor = anchorizer.anchorize(id.as_str());
+      push(&title, Some(&anchor), body.unwrap_or_default());
+    }
+  }
+
+  entries.extend(
+    doc_nodes
+      .iter()
+      .filter_map(|node| node.get_drilldown_symbols())
+      .flatten()
+      .flat_map(|drilldown_node| {
+        let name = drilldown_node.get_qualified_name();
+        doc_nodes_into_full_text_entries(ctx, &[drilldown_node], &name)
+      }),
+  );
+
+  entries
+}
+
+/// Splits the module documentation at its headings, so that each section
+/// links to its heading.
+fn module_doc_into_full_text_entries(
+  ctx: &RenderContext,
+  short_path: &ShortPath,
+  doc: &str,
+) -> Vec<FullTextEntry> {
+  let url = ctx.ctx.resolve_path(
+    UrlResolveKind::Root,
+    UrlResolveKind::File { file: short_path },
+  );
+  let name = short_path.display_name();
+
+  let mut anchorizer = Anchorizer::default();
+  let mut sections = vec![(None, String::new())];
+  let mut fence = None;
+
+  for line in doc.lines() {
+    let trimmed = line.trim_start();
+    let fence_marker = ["```", "~~~"]
+      .into_iter()
+      .find(|marker| trimmed.starts_with(marker));
+    match (fence, fence_marker) {
+      (None, Some(marker)) => fence = Some(marker),
+      (Some(open), Some(marker)) if open == marker => fence = None,
+      _ => {}
+    }
+
+    let heading = fence
+      .is_none()
+      .then(|| {
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        let rest = &trimmed[level..];
+        ((1..=6).contains(&level) && rest.starts_with([' ', '\t']))
+          .then(|| rest.trim().trim_end_matches('#').trim())
+      })
+      .flatten();
+
+    match heading {
+      Some(heading) => {
+        let heading = super::jsdoc::strip(ctx, heading);
+        let anchor = anchorizer.anchorize(heading.trim());
+        sections
+          .push((Some((heading.trim().to_string(), anchor)), String::new()));
+      }
+      None => {
+        let (_, body) = sections.last_mut().unwrap();
+        body.push_str(line);
+        body.push('\n');
+      }
+    }
+  }
+
+  sections
+    .into_iter()
+    .filter_map(|(heading, body)| {
+      let text = strip_blocks(ctx, &body);
+      if text.is_empty() {
+        return None;
+      }
+      let (section, anchor) = heading.unzip();
+      Some(FullTextEntry {
+        name: name.into(),
+        section: section.unwrap_or_default().into(),
+        url: url_with_anchor(&url, anchor.as_deref()),
+        text: text.into_boxed_str(),
+      })
+    })
+    .collect()
+}
+
+/// Collects the parts of the documentation of all symbols and modules for
+/// the full-text search index, see [`GenerateOptions::full_text_search`].
+///
+/// [`GenerateOptions::full_text_search`]: super::GenerateOptions::full_text_search
+pub fn collect_full_text_entries(ctx: &GenerateCtx) -> Vec<FullTextEntry> {
+  let render_ctx = RenderContext::new(ctx, &[], UrlResolveKind::AllSymbols);
+
+  let mut entries = ctx
+    .doc_nodes
+    .iter()
+    .flat_map(|(short_path, nodes)| {
+      nodes
+        .iter()
+        .filter(|node| matches!(node.def, DocNodeDef::ModuleDoc))
+        .filter_map(|node| node.js_doc.doc.as_deref())
+        .flat_map(|doc| {
+          module_doc_into_full_text_entries(&render_ctx, short_path, doc)
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  let doc_nodes = ctx
+    .doc_nodes
+    .values()
+    .flatten()
+    .map(std::borrow::Cow::Borrowed);
+  let partitions =
+    super::partition::partition_nodes_by_name(ctx, doc_nodes, true);
+  entries.extend(partitions.into_iter().flat_map(|(name, nodes)| {
+    doc_nodes_into_full_text_entries(&render_ctx, &nodes, &name)
+  }));
+
+  entries
 }
 
-pub(crate) fn get_search_index_file(
+/// The directory of the lazily loaded chunks of the search index.
+const SEARCH_CHUNKS_DIR: &str = "search";
+/// The number of nodes per node chunk.
+const NODE_CHUNK_SIZE: usize = 256;
+/// The number of entries per full-text entry chunk.
+const ENTRY_CHUNK_SIZE: usize = 128;
+/// The maximum number of terms taken from the documentation of a symbol.
+const MAX_DOC_TERMS: usize = 64;
+
+const STOP_WORDS: &[&str] = &[
+  "and", "are", "for", "from", "has", "into", "its", "not", "that", "the",
+  "this", "was", "will", "with",
+];
+
+/// Where a term of the inverted search index was found. The lower the value,
+/// the more relevant a match.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TermField {
+  /// The whole name of the symbol.
+  Name = 0,
+  /// A trailing part of a camelCase or qualified name, eg. `username` for
+  /// `getUsername`.
+  NamePart = 1,
+  /// A word of the documentation.
+  Doc = 2,
+}
+
+/// Splits a name into lowercase terms: for `Deno.getUserName`, the whole
+/// name `getusername`, and the trailing parts `username`, `name`, `deno`.
+/// CJK runs of the name are also split into bigrams, like in the
+/// documentation.
+pub fn tokenize_name(name: &str) -> Vec<(String, TermField)> {
+  let mut segments = name
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|segment| !segment.is_empty())
+    .collect::<Vec<_>>();
+  let Some(last) = segments.pop() else {
+    return vec![];
+  };
+
+  let mut terms = vec![];
+  let mut push = |term: String, field: TermField| {
+    if !terms.iter().any(|(existing, _)| *existing == term) {
+      terms.push((term, field));
+    }
+  };
+
+  let parts = split_camel_case(last);
+  for i in 0..parts.len() {
+    let field = if i == 0 {
+      TermField::Name
+    } else {
+      TermField::NamePart
+    };
+    push(parts[i..].concat().to_lowercase(), field);
+  }
+  for (segment, is_cjk) in segment_cjk(last) {
+    if is_cjk {
+      push(segment.to_lowercase(), TermField::NamePart);
+    }
+  }
+  for segment in segments {
+    let parts = split_camel_case(segment);
+    for i in 0..parts.len() {
+      push(parts[i..].concat().to_lowercase(), TermField::NamePart);
+    }
+  }
+
+  terms
+}
+
+/// Splits an identifier on case changes and digits, keeping acronyms
+/// together: `HTMLElement2D` becomes `HTML`, `Element`, `2`, `D`.
+fn split_camel_case(s: &str) -> Vec<&str> {
+  let chars = s.char_indices().collect::<Vec<_>>();
+  let mut parts = vec![];
+  let mut start = 0;
+
+  for i in 1..chars.len() {
+    let (index, c) = chars[i];
+    let prev = chars[i - 1].1;
+    let next = chars.get(i + 1).map(|(_, c)| *c);
+
+    let boundary = (prev.is_lowercase() && c.is_uppercase())
+      || (prev.is_uppercase()
+        && c.is_uppercase()
+        && next.is_some_and(|next| next.is_lowercase()))
+      || (prev.is_ascii_digit() != c.is_ascii_digit());
+
+    if boundary {
+      parts.push(&s[start..index]);
+      start = index;
+    }
+  }
+  parts.push(&s[start..]);
+
+  parts
+}
+
+/// Splits documentation into lowercase words, skipping short and common
+/// words. Text in CJK scripts, which has no separators between words, is
+/// split into overlapping bigrams.
+pub fn tokenize_doc(doc: &str) -> Vec<String> {
+  tokenize_words(doc, MAX_DOC_TERMS)
+}
+
+fn tokenize_words(text: &str, max_terms: usize) -> Vec<String> {
+  let mut terms = IndexSet::<String>::new();
+
+  for word in text.split(|c: char| !c.is_alphanumeric()) {
+    for (segment, is_cjk) in segment_cjk(word) {
+      if terms.len() == max_terms {
+        return terms.into_iter().collect();
+      }
+      if !is_cjk && segment.chars().count() < 3 {
+        continue;
+      }
+      let segment = segment.to_lowercase();
+      if !STOP_WORDS.contains(&segment.as_str()) {
+        terms.insert(segment);
+      }
+    }
+  }
+
+  terms.into_iter().collect()
+}
+
+/// Whether a char is of the Chinese, Japanese or Korean scripts. These are
+/// matched by the same ranges in `search.js`.
+fn is_cjk(c: char) -> bool {
+  matches!(
+    c,
+    '\u{3040}'..='\u{30FF}'
+      | '\u{31F0}'..='\u{31FF}'
+      | '\u{3400}'..='\u{4DBF}'
+      | '\u{4E00}'..='\u{9FFF}'
+      | '\u{AC00}'..='\u{D7AF}'
+      | '\u{F900}'..='\u{FAFF}'
+      | '\u{FF66}'..='\u{FF9F}'
+      | '\u{20000}'..='\u{2FA1F}'
+  )
+}
+
+/// Splits a word into its runs of other chars, and the overlapping bigrams
+/// of its CJK runs, or the single char of a run of one, flagged as CJK.
+fn segment_cjk(word: &str) -> Vec<(&str, bool)> {
+  let mut segments = vec![];
+  let mut chars = word.char_indices().peekable();
+
+  while let Some((start, c)) = chars.next() {
+    if !is_cjk(c) {
+      let mut end = start + c.len_utf8();
+      while let Some((index, c)) = chars.next_if(|(_, c)| !is_cjk(*c)) {
+        end = index + c.len_utf8();
+      }
+      segments.push((&word[start..end], false));
+      continue;
+    }
+
+    let mut run = vec![(start, c)];
+    while let Some(next) = chars.next_if(|(_, c)| is_cjk(*c)) {
+      run.push(next);
+    }
+    if let [(start, c)] = run[..] {
+      segments.push((&word[start..start + c.len_utf8()], true));
+    }
+    for pair in run.windows(2) {
+      let (start, _) = pair[0];
+      let (index, c) = pair[1];
+      segments.push((&word[start..index + c.len_utf8()], true));
+    }
+  }
+
+  segments
+}
+
+#[derive(Debug, Default)]
+struct TrieNode {
+  /// The nodes containing the term ending at this trie node, and the most
+  /// relevant field it was found in.
+  postings: Vec<(usize, TermField)>,
+  children: BTreeMap<char, TrieNode>,
+}
+
+impl TrieNode {
+  fn insert(&mut self, term: &str, node_index: usize, field: TermField) {
+    let mut trie_node = self;
+    for c in term.chars() {
+      trie_node = trie_node.children.entry(c).or_default();
+    }
+
+    match trie_node
+      .postings
+      .iter_mut()
+      .find(|(index, _)| *index == node_index)
+    {
+      Some((_, existing)) => *existing = (*existing).min(field),
+      None => trie_node.postings.push((node_index, field)),
+    }
+  }
+
+  /// Serializes the trie, merging chains of nodes with a single child and no
+  /// postings into a single edge: `{"p": [node, field, ...], "c": {"edge":
+  /// child}}`.
+  fn to_json(&self) -> serde_json::Value {
+    let mut value = serde_json::Map::new();
+
+    if !self.postings.is_empty() {
+      value.insert(
+        "p".to_string(),
+        self
+          .postings
+          .iter()
+          .flat_map(|(index, field)| [*index, *field as usize])
+          .collect(),
+      );
+    }
+
+    if !self.children.is_empty() {
+      let mut children = serde_json::Map::new();
+      for (c, child) in &self.children {
+        let mut label = c.to_string();
+        let mut child = child;
+        while child.postings.is_empty() && child.children.len() == 1 {
+          let (c, next) = child.children.iter().next().unwrap();
+          label.push(*c);
+          child = next;
+        }
+        children.insert(label, child.to_json());
+      }
+      value.insert("c".to_string(), children.into());
+    }
+
+    value.into()
+  }
+}
+
+/// The key of the shard containing a term, its first char.
+pub(crate) fn shard_key(term: &str) -> char {
+  term
+    .chars()
+    .next()
+    .filter(|c| c.is_alphanumeric())
+    .unwrap_or('_')
+}
+
+/// A tokenized search index, sharded into chunks which are only loaded by
+/// the client when a query needs them.
+#[derive(Debug)]
+pub struct InvertedSearchIndex {
+  pub kinds: IndexMap<char, SlimKindCtx>,
+  pub nodes: Vec<SearchIndexNode>,
+  /// The parts of the documentation, if full-text search is enabled.
+  pub entries: Option<Vec<FullTextEntry>>,
+  /// The title of the full-text results.
+  text_title: String,
+  shards: BTreeMap<char, TrieNode>,
+  text_shards: BTreeMap<char, TrieNode>,
+}
+
+impl InvertedSearchIndex {
+  pub fn new(ctx: &GenerateCtx) -> Self {
+    let nodes = collect_search_index_nodes(ctx);
+
+    let mut kinds = IndexMap::new();
+    let mut shards = BTreeMap::<char, TrieNode>::new();
+
+    for (index, node) in nodes.iter().enumerate() {
+      for kind in &node.kind {
+        kinds.entry(kind.char).or_insert_with(|| kind.clone());
+      }
+
+      let name = html_escape::decode_html_entities(&node.name);
+      let terms = tokenize_name(&name).into_iter().chain(
+        tokenize_doc(&node.doc)
+          .into_iter()
+          .map(|term| (term, TermField::Doc)),
+      );
+      for (term, field) in terms {
+        shards
+          .entry(shard_key(&term))
+          .or_default()
+          .insert(&term, index, field);
+      }
+    }
+
+    let entries = ctx.full_text_search.then(|| collect_full_text_entries(ctx));
+
+    let mut text_shards = BTreeMap::<char, TrieNode>::new();
+    for (index, entry) in entries.iter().flatten().enumerate() {
+      let terms = tokenize_words(&entry.section, usize::MAX)
+        .into_iter()
+        .chain(tokenize_words(&entry.text, usize::MAX));
+      for term in terms {
+        text_shards.entry(shard_key(&term)).or_default().insert(
+          &term,
+          index,
+          TermField::Doc,
+        );
+      }
+    }
+
+    Self {
+      kinds,
+      nodes,
+      entries,
+      text_title: ctx.messages.search_in_documentation.clone(),
+      shards,
+      text_shards,
+    }
+  }
+
+  /// The index which is loaded on every page, describing the chunks.
+  pub fn manifest(&self) -> serde_json::Value {
+    let mut manifest = json!({
+      "kind": "search",
+      "dir": SEARCH_CHUNKS_DIR,
+      "kinds": self.kinds,
+      "nodeChunkSize": NODE_CHUNK_SIZE,
+      "nodeChunks": self.nodes.len().div_ceil(NODE_CHUNK_SIZE),
+      "termShards": self.shards.keys().collect::<String>(),
+    });
+
+    if let Some(entries) = &self.entries {
+      manifest["entryChunkSize"] = json!(ENTRY_CHUNK_SIZE);
+      manifest["entryChunks"] = json!(entries.len().div_ceil(ENTRY_CHUNK_SIZE));
+      manifest["textShards"] =
+        json!(self.text_shards.keys().collect::<String>());
+      manifest["textTitle"] = json!(self.text_title);
+    }
+
+    manifest
+  }
+
+  /// The chunks of the index, by name. Term shards are named `terms_{key}`
+  /// and contain a trie of the terms starting with the key, node chunks are
+  /// named `nodes_{index}` and contain the nodes as
+  /// `[name, file, url, kinds, deprecated]`. With full-text search, the
+  /// shards of the documentation terms are named `text_{key}`, and entry
+  /// chunks are named `entries_{index}` and contain the entries as
+  /// `[name, section, url, text]`.
+  pub fn chunks(&self) -> Vec<(String, serde_json::Value)> {
+    let term_shards = self
+      .shards
+      .iter()
+      .map(|(key, trie)| (format!("terms_{key}"), trie.to_json()));
+
+    let node_chunks =
+      self
+        .nodes
+        .chunks(NODE_CHUNK_SIZE)
+        .enumerate()
+        .map(|(i, nodes)| {
+          let nodes = nodes
+            .iter()
+            .map(|node| {
+              json!([
+                node.name,
+                node.file,
+                html_escape::encode_double_quoted_attribute(&node.url),
+                node.kind.iter().map(|kind| kind.char).collect::<String>(),
+                node.deprecated,
+              ])
+            })
+            .collect::<Vec<_>>();
+          (format!("nodes_{i}"), nodes.into())
+        });
+
+    let text_shards = self
+      .text_shards
+      .iter()
+      .map(|(key, trie)| (format!("text_{key}"), trie.to_json()));
+
+    let entry_chunks = self
+      .entries
+      .iter()
+      .flat_map(|entries| entries.chunks(ENTRY_CHUNK_SIZE))
+      .enumerate()
+      .map(|(i, entries)| {
+        let entries = entries
+          .iter()
+          .map(|entry| {
+            json!([entry.name, entry.section, entry.url, entry.text])
+          })
+          .collect::<Vec<_>>();
+        (format!("entries_{i}"), entries.into())
+      });
+
+    term_shards
+      .chain(node_chunks)
+      .chain(text_shards)
+      .chain(entry_chunks)
+      .collect()
+  }
+}
+
+/// The files of the search index: the manifest, followed by the chunks.
+///
+/// With `hashed`, the chunks are written under filenames containing the hash
+/// of their content, which the manifest maps the chunk names to, so that the
+/// URL of a chunk changes along with its content.
+pub(crate) fn get_search_index_files(
   ctx: &GenerateCtx,
-) -> Result<String, anyhow::Error> {
-  let search_index = generate_search_index(ctx);
-  let search_index_str = serde_json::to_string(&search_index)?;
+  hashed: bool,
+) -> Result<Vec<(String, String)>, anyhow::Error> {
+  let search_index = InvertedSearchIndex::new(ctx);
 
-  let index = format!(
-    r#"(function () {{
+  let mut chunk_filenames = serde_json::Map::new();
+  let mut files = vec![];
+  for (name, chunk) in search_index.chunks() {
+    let content = format!(
+      r#"(function () {{
+  window.DENO_DOC_SEARCH_CHUNKS = window.DENO_DOC_SEARCH_CHUNKS || {{}};
+  window.DENO_DOC_SEARCH_CHUNKS["{name}"] = {};
+}})()"#,
+      serde_json::to_string(&chunk)?
+    );
+    let filename = if hashed {
+      let filename =
+        super::incremental::hashed_filename(&format!("{name}.js"), &content);
+      chunk_filenames.insert(name, filename.clone().into());
+      filename
+    } else {
+      format!("{name}.js")
+    };
+    files.push((format!("{SEARCH_CHUNKS_DIR}/{filename}"), content));
+  }
+
+  let mut manifest = search_index.manifest();
+  if hashed {
+    manifest["chunkFiles"] = chunk_filenames.into();
+  }
+  files.insert(
+    0,
+    (
+      super::SEARCH_INDEX_FILENAME.to_string(),
+      format!(
+        r#"(function () {{
   window.DENO_DOC_SEARCH_INDEX = {};
 }})()"#,
-    search_index_str
+        serde_json::to_string(&manifest)?
+      ),
+    ),
   );
-  Ok(index)
+
+  Ok(files)
 }
@@ -0,0 +1,78 @@
+use super::GenerateCtx;
+
+pub const SITEMAP_FILENAME: &str = "sitemap.xml";
+pub const ROBOTS_FILENAME: &str = "robots.txt";
+
+/// The canonical link of the page at the given path, relative to the root of
+/// the output. Links to the canonical version if the output is an alias of
+/// another version, otherwise to the page under
+/// [`GenerateOptions::base_url`].
+///
+/// [`GenerateOptions::base_url`]: super::GenerateOptions::base_url
+pub(crate) fn canonical_url(
+  ctx: &GenerateCtx,
+  root: &str,
+  path: &str,
+) -> Option<String> {
+  if let Some(canonical_root) = ctx
+    .versions
+    .as_ref()
+    .and_then(|versions| versions.canonical_root.as_ref())
+  {
+    return Some(format!(
+      "{}{path}",
+      super::versions::join_root(root, canonical_root)
+    ));
+  }
+
+  let base_url = ctx.base_url.as_ref()?;
+  Some(absolute_url(base_url, path))
+}
+
+fn absolute_url(base_url: &str, path: &str) -> String {
+  let separator = if base_url.ends_with('/') { "" } else { "/" };
+  format!("{base_url}{separator}{}", path.trim_start_matches("./"))
+}
+
+/// Generates the `sitemap.xml` listing the given pages, and a `robots.txt`
+/// pointing to it. Returns nothing if no base URL is set, as both require
+/// absolute URLs.
+pub(crate) fn get_seo_files<'a>(
+  ctx: &GenerateCtx,
+  pages: impl IntoIterator<Item = &'a str>,
+) -> Vec<(String, String)> {
+  let Some(base_url) = &ctx.base_url else {
+    return vec![];
+  };
+
+  let mut paths = pages
+    .into_iter()
+    .map(|page| {
+      let page = page.trim_start_matches("./");
+      // the root index page is linked to as the root of the output
+      if page == "index.html" { "" } else { page }
+    })
+    .collect::<Vec<_>>();
+  paths.sort_unstable();
+
+  let mut sitemap = String::from(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+  );
+  for path in paths {
+    sitemap.push_str(&format!(
+      "  <url><loc>{}</loc></url>\n",
+      html_escape::encode_text(&absolute_url(base_url, path))
+    ));
+  }
+  sitemap.push_str("</urlset>\n");
+
+  let robots = format!(
+    "User-agent: *\nAllow: /\n\nSitemap: {}\n",
+    absolute_url(base_url, SITEMAP_FILENAME)
+  );
+
+  vec![
+    (SITEMAP_FILENAME.to_string(), sitemap),
+    (ROBOTS_FILENAME.to_string(), robots),
+  ]
+}
@@ -0,0 +1,273 @@
+use super::DocNodeWithContext;
+use super::FileMode;
+use super::GenerateCtx;
+use super::HrefResolver;
+use super::UrlResolveKind;
+use super::hierarchy::HIERARCHY_FILENAME;
+use super::hierarchy::HierarchyPageCtx;
+use super::jsdoc::ModuleDocCtx;
+use super::pages;
+use super::pages::SymbolPage;
+use super::partition;
+use super::symbols::SymbolContentCtx;
+use super::symbols::SymbolGroupCtx;
+use super::usage::UsagesCtx;
+use super::util;
+use super::util::BreadcrumbsCtx;
+use crate::html::Shared;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// A resolver for [`generate_single_page`], which resolves the pages to
+/// fragments of the single page. The ids of the fragments are the paths of
+/// the pages without the `.html` extension, eg. `#mod.ts/~/Foo` for a
+/// symbol, and `#mod.ts` for a module.
+///
+/// Everything else is resolved by the wrapped resolver.
+pub struct SinglePageHrefResolver {
+  pub inner: Shared<dyn HrefResolver>,
+  /// The language the ids of the category sections are slugified for.
+  pub lang: String,
+}
+
+impl HrefResolver for SinglePageHrefResolver {
+  fn resolve_path(
+    &self,
+    _current: UrlResolveKind,
+    target: UrlResolveKind,
+  ) -> String {
+    match target {
+      UrlResolveKind::Root => "#".to_string(),
+      UrlResolveKind::File { file } if file.is_main => "#".to_string(),
+      UrlResolveKind::File { file } => format!("#{}", file.path),
+      UrlResolveKind::Symbol { file, symbol } => {
+        format!("#{}/~/{symbol}", file.path)
+      }
+      UrlResolveKind::AllSymbols => "#all_symbols".to_string(),
+      UrlResolveKind::Category { category } => {
+        format!("#{}", util::slugify_localized(category, &self.lang))
+      }
+    }
+  }
+
+  fn resolve_global_symbol(&self, symbol: &[String]) -> Option<String> {
+    self.inner.resolve_global_symbol(symbol)
+  }
+
+  fn resolve_import_href(
+    &self,
+    symbol: &[String],
+    src: &str,
+  ) -> Option<String> {
+    self.inner.resolve_import_href(symbol, src)
+  }
+
+  fn resolve_source(&self, location: &crate::Location) -> Option<String> {
+    self.inner.resolve_source(location)
+  }
+
+  fn resolve_external_jsdoc_module(
+    &self,
+    module: &str,
+    symbol: Option<&str>,
+  ) -> Option<(String, String)> {
+    self.inner.resolve_external_jsdoc_module(module, symbol)
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SinglePageSectionCtx {
+  pub id: String,
+  /// The ids of the redirect pages which point to this section.
+  pub aliases: Vec<String>,
+  pub breadcrumbs_ctx: BreadcrumbsCtx,
+  pub usage: Option<UsagesCtx>,
+  pub module_doc: Option<ModuleDocCtx>,
+  pub overview: Option<SymbolContentCtx>,
+  pub symbol_group: Option<SymbolGroupCtx>,
+}
+
+impl SinglePageSectionCtx {
+  fn from_index(id: String, index: pages::IndexCtx) -> Self {
+    Self {
+      id,
+      aliases: vec![],
+      breadcrumbs_ctx: index.breadcrumbs_ctx,
+      usage: index.usage,
+      module_doc: index.module_doc,
+      overview: index.overview,
+      symbol_group: None,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind")]
+pub struct SinglePageCtx {
+  pub title: String,
+  pub stylesheet: String,
+  pub script_js: &'static str,
+  pub darkmode_toggle_js: &'static str,
+  pub head_inject: Option<String>,
+  pub breadcrumbs_ctx: BreadcrumbsCtx,
+  pub disable_search: bool,
+  pub version_switcher: Option<super::VersionSwitcherCtx>,
+  pub sections: Vec<SinglePageSectionCtx>,
+}
+
+impl SinglePageCtx {
+  pub const TEMPLATE: &'static str = "pages/single_page";
+
+  /// Expects the context to use a [`SinglePageHrefResolver`].
+  pub fn new(ctx: &GenerateCtx) -> Self {
+    let mut sections = vec![];
+
+    let index = super::main_index_ctx(ctx);
+    let breadcrumbs_ctx = index.breadcrumbs_ctx.clone();
+    sections.push(SinglePageSectionCtx::from_index(String::new(), index));
+
+    let all_doc_nodes = ctx
+      .doc_nodes
+      .values()
+      .flatten()
+      .cloned()
+      .collect::<Vec<DocNodeWithContext>>();
+
+    let all_symbols = pages::AllSymbolsCtx::new(
+      ctx,
+      partition::partition_nodes_by_entrypoint(
+        ctx,
+        all_doc_nodes.iter().map(Cow::Borrowed),
+        true,
+      ),
+    );
+    sections.push(SinglePageSectionCtx {
+      id: "all_symbols".to_string(),
+      aliases: vec![],
+      breadcrumbs_ctx: all_symbols.breadcrumbs_ctx,
+      usage: None,
+      module_doc: None,
+      overview: Some(all_symbols.content),
+      symbol_group: None,
+    });
+
+    if ctx.file_mode == FileMode::SingleDts {
+      let categories = partition::partition_nodes_by_category(
+        ctx,
+        all_doc_nodes.iter().map(Cow::Borrowed),
+        true,
+      );
+
+      if categories.len() != 1 {
+        for (category, nodes) in &categories {
+          let partitions = partition::partition_nodes_by_kind(
+            ctx,
+            nodes.iter().map(Cow::Borrowed),
+            false,
+          );
+
+          let index = pages::IndexCtx::new_category(
+            ctx,
+            category,
+            partitions,
+            &all_doc_nodes,
+          );
+          sections.push(SinglePageSectionCtx::from_index(
+            util::slugify_localized(category, &ctx.messages.lang),
+            index,
+          ));
+        }
+      }
+    }
+
+    for (short_path, doc_nodes) in &ctx.doc_nodes {
+      if !short_path.is_main {
+        let index = pages::IndexCtx::new(
+          ctx,
+          Some(short_path.clone()),
+          partition::partition_nodes_by_kind(
+            ctx,
+            doc_nodes.iter().map(Cow::Borrowed),
+            ctx.file_mode == FileMode::SingleDts,
+          ),
+          false,
+        );
+        sections.push(SinglePageSectionCtx::from_index(
+          short_path.path.clone(),
+          index,
+        ));
+      }
+
+      let symbol_pages =
+        pages::generate_symbol_pages_for_module(ctx, short_path, doc_nodes);
+      for symbol_page in symbol_pages {
+        match symbol_page {
+          SymbolPage::Symbol {
+            breadcrumbs_ctx,
+            symbol_group_ctx,
+            ..
+          } => {
+            sections.push(SinglePageSectionCtx {
+              id: format!("{}/~/{}", short_path.path, symbol_group_ctx.name),
+              aliases: vec![],
+              breadcrumbs_ctx,
+              usage: None,
+              module_doc: None,
+              overview: None,
+              symbol_group: Some(symbol_group_ctx),
+            });
+          }
+          // redirects follow the page they point to
+          SymbolPage::Redirect { current_symbol, .. } => {
+            if let Some(section) = sections.last_mut() {
+              section
+                .aliases
+                .push(format!("{}/~/{current_symbol}", short_path.path));
+            }
+          }
+        }
+      }
+    }
+
+    if ctx.class_hierarchy {
+      let hierarchy = HierarchyPageCtx::new(ctx);
+      sections.push(SinglePageSectionCtx {
+        // the diagrams link to the hierarchy page relative to the root
+        id: HIERARCHY_FILENAME.to_string(),
+        aliases: vec![],
+        breadcrumbs_ctx: hierarchy.breadcrumbs_ctx,
+        usage: None,
+        module_doc: None,
+        overview: Some(SymbolContentCtx {
+          id: util::Id::empty(),
+          sections: hierarchy.sections,
+          docs: None,
+        }),
+        symbol_group: None,
+      });
+    }
+
+    let stylesheet = [
+      super::STYLESHEET,
+      super::PAGE_STYLESHEET,
+      super::RESET_STYLESHEET,
+      #[cfg(feature = "comrak")]
+      super::comrak::COMRAK_STYLESHEET,
+    ]
+    .join("\n");
+
+    Self {
+      title: ctx
+        .messages
+        .documentation_title(ctx.package_name.as_deref()),
+      stylesheet,
+      script_js: super::SCRIPT_JS,
+      darkmode_toggle_js: super::DARKMODE_TOGGLE_JS,
+      head_inject: ctx.head_inject.clone().map(|head_inject| head_inject("./")),
+      breadcrumbs_ctx,
+      disable_search: true,
+      version_switcher: None,
+      sections,
+    }
+  }
+}
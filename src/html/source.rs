@@ -0,0 +1,340 @@
+use super::GenerateCtx;
+use super::ShortPath;
+use super::UrlResolveKind;
+use super::pages::HtmlHeadCtx;
+use super::util::BreadcrumbCtx;
+use super::util::BreadcrumbsCtx;
+use crate::html::Shared;
+use crate::node::DocNodeDef;
+use deno_ast::MediaType;
+use deno_ast::ModuleSpecifier;
+use deno_ast::TokenOrComment;
+use deno_ast::swc::lexer::token::Token;
+use deno_ast::swc::lexer::token::Word;
+use deno_graph::Module;
+use deno_graph::ModuleGraph;
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Identifiers which are only keywords in some positions, and as such are
+/// lexed as plain identifiers.
+const CONTEXTUAL_KEYWORDS: &[&str] = &[
+  "abstract",
+  "accessor",
+  "any",
+  "as",
+  "asserts",
+  "async",
+  "bigint",
+  "boolean",
+  "declare",
+  "from",
+  "get",
+  "global",
+  "implements",
+  "infer",
+  "interface",
+  "is",
+  "keyof",
+  "module",
+  "namespace",
+  "never",
+  "number",
+  "object",
+  "of",
+  "override",
+  "private",
+  "protected",
+  "public",
+  "readonly",
+  "satisfies",
+  "set",
+  "static",
+  "string",
+  "symbol",
+  "type",
+  "undefined",
+  "unique",
+  "unknown",
+];
+
+/// A module whose source is rendered as a page of the output.
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+  pub short_path: Shared<ShortPath>,
+  pub text: Arc<str>,
+}
+
+impl SourceFile {
+  /// The path of the page of the source relative to the root of the output,
+  /// without the extension.
+  pub fn path(&self) -> &str {
+    // a single module is its own common ancestor, so its short path is `.`,
+    // which cannot name a page
+    if self.short_path.path == "." {
+      self
+        .short_path
+        .specifier
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .unwrap_or("mod")
+    } else {
+      &self.short_path.path
+    }
+  }
+}
+
+/// Collects the source text of the JavaScript and TypeScript modules in the
+/// graph, to be passed as [`GenerateOptions::source_files`].
+///
+/// [`GenerateOptions::source_files`]: super::GenerateOptions::source_files
+pub fn collect_source_files(
+  graph: &ModuleGraph,
+) -> IndexMap<ModuleSpecifier, Arc<str>> {
+  graph
+    .modules()
+    .filter_map(|module| match module {
+      Module::Js(module) => {
+        Some((module.specifier.clone(), module.source.text.clone()))
+      }
+      _ => None,
+    })
+    .collect()
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SourceLineCtx {
+  pub number: usize,
+  pub html: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind")]
+pub struct SourcePageCtx {
+  pub html_head_ctx: HtmlHeadCtx,
+  pub breadcrumbs_ctx: BreadcrumbsCtx,
+  pub disable_search: bool,
+  pub version_switcher: Option<super::VersionSwitcherCtx>,
+  pub lines: Vec<SourceLineCtx>,
+}
+
+impl SourcePageCtx {
+  pub const TEMPLATE: &'static str = "pages/source";
+
+  pub fn new(ctx: &GenerateCtx, source: &SourceFile) -> Self {
+    let root = "../".repeat(source.path().split('/').count() - 1);
+    let display_name = source.short_path.display_name();
+
+    let mut html_head_ctx = HtmlHeadCtx::new(
+      ctx,
+      UrlResolveKind::Root,
+      &root,
+      Some(display_name),
+      Some(&source.short_path),
+    );
+    html_head_ctx.canonical_url =
+      super::seo::canonical_url(ctx, &root, &format!("{}.html", source.path()));
+
+    let breadcrumbs_ctx = BreadcrumbsCtx {
+      parts: vec![
+        BreadcrumbCtx {
+          name: ctx
+            .package_name
+            .clone()
+            .unwrap_or_else(|| ctx.messages.breadcrumb_index.clone()),
+          href: if root.is_empty() {
+            "./".to_string()
+          } else {
+            root.clone()
+          },
+          is_symbol: false,
+          is_first_symbol: false,
+        },
+        BreadcrumbCtx {
+          name: display_name.to_string(),
+          href: String::new(),
+          is_symbol: false,
+          is_first_symbol: false,
+        },
+      ],
+    };
+
+    let declarations = collect_declarations(ctx, &source.short_path, &root);
+
+    Self {
+      html_head_ctx,
+      breadcrumbs_ctx,
+      disable_search: ctx.disable_search,
+      version_switcher: None,
+      lines: highlight(
+        &source.text,
+        MediaType::from_specifier(&source.short_path.specifier),
+        &declarations,
+      )
+      .into_iter()
+      .enumerate()
+      .map(|(i, html)| SourceLineCtx {
+        number: i + 1,
+        html,
+      })
+      .collect(),
+    }
+  }
+}
+
+/// A declaration in the source, which links to its symbol page.
+struct Declaration {
+  byte_index: usize,
+  name: String,
+  href: String,
+}
+
+fn collect_declarations(
+  ctx: &GenerateCtx,
+  source_path: &ShortPath,
+  root: &str,
+) -> Vec<Declaration> {
+  let filename = source_path.specifier.as_str();
+
+  let mut declarations = ctx
+    .doc_nodes
+    .values()
+    .flatten()
+    .flat_map(|node| {
+      let mut nodes = vec![];
+      let mut stack = vec![node];
+      while let Some(node) = stack.pop() {
+        nodes.push(node);
+        if let Some(children) = &node.namespace_children {
+          stack.extend(children);
+        }
+      }
+      nodes
+    })
+    .filter(|node| {
+      &*node.location.filename == filename
+        && !matches!(
+          node.def,
+          DocNodeDef::ModuleDoc | DocNodeDef::Import { .. }
+        )
+    })
+    .map(|node| Declaration {
+      byte_index: node.location.byte_index,
+      name: node.get_name().to_string(),
+      href: format!(
+        "{root}{}",
+        ctx.resolve_path(
+          UrlResolveKind::Root,
+          UrlResolveKind::Symbol {
+            file: &node.origin,
+            symbol: &node.get_qualified_name(),
+          },
+        )
+      ),
+    })
+    .collect::<Vec<_>>();
+
+  // a symbol that is exported from several modules links to the first one
+  declarations.sort_by_key(|declaration| declaration.byte_index);
+  declarations.dedup_by_key(|declaration| declaration.byte_index);
+  declarations
+}
+
+/// Splits the source into lines of highlighted HTML.
+fn highlight(
+  text: &str,
+  media_type: MediaType,
+  declarations: &[Declaration],
+) -> Vec<String> {
+  let mut lines = vec![];
+  let mut line = String::new();
+  let mut push =
+    |range: Range<usize>, class: Option<&str>, href: Option<&str>| {
+      let mut parts = text[range].split('\n').peekable();
+      while let Some(part) = parts.next() {
+        let part = part.strip_suffix('\r').unwrap_or(part);
+        if !part.is_empty() {
+          let escaped = html_escape::encode_safe(part);
+          match (class, href) {
+            (_, Some(href)) => line.push_str(&format!(
+              r#"<a href="{}" class="sourceDeclaration">{escaped}</a>"#,
+              html_escape::encode_double_quoted_attribute(href)
+            )),
+            (Some(class), None) => line
+              .push_str(&format!(r#"<span class="{class}">{escaped}</span>"#)),
+            (None, None) => line.push_str(&escaped),
+          }
+        }
+        if parts.peek().is_some() {
+          lines.push(std::mem::take(&mut line));
+        }
+      }
+    };
+
+  let lexable = matches!(
+    media_type,
+    MediaType::JavaScript
+      | MediaType::Jsx
+      | MediaType::Mjs
+      | MediaType::Cjs
+      | MediaType::TypeScript
+      | MediaType::Mts
+      | MediaType::Cts
+      | MediaType::Dts
+      | MediaType::Dmts
+      | MediaType::Dcts
+      | MediaType::Tsx
+  );
+
+  let mut pos = 0;
+  if lexable {
+    let mut declarations = declarations.iter().peekable();
+    let mut pending = None;
+
+    for item in deno_ast::lex(text, media_type) {
+      if item.range.start < pos {
+        continue;
+      }
+      push(pos..item.range.start, None, None);
+      pos = item.range.end;
+
+      while let Some(declaration) =
+        declarations.next_if(|d| d.byte_index <= item.range.start)
+      {
+        pending = Some(declaration);
+      }
+
+      let class = match &item.inner {
+        TokenOrComment::Comment { .. } => Some("comment"),
+        TokenOrComment::Token(token) => match token {
+          Token::Word(Word::Keyword(_)) => Some("keyword"),
+          Token::Word(Word::Null | Word::True | Word::False) => Some("literal"),
+          Token::Word(Word::Ident(_)) => {
+            let ident = &text[item.range.clone()];
+            if let Some(declaration) =
+              pending.filter(|declaration| declaration.name == ident)
+            {
+              pending = None;
+              push(item.range, None, Some(&declaration.href));
+              continue;
+            }
+            CONTEXTUAL_KEYWORDS.contains(&ident).then_some("keyword")
+          }
+          Token::Str { .. }
+          | Token::Template { .. }
+          | Token::BackQuote
+          | Token::Regex(..) => Some("string"),
+          Token::Num { .. } | Token::BigInt { .. } => Some("number"),
+          _ => None,
+        },
+      };
+      push(item.range, class, None);
+    }
+  }
+  push(pos..text.len(), None, None);
+
+  lines.push(line);
+  lines
+}
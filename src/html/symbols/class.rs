@@ -1,12 +1,14 @@
+use crate::accessor::AccessorDef;
 use crate::class::ClassMethodDef;
 use crate::class::ClassPropertyDef;
 use crate::html::DocNodeWithContext;
 use crate::html::parameters::render_params;
 use crate::html::render_context::RenderContext;
+use crate::html::symbols::interface::accessor_tags;
+use crate::html::symbols::interface::render_accessor_type;
 use crate::html::types::render_type_def_colon;
 use crate::html::util::*;
 use deno_ast::swc::ast::Accessibility;
-use deno_ast::swc::ast::MethodKind;
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
@@ -26,8 +28,10 @@ pub(crate) fn render_class(
 
   let ctx = &ctx.with_current_type_params(current_type_params);
 
+  let accessors = class_def.accessors();
   let class_items = partition_properties_and_classes(
-    class_def.properties.clone(),
+    &class_def.properties,
+    &accessors,
     class_def.methods.clone(),
   );
 
@@ -57,7 +61,7 @@ pub(crate) fn render_class(
   if !class_items.properties.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "Properties",
+      &ctx.ctx.messages.properties,
       SectionContentCtx::DocEntry(render_class_properties(
         ctx,
         name,
@@ -69,7 +73,7 @@ pub(crate) fn render_class(
   if !class_items.methods.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "Methods",
+      &ctx.ctx.messages.methods,
       SectionContentCtx::DocEntry(render_class_methods(
         ctx,
         name,
@@ -81,7 +85,7 @@ pub(crate) fn render_class(
   if !class_items.static_properties.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "Static Properties",
+      &ctx.ctx.messages.static_properties,
       SectionContentCtx::DocEntry(render_class_properties(
         ctx,
         name,
@@ -93,7 +97,7 @@ pub(crate) fn render_class(
   if !class_items.static_methods.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "Static Methods",
+      &ctx.ctx.messages.static_methods,
       SectionContentCtx::DocEntry(render_class_methods(
         ctx,
         name,
@@ -102,6 +106,13 @@ pub(crate) fn render_class(
     ));
   }
 
+  sections.extend(crate::html::hierarchy::render_hierarchy_section(
+    ctx, doc_node,
+  ));
+  sections.extend(crate::html::references::render_type_references(
+    ctx, doc_node,
+  ));
+
   sections
 }
 
@@ -146,7 +157,7 @@ fn render_constructors(
 
   Some(SectionCtx::new(
     ctx,
-    "Constructors",
+    &ctx.ctx.messages.constructors,
     SectionContentCtx::DocEntry(items),
   ))
 }
@@ -161,133 +172,98 @@ pub struct IndexSignatureCtx {
   pub source_href: Option<String>,
 }
 
-enum PropertyOrMethod {
-  Property(ClassPropertyDef),
-  Method(ClassMethodDef),
+enum PropertyOrAccessor<'a> {
+  Property(&'a ClassPropertyDef),
+  Accessor(&'a AccessorDef),
 }
 
-impl PropertyOrMethod {
-  fn prop(&self) -> Option<&ClassPropertyDef> {
-    if let PropertyOrMethod::Property(prop) = self {
-      Some(prop)
-    } else {
-      None
+impl PropertyOrAccessor<'_> {
+  fn name(&self) -> &str {
+    match self {
+      PropertyOrAccessor::Property(prop) => &prop.name,
+      PropertyOrAccessor::Accessor(accessor) => &accessor.name,
     }
   }
 
-  fn method(&self) -> Option<&ClassMethodDef> {
-    if let PropertyOrMethod::Method(method) = self {
-      Some(method)
-    } else {
-      None
+  fn accessibility(&self) -> Option<Accessibility> {
+    match self {
+      PropertyOrAccessor::Property(prop) => prop.accessibility,
+      PropertyOrAccessor::Accessor(accessor) => accessor.accessibility,
     }
   }
 }
 
-fn property_or_method_cmp(
-  a: &PropertyOrMethod,
-  b: &PropertyOrMethod,
+fn property_or_accessor_cmp(
+  a: &PropertyOrAccessor,
+  b: &PropertyOrAccessor,
 ) -> std::cmp::Ordering {
-  let name_cmp = {
-    let a_name = a
-      .prop()
-      .map(|prop| &prop.name)
-      .unwrap_or_else(|| &a.method().unwrap().name);
-    let b_name = b
-      .prop()
-      .map(|prop| &prop.name)
-      .unwrap_or_else(|| &b.method().unwrap().name);
-
-    a_name.cmp(b_name)
-  };
-
-  let accessibility_cmp = {
-    let a_accessibility = a
-      .prop()
-      .map(|prop| &prop.accessibility)
-      .unwrap_or_else(|| &a.method().unwrap().accessibility);
-    let b_accessibility = b
-      .prop()
-      .map(|prop| &prop.accessibility)
-      .unwrap_or_else(|| &b.method().unwrap().accessibility);
-
-    a_accessibility
-      .and_then(|a_accessibility| {
-        b_accessibility.map(|b_accessibility| {
-          match (a_accessibility, b_accessibility) {
-            (Accessibility::Public, Accessibility::Public) => {
-              std::cmp::Ordering::Equal
-            }
-            (Accessibility::Private, Accessibility::Private) => {
-              std::cmp::Ordering::Equal
-            }
-            (Accessibility::Protected, Accessibility::Protected) => {
-              std::cmp::Ordering::Equal
-            }
-            (Accessibility::Private, _) => std::cmp::Ordering::Greater,
-            (_, Accessibility::Private) => std::cmp::Ordering::Less,
-            (Accessibility::Protected, _) => std::cmp::Ordering::Greater,
-            (_, Accessibility::Protected) => std::cmp::Ordering::Less,
+  let name_cmp = a.name().cmp(b.name());
+
+  let accessibility_cmp = a
+    .accessibility()
+    .and_then(|a_accessibility| {
+      b.accessibility().map(|b_accessibility| {
+        match (a_accessibility, b_accessibility) {
+          (Accessibility::Public, Accessibility::Public) => {
+            std::cmp::Ordering::Equal
           }
-        })
-      })
-      .unwrap_or(std::cmp::Ordering::Equal)
-  };
-
-  let accessor_cmp = {
-    let a_accessor = a.method().map(|method| &method.kind);
-    let b_accessor = b.method().map(|method| &method.kind);
-
-    a_accessor
-      .and_then(|a_accessor| {
-        b_accessor.map(|b_accessor| match (a_accessor, b_accessor) {
-          (MethodKind::Getter, MethodKind::Getter) => std::cmp::Ordering::Equal,
-          (MethodKind::Setter, MethodKind::Setter) => std::cmp::Ordering::Equal,
-          (MethodKind::Getter, MethodKind::Setter) => {
-            std::cmp::Ordering::Greater
+          (Accessibility::Private, Accessibility::Private) => {
+            std::cmp::Ordering::Equal
+          }
+          (Accessibility::Protected, Accessibility::Protected) => {
+            std::cmp::Ordering::Equal
           }
-          (MethodKind::Setter, MethodKind::Getter) => std::cmp::Ordering::Less,
-          _ => unreachable!(),
-        })
+          (Accessibility::Private, _) => std::cmp::Ordering::Greater,
+          (_, Accessibility::Private) => std::cmp::Ordering::Less,
+          (Accessibility::Protected, _) => std::cmp::Ordering::Greater,
+          (_, Accessibility::Protected) => std::cmp::Ordering::Less,
+        }
       })
-      .unwrap_or(std::cmp::Ordering::Equal)
-  };
+    })
+    .unwrap_or(std::cmp::Ordering::Equal);
 
-  accessibility_cmp.then(accessor_cmp).then(name_cmp)
+  accessibility_cmp.then(name_cmp)
 }
 
-struct ClassItems {
-  properties: Vec<PropertyOrMethod>,
-  static_properties: Vec<PropertyOrMethod>,
+struct ClassItems<'a> {
+  properties: Vec<PropertyOrAccessor<'a>>,
+  static_properties: Vec<PropertyOrAccessor<'a>>,
   methods: BTreeMap<Box<str>, Vec<ClassMethodDef>>,
   static_methods: BTreeMap<Box<str>, Vec<ClassMethodDef>>,
 }
 
-fn partition_properties_and_classes(
-  properties: Box<[ClassPropertyDef]>,
+fn partition_properties_and_classes<'a>(
+  properties: &'a [ClassPropertyDef],
+  accessors: &'a [AccessorDef],
   methods: Box<[ClassMethodDef]>,
-) -> ClassItems {
+) -> ClassItems<'a> {
   let mut out_properties = vec![];
   let mut out_static_properties = vec![];
   let mut out_methods = BTreeMap::new();
   let mut out_static_methods = BTreeMap::new();
 
-  for property in properties.into_vec().into_iter() {
+  for property in properties {
     if property.is_static {
-      out_static_properties.push(PropertyOrMethod::Property(property));
+      out_static_properties.push(PropertyOrAccessor::Property(property));
     } else {
-      out_properties.push(PropertyOrMethod::Property(property));
+      out_properties.push(PropertyOrAccessor::Property(property));
+    }
+  }
+
+  for accessor in accessors {
+    if accessor.is_static {
+      out_static_properties.push(PropertyOrAccessor::Accessor(accessor));
+    } else {
+      out_properties.push(PropertyOrAccessor::Accessor(accessor));
     }
   }
 
   for method in methods.into_vec().into_iter() {
-    if matches!(method.kind, MethodKind::Getter | MethodKind::Setter) {
-      if method.is_static {
-        out_static_properties.push(PropertyOrMethod::Method(method));
-      } else {
-        out_properties.push(PropertyOrMethod::Method(method));
-      }
-    } else if method.is_static {
+    if crate::accessor::is_accessor(method.kind) {
+      continue;
+    }
+
+    if method.is_static {
       let entry = out_static_methods
         .entry(method.name.clone())
         .or_insert(vec![]);
@@ -298,8 +274,8 @@ fn partition_properties_and_classes(
     }
   }
 
-  out_properties.sort_by(property_or_method_cmp);
-  out_static_properties.sort_by(property_or_method_cmp);
+  out_properties.sort_by(property_or_accessor_cmp);
+  out_static_properties.sort_by(property_or_accessor_cmp);
 
   ClassItems {
     properties: out_properties,
@@ -312,56 +288,26 @@ fn partition_properties_and_classes(
 fn render_class_accessor(
   ctx: &RenderContext,
   class_name: &str,
-  getter: Option<&ClassMethodDef>,
-  setter: Option<&ClassMethodDef>,
+  accessor: &AccessorDef,
 ) -> DocEntryCtx {
-  let getter_or_setter = getter.or(setter).unwrap();
-
-  let name = &getter_or_setter.name;
   let id = IdBuilder::new(ctx.ctx)
     .kind(IdKind::Accessor)
-    .name(name)
+    .name(&accessor.name)
     .build();
-  let ts_type = getter
-    .and_then(|getter| getter.function_def.return_type.as_ref())
-    .or_else(|| {
-      setter.and_then(|setter| {
-        setter
-          .function_def
-          .params
-          .first()
-          .and_then(|param| param.ts_type.as_ref())
-      })
-    })
-    .map_or_else(String::new, |ts_type| render_type_def_colon(ctx, ts_type));
-  let js_doc = getter_or_setter.js_doc.doc.as_deref();
-
-  let mut tags = Tag::from_js_doc(&getter_or_setter.js_doc);
-  if let Some(tag) = Tag::from_accessibility(getter_or_setter.accessibility) {
-    tags.insert(tag);
-  }
-  if getter_or_setter.is_abstract {
-    tags.insert(Tag::Abstract);
-  }
-  if getter.is_some() && setter.is_none() {
-    tags.insert(Tag::Readonly);
-  } else if getter.is_none() && setter.is_some() {
-    tags.insert(Tag::Writeonly);
-  }
 
   DocEntryCtx::new(
     ctx,
     id,
-    Some(html_escape::encode_text(&name).into_owned()),
+    Some(html_escape::encode_text(&accessor.name).into_owned()),
     ctx.lookup_symbol_href(&qualify_drilldown_name(
       class_name,
-      name,
-      getter_or_setter.is_static,
+      &accessor.name,
+      accessor.is_static,
     )),
-    &ts_type,
-    tags,
-    js_doc,
-    &getter_or_setter.location,
+    &render_accessor_type(ctx, accessor),
+    accessor_tags(accessor),
+    accessor.js_doc.doc.as_deref(),
+    &accessor.location,
   )
 }
 
@@ -457,48 +403,19 @@ fn render_class_property(
 fn render_class_properties(
   ctx: &RenderContext,
   class_name: &str,
-  properties: Vec<PropertyOrMethod>,
+  properties: Vec<PropertyOrAccessor>,
 ) -> Vec<DocEntryCtx> {
-  let mut properties = properties.into_iter().peekable();
-  let mut out = vec![];
-
-  while let Some(property) = properties.next() {
-    let content = match property {
-      PropertyOrMethod::Property(property) => {
-        render_class_property(ctx, class_name, &property)
+  properties
+    .into_iter()
+    .map(|property| match property {
+      PropertyOrAccessor::Property(property) => {
+        render_class_property(ctx, class_name, property)
       }
-      PropertyOrMethod::Method(method) => {
-        let (getter, setter) = if method.kind == MethodKind::Getter {
-          let next_is_setter = properties
-            .peek()
-            .and_then(|property| property.method())
-            .is_some_and(|method| method.kind == MethodKind::Setter);
-
-          if next_is_setter {
-            let res = if let PropertyOrMethod::Method(method) =
-              properties.next().unwrap()
-            {
-              method
-            } else {
-              unreachable!()
-            };
-
-            (Some(&method), Some(res))
-          } else {
-            (Some(&method), None)
-          }
-        } else {
-          (None, Some(method))
-        };
-
-        render_class_accessor(ctx, class_name, getter, setter.as_ref())
+      PropertyOrAccessor::Accessor(accessor) => {
+        render_class_accessor(ctx, class_name, accessor)
       }
-    };
-
-    out.push(content)
-  }
-
-  out
+    })
+    .collect()
 }
 
 fn render_class_methods(
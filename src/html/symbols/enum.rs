@@ -41,7 +41,7 @@ pub(crate) fn render_enum(
 
   vec![SectionCtx::new(
     render_ctx,
-    "Members",
+    &render_ctx.ctx.messages.members,
     SectionContentCtx::DocEntry(items),
   )]
 }
@@ -3,6 +3,7 @@ use crate::function::FunctionDef;
 use crate::html::DocNodeWithContext;
 use crate::html::parameters::render_params;
 use crate::html::render_context::RenderContext;
+use crate::html::types::render_expanded_type_section;
 use crate::html::types::render_type_def;
 use crate::html::types::render_type_def_colon;
 use crate::html::types::type_params_summary;
@@ -154,6 +155,8 @@ fn render_single_function(
       (&Option<Box<str>>, bool, &Option<Box<str>>),
     >>();
 
+  let mut expanded_param_types = vec![];
+
   let params = function_def
     .params
     .iter()
@@ -182,6 +185,12 @@ fn render_single_function(
           param.ts_type.as_ref()
         };
 
+      if let Some(expanded_type) = ts_type.and_then(|ts_type| {
+        render_expanded_type_section(ctx, ts_type, Some(name.clone()))
+      }) {
+        expanded_param_types.push(expanded_type);
+      }
+
       let mut ts_type = ts_type
         .map(|ts_type| render_type_def_colon(ctx, ts_type))
         .unwrap_or_default();
@@ -244,14 +253,15 @@ fn render_single_function(
   if !params.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "Parameters",
+      &ctx.ctx.messages.parameters,
       SectionContentCtx::DocEntry(params),
     ));
+    sections.extend(expanded_param_types);
   }
 
   sections.push(SectionCtx::new(
     ctx,
-    "Return Type",
+    &ctx.ctx.messages.return_type,
     SectionContentCtx::DocEntry(
       render_function_return_type(
         ctx,
@@ -263,6 +273,14 @@ fn render_single_function(
     ),
   ));
 
+  if let Some(expanded_type) =
+    function_def.return_type.as_ref().and_then(|return_type| {
+      render_expanded_type_section(ctx, return_type, None)
+    })
+  {
+    sections.push(expanded_type);
+  }
+
   let throws = doc_node
     .js_doc
     .tags
@@ -292,7 +310,7 @@ fn render_single_function(
   if !throws.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "Throws",
+      &ctx.ctx.messages.throws,
       SectionContentCtx::DocEntry(throws),
     ));
   }
@@ -313,7 +331,7 @@ fn render_single_function(
   if !references.is_empty() {
     sections.push(SectionCtx::new(
       ctx,
-      "See",
+      &ctx.ctx.messages.see,
       SectionContentCtx::See(references),
     ));
   }
@@ -1,10 +1,13 @@
+use crate::accessor::AccessorDef;
 use crate::html::DocNodeWithContext;
 use crate::html::parameters::render_params;
 use crate::html::render_context::RenderContext;
 use crate::html::symbols::class::IndexSignatureCtx;
+use crate::html::types::render_type_def;
 use crate::html::types::render_type_def_colon;
 use crate::html::types::type_params_summary;
 use crate::html::util::*;
+use indexmap::IndexSet;
 
 pub(crate) fn render_interface(
   ctx: &RenderContext,
@@ -43,9 +46,12 @@ pub(crate) fn render_interface(
     sections.push(call_signatures);
   }
 
-  if let Some(properties) =
-    render_properties(ctx, name, &interface_def.properties)
-  {
+  if let Some(properties) = render_properties(
+    ctx,
+    name,
+    &interface_def.properties,
+    &interface_def.accessors(),
+  ) {
     sections.push(properties);
   }
 
@@ -53,6 +59,13 @@ pub(crate) fn render_interface(
     sections.push(methods);
   }
 
+  sections.extend(crate::html::hierarchy::render_hierarchy_section(
+    ctx, doc_node,
+  ));
+  sections.extend(crate::html::references::render_type_references(
+    ctx, doc_node,
+  ));
+
   sections
 }
 
@@ -86,14 +99,13 @@ pub(crate) fn render_index_signatures(
       ts_type,
       source_href: ctx
         .ctx
-        .href_resolver
-        .resolve_source(&index_signature.location),
+        .resolve_source(ctx.get_current_resolve(), &index_signature.location),
     });
   }
 
   Some(SectionCtx::new(
     ctx,
-    "Index Signatures",
+    &ctx.ctx.messages.index_signatures,
     SectionContentCtx::IndexSignature(items),
   ))
 }
@@ -142,7 +154,7 @@ pub(crate) fn render_call_signatures(
 
   Some(SectionCtx::new(
     ctx,
-    "Call Signatures",
+    &ctx.ctx.messages.call_signatures,
     SectionContentCtx::DocEntry(items),
   ))
 }
@@ -151,12 +163,13 @@ pub(crate) fn render_properties(
   ctx: &RenderContext,
   interface_name: &str,
   properties: &[crate::ts_type::PropertyDef],
+  accessors: &[AccessorDef],
 ) -> Option<SectionCtx> {
-  if properties.is_empty() {
+  if properties.is_empty() && accessors.is_empty() {
     return None;
   }
 
-  let items = properties
+  let mut items = properties
     .iter()
     .map(|property| {
       let id = IdBuilder::new(ctx.ctx)
@@ -214,24 +227,90 @@ pub(crate) fn render_properties(
     })
     .collect::<Vec<DocEntryCtx>>();
 
+  items.extend(accessors.iter().map(|accessor| {
+    let id = IdBuilder::new(ctx.ctx)
+      .kind(IdKind::Accessor)
+      .name(&accessor.name)
+      .build();
+
+    DocEntryCtx::new(
+      ctx,
+      id,
+      Some(if accessor.computed {
+        format!("[{}]", html_escape::encode_text(&accessor.name))
+      } else {
+        html_escape::encode_text(&accessor.name).into_owned()
+      }),
+      ctx.lookup_symbol_href(&qualify_drilldown_name(
+        interface_name,
+        &accessor.name,
+        true,
+      )),
+      &render_accessor_type(ctx, accessor),
+      accessor_tags(accessor),
+      accessor.js_doc.doc.as_deref(),
+      &accessor.location,
+    )
+  }));
+
   Some(SectionCtx::new(
     ctx,
-    "Properties",
+    &ctx.ctx.messages.properties,
     SectionContentCtx::DocEntry(items),
   ))
 }
 
+/// Renders the type of the accessor, followed by the type accepted by the
+/// setter when it differs from the one returned by the getter.
+pub(crate) fn render_accessor_type(
+  ctx: &RenderContext,
+  accessor: &AccessorDef,
+) -> String {
+  let ts_type = accessor
+    .ts_type()
+    .map(|ts_type| render_type_def_colon(ctx, ts_type))
+    .unwrap_or_default();
+
+  match &accessor.setter_type {
+    Some(setter_type) if accessor.has_divergent_types() => format!(
+      r#"{ts_type}<span><span class="font-normal">, set: </span>{}</span>"#,
+      render_type_def(ctx, setter_type)
+    ),
+    _ => ts_type,
+  }
+}
+
+pub(crate) fn accessor_tags(accessor: &AccessorDef) -> IndexSet<Tag> {
+  let mut tags = Tag::from_js_doc(&accessor.js_doc);
+  if let Some(tag) = Tag::from_accessibility(accessor.accessibility) {
+    tags.insert(tag);
+  }
+  if accessor.is_abstract {
+    tags.insert(Tag::Abstract);
+  }
+  if accessor.is_readonly() {
+    tags.insert(Tag::Readonly);
+  } else if accessor.is_writeonly() {
+    tags.insert(Tag::Writeonly);
+  }
+  tags
+}
+
 pub(crate) fn render_methods(
   ctx: &RenderContext,
   interface_name: &str,
   methods: &[crate::ts_type::MethodDef],
 ) -> Option<SectionCtx> {
-  if methods.is_empty() {
+  if methods
+    .iter()
+    .all(|method| crate::accessor::is_accessor(method.kind))
+  {
     return None;
   }
 
   let items = methods
     .iter()
+    .filter(|method| !crate::accessor::is_accessor(method.kind))
     .enumerate()
     .map(|(i, method)| {
       let id = IdBuilder::new(ctx.ctx)
@@ -282,7 +361,7 @@ pub(crate) fn render_methods(
 
   Some(SectionCtx::new(
     ctx,
-    "Methods",
+    &ctx.ctx.messages.methods,
     SectionContentCtx::DocEntry(items),
   ))
 }
@@ -115,6 +115,10 @@ impl SymbolGroupCtx {
           tags.insert(Tag::Private);
         }
 
+        if doc_nodes[0].location.filename.ends_with(".wasm") {
+          tags.insert(Tag::WebAssembly);
+        }
+
         let deprecated = if all_deprecated
           && !(matches!(doc_nodes[0].def, DocNodeDef::Function { .. })
             && doc_nodes.len() == 1)
@@ -151,8 +155,7 @@ impl SymbolGroupCtx {
           content: SymbolInnerCtx::new(ctx, doc_nodes, name),
           source_href: ctx
             .ctx
-            .href_resolver
-            .resolve_source(&doc_nodes[0].location),
+            .resolve_source(ctx.get_current_resolve(), &doc_nodes[0].location),
           deprecated,
           usage,
         }
@@ -376,7 +379,7 @@ impl SymbolInnerCtx {
       if !references.is_empty() {
         sections.push(SectionCtx::new(
           ctx,
-          "See",
+          &ctx.ctx.messages.see,
           SectionContentCtx::See(references),
         ));
       }
@@ -61,6 +61,37 @@ fn get_namespace_section_render_ctx(
   section
 }
 
+/// Renders a section listing the given symbols, without their members.
+pub(crate) fn render_symbol_list(
+  ctx: &RenderContext,
+  title: &str,
+  doc_nodes: &[DocNodeWithContext],
+) -> Option<SectionCtx> {
+  let nodes = doc_nodes
+    .iter()
+    .filter(|node| !node.is_internal(ctx.ctx))
+    .map(|node| {
+      let mut node_ctx = NamespaceNodeCtx::new(
+        ctx,
+        node.get_qualified_name(),
+        vec![node.clone()],
+      );
+      node_ctx.subitems.clear();
+      node_ctx
+    })
+    .collect::<Vec<_>>();
+
+  if nodes.is_empty() {
+    return None;
+  }
+
+  Some(SectionCtx::new(
+    ctx,
+    title,
+    SectionContentCtx::NamespaceSection(nodes),
+  ))
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct NamespaceNodeSubItemCtx {
   title: String,
@@ -166,9 +197,23 @@ impl NamespaceNodeCtx {
                 _ => unreachable!(),
               };
 
+              // in the single page output the symbol is a fragment itself, so
+              // the member links to its own drilldown section instead
+              let href = if href.starts_with('#') {
+                ctx.ctx.resolve_path(
+                  ctx.get_current_resolve(),
+                  UrlResolveKind::Symbol {
+                    file: &symbol.origin,
+                    symbol: &symbol.get_qualified_name(),
+                  },
+                )
+              } else {
+                format!("{href}#{}", target_id.as_str())
+              };
+
               NamespaceNodeSubItemCtx {
                 title: symbol.drilldown_name.as_ref().unwrap().to_string(),
-                href: format!("{href}#{}", target_id.as_str()),
+                href,
               }
             }),
         );
@@ -4,6 +4,7 @@ use crate::html::symbols::interface::render_call_signatures;
 use crate::html::symbols::interface::render_index_signatures;
 use crate::html::symbols::interface::render_methods;
 use crate::html::symbols::interface::render_properties;
+use crate::html::types::render_expanded_type_section;
 use crate::html::types::render_type_def;
 use crate::html::util::*;
 use std::collections::HashSet;
@@ -51,9 +52,12 @@ pub(crate) fn render_type_alias(
       sections.push(call_signatures);
     }
 
-    if let Some(properties) =
-      render_properties(ctx, name, &ts_type_literal.properties)
-    {
+    if let Some(properties) = render_properties(
+      ctx,
+      name,
+      &ts_type_literal.properties,
+      &ts_type_literal.accessors(),
+    ) {
       sections.push(properties);
     }
 
@@ -63,7 +67,7 @@ pub(crate) fn render_type_alias(
   } else {
     sections.push(SectionCtx::new(
       ctx,
-      "Definition",
+      &ctx.ctx.messages.definition,
       SectionContentCtx::DocEntry(vec![DocEntryCtx::new(
         ctx,
         id,
@@ -75,6 +79,12 @@ pub(crate) fn render_type_alias(
         &doc_node.location,
       )]),
     ));
+
+    if let Some(expanded_type) =
+      render_expanded_type_section(ctx, &type_alias_def.ts_type, None)
+    {
+      sections.push(expanded_type);
+    }
   }
 
   sections
@@ -4,6 +4,7 @@ use crate::html::symbols::interface::render_call_signatures;
 use crate::html::symbols::interface::render_index_signatures;
 use crate::html::symbols::interface::render_methods;
 use crate::html::symbols::interface::render_properties;
+use crate::html::types::render_expanded_type_section;
 use crate::html::types::render_type_def;
 use crate::html::util::*;
 
@@ -38,9 +39,12 @@ pub(crate) fn render_variable(
       sections.push(call_signatures);
     }
 
-    if let Some(properties) =
-      render_properties(ctx, name, &ts_type_literal.properties)
-    {
+    if let Some(properties) = render_properties(
+      ctx,
+      name,
+      &ts_type_literal.properties,
+      &ts_type_literal.accessors(),
+    ) {
       sections.push(properties);
     }
 
@@ -50,7 +54,7 @@ pub(crate) fn render_variable(
   } else {
     sections.push(SectionCtx::new(
       ctx,
-      "Type",
+      &ctx.ctx.messages.r#type,
       SectionContentCtx::DocEntry(vec![DocEntryCtx::new(
         ctx,
         id,
@@ -62,6 +66,35 @@ pub(crate) fn render_variable(
         &doc_node.location,
       )]),
     ));
+
+    if let Some(expanded_type) =
+      render_expanded_type_section(ctx, ts_type, None)
+    {
+      sections.push(expanded_type);
+    }
+  }
+
+  if let Some(value_preview) = &variable_def.value_preview {
+    let id = IdBuilder::new(ctx.ctx)
+      .kind(IdKind::Variable)
+      .name(&doc_node.get_qualified_name())
+      .component("value")
+      .build();
+
+    sections.push(SectionCtx::new(
+      ctx,
+      &ctx.ctx.messages.value,
+      SectionContentCtx::DocEntry(vec![DocEntryCtx::new(
+        ctx,
+        id,
+        None,
+        None,
+        &html_escape::encode_text(value_preview),
+        Default::default(),
+        None,
+        &doc_node.location,
+      )]),
+    ));
   }
 
   sections
@@ -1,27 +1,42 @@
-const Fuse = window.Fuse;
-
 const searchInput = document.querySelector("#searchbar");
 const contentDiv = document.querySelector("#content");
 const searchResultsDiv = document.querySelector("#searchResults");
-const currentFile =
-  document.querySelector("meta[name='doc-current-file']").attributes
-    .getNamedItem("content").value;
-const pathToRoot = "../".repeat(
-  currentFile ? (currentFile.split("/").length + 1) : 0,
-);
 searchInput.removeAttribute("style");
 
 const SEARCH_INDEX = window.DENO_DOC_SEARCH_INDEX;
+// search.js is next to the search index, at the root of the output
+const rootUrl = new URL(".", document.currentScript.src);
 
-const fuse = new Fuse(SEARCH_INDEX.nodes, {
-  keys: [{
-    name: "name",
-    weight: 2,
-  }],
-  isCaseSensitive: false,
-  minMatchCharLength: 2,
-  threshold: 0.4,
-});
+const MAX_RESULTS = 100;
+const MAX_TEXT_RESULTS = 50;
+// the number of characters of a full-text snippet, and before its first match
+const SNIPPET_LENGTH = 160;
+const SNIPPET_CONTEXT = 40;
+// the score of a match by the field the term was found in: the whole name,
+// a part of the name, or the documentation
+const EXACT_SCORES = [100, 40, 6];
+const PREFIX_SCORES = [60, 20, 3];
+
+const chunkPromises = new Map();
+
+function loadChunk(name) {
+  let promise = chunkPromises.get(name);
+  if (!promise) {
+    promise = new Promise((resolve, reject) => {
+      const script = document.createElement("script");
+      const filename = SEARCH_INDEX.chunkFiles?.[name] ?? `${name}.js`;
+      script.src = new URL(`${SEARCH_INDEX.dir}/${filename}`, rootUrl).href;
+      script.onload = () => resolve(window.DENO_DOC_SEARCH_CHUNKS[name]);
+      script.onerror = () => {
+        chunkPromises.delete(name);
+        reject(new Error(`Failed to load search index chunk '${name}'`));
+      };
+      document.head.appendChild(script);
+    });
+    chunkPromises.set(name, promise);
+  }
+  return promise;
+}
 
 const loadedUrl = new URL(window.location.href);
 const val = loadedUrl.searchParams.get("q");
@@ -40,11 +55,11 @@ window.addEventListener("load", function () {
     }
   });
 
-  const emptyPlaceholder = "Click or press 'S' to search...";
+  const emptyPlaceholder = searchInput.dataset.placeholder;
   searchInput.placeholder = emptyPlaceholder;
 
   searchInput.addEventListener("focus", function () {
-    searchInput.placeholder = "Type your query here...";
+    searchInput.placeholder = searchInput.dataset.focusedPlaceholder;
   });
 
   searchInput.addEventListener("blur", function () {
@@ -74,15 +89,24 @@ searchInput.addEventListener("input", (e) => {
   debouncedSearch(val);
 });
 
-function doSearch(val) {
+let searchId = 0;
+
+async function doSearch(val) {
+  const id = ++searchId;
   if (!val) {
     updateCurrentLocation(val);
     showPage();
   } else {
-    const results = searchInIndex(val);
-    // console.log("results", results);
+    const [results, textResults] = await Promise.all([
+      searchInIndex(val),
+      searchInText(val),
+    ]);
+    // a newer search finished first
+    if (id !== searchId) {
+      return;
+    }
     updateCurrentLocation(val);
-    renderResults(results);
+    renderResults(results, textResults);
     showSearchResults();
   }
 }
@@ -107,9 +131,11 @@ function showSearchResults() {
   searchResultsDiv.style.display = "block";
 }
 
-function renderResults(results) {
-  if (results.length === 0) {
-    searchResultsDiv.innerHTML = `<span>No result</span>`;
+function renderResults(results, textResults) {
+  if (results.length === 0 && textResults.length === 0) {
+    const noResults = document.createElement("span");
+    noResults.textContent = searchInput.dataset.noResults;
+    searchResultsDiv.replaceChildren(noResults);
     return;
   }
 
@@ -117,11 +143,12 @@ function renderResults(results) {
 
   for (const result of results) {
     const kind = result.kind.map((kind) => {
+      kind = SEARCH_INDEX.kinds[kind];
       return `<div class="text-${kind.kind} bg-${kind.kind}/15 dark:text-${kind.kind}Dark dark:bg-${kind.kind}Dark/15" title="${kind.title}">${kind.char}</div>`;
     }).join("");
 
     html += `<li class="block">
-<a href="${pathToRoot}${result.file}/~/${result.name}.html" class="flex rounded-lg gap-4 items-center justify-between py-2 px-3 hover:bg-stone-100 dark:hover:bg-stone-800">
+<a href="${new URL(result.url, rootUrl).href}" class="flex rounded-lg gap-4 items-center justify-between py-2 px-3 hover:bg-stone-100 dark:hover:bg-stone-800">
     <div class="flex items-center gap-2.5">
       <div class="docNodeKindIcon">
         ${kind}
@@ -133,9 +160,245 @@ function renderResults(results) {
   }
 
   html += `</ul>`;
+
+  if (textResults.length > 0) {
+    html += `<h3 class="textResultsTitle">${
+      escapeHtml(SEARCH_INDEX.textTitle)
+    }</h3><ul>`;
+
+    for (const result of textResults) {
+      const title = result.section
+        ? `${escapeHtml(result.name)} › ${escapeHtml(result.section)}`
+        : escapeHtml(result.name);
+
+      html += `<li class="block">
+<a href="${
+        escapeHtml(new URL(result.url, rootUrl).href)
+      }" class="textResult block rounded-lg py-2 px-3 hover:bg-stone-100 dark:hover:bg-stone-800">
+  <div class="textResultTitle">${title}</div>
+  <div class="textResultSnippet">${result.snippet}</div>
+</a>
+</li>`;
+    }
+
+    html += `</ul>`;
+  }
+
   searchResultsDiv.innerHTML = html;
 }
 
-function searchInIndex(val) {
-  return fuse.search(val).map((result) => result.item);
+// the chars of the Chinese, Japanese and Korean scripts, matching `is_cjk` in
+// search.rs
+const CJK_RUN =
+  /([\u3040-\u30ff\u31f0-\u31ff\u3400-\u4dbf\u4e00-\u9fff\uac00-\ud7af\uf900-\ufaff\uff66-\uff9f\u{20000}-\u{2fa1f}]+)/u;
+
+function tokenize(val) {
+  return val
+    .toLowerCase()
+    .split(/[^\p{L}\p{N}]+/u)
+    .filter(Boolean)
+    .flatMap(segmentCjk);
+}
+
+/**
+ * Splits the CJK runs of a word into overlapping bigrams, as these scripts
+ * have no separators between words.
+ */
+function segmentCjk(word) {
+  return word.split(CJK_RUN).filter(Boolean).flatMap((segment) => {
+    if (!CJK_RUN.test(segment)) {
+      return [segment];
+    }
+    const chars = [...segment];
+    if (chars.length === 1) {
+      return chars;
+    }
+    return chars.slice(1).map((char, i) => chars[i] + char);
+  });
+}
+
+function shardKey(term) {
+  return String.fromCodePoint(term.codePointAt(0));
+}
+
+/** Adds the postings of a trie node and all its descendants to the scores. */
+function collectPostings(trieNode, scores, exact) {
+  const postings = trieNode.p ?? [];
+  for (let i = 0; i < postings.length; i += 2) {
+    const score = (exact ? EXACT_SCORES : PREFIX_SCORES)[postings[i + 1]];
+    scores.set(postings[i], Math.max(scores.get(postings[i]) ?? 0, score));
+  }
+  for (const child of Object.values(trieNode.c ?? {})) {
+    collectPostings(child, scores, false);
+  }
+}
+
+/** Scores the nodes with a term starting with the given token. */
+function matchToken(trie, token) {
+  const scores = new Map();
+  let trieNode = trie;
+  let rest = token;
+
+  while (rest) {
+    const edge = Object.keys(trieNode.c ?? {}).find((edge) =>
+      edge.startsWith(rest) || rest.startsWith(edge)
+    );
+    if (edge === undefined) {
+      return scores;
+    }
+    trieNode = trieNode.c[edge];
+    if (edge.length > rest.length) {
+      // the token ends in the middle of the edge, so every term below is
+      // only a prefix match
+      collectPostings(trieNode, scores, false);
+      return scores;
+    }
+    rest = rest.slice(edge.length);
+  }
+
+  collectPostings(trieNode, scores, true);
+  return scores;
+}
+
+/**
+ * Scores the items of which a term starts with each of the tokens, using the
+ * shards with the given prefix.
+ */
+async function matchTokens(tokens, shardKeys, prefix) {
+  const shards = await Promise.all(tokens.map((token) => {
+    const key = shardKey(token);
+    return shardKeys.includes(key) ? loadChunk(`${prefix}_${key}`) : null;
+  }));
+
+  // every token has to match a term of the item
+  let scores = null;
+  for (let i = 0; i < tokens.length; i++) {
+    const tokenScores = shards[i]
+      ? matchToken(shards[i], tokens[i])
+      : new Map();
+    if (scores === null) {
+      scores = tokenScores;
+    } else {
+      for (const [item, score] of scores) {
+        if (tokenScores.has(item)) {
+          scores.set(item, score + tokenScores.get(item));
+        } else {
+          scores.delete(item);
+        }
+      }
+    }
+  }
+  return scores;
+}
+
+/** Loads the items at the given indexes from the chunks with the prefix. */
+async function loadItems(indexes, chunkSize, prefix) {
+  const chunkIndexes = new Set(indexes.map((i) => Math.floor(i / chunkSize)));
+  const chunks = new Map();
+  await Promise.all([...chunkIndexes].map(async (chunk) => {
+    chunks.set(chunk, await loadChunk(`${prefix}_${chunk}`));
+  }));
+  return indexes.map((i) =>
+    chunks.get(Math.floor(i / chunkSize))[i % chunkSize]
+  );
+}
+
+async function searchInIndex(val) {
+  const tokens = tokenize(val);
+  if (tokens.length === 0) {
+    return [];
+  }
+
+  const scores = await matchTokens(tokens, SEARCH_INDEX.termShards, "terms");
+  const nodeIndexes = [...scores.keys()];
+  const nodes = await loadItems(
+    nodeIndexes,
+    SEARCH_INDEX.nodeChunkSize,
+    "nodes",
+  );
+
+  return nodes
+    .map(([name, file, url, kind, deprecated], i) => ({
+      name,
+      file,
+      url,
+      kind: [...kind],
+      deprecated,
+      score: scores.get(nodeIndexes[i]),
+    }))
+    .sort((a, b) =>
+      b.score - a.score || a.name.length - b.name.length ||
+      a.name.localeCompare(b.name)
+    )
+    .slice(0, MAX_RESULTS);
+}
+
+async function searchInText(val) {
+  const tokens = tokenize(val);
+  if (tokens.length === 0 || SEARCH_INDEX.textShards === undefined) {
+    return [];
+  }
+
+  const scores = await matchTokens(tokens, SEARCH_INDEX.textShards, "text");
+  // the entries are in the order of the documentation, which is kept for
+  // entries with the same score
+  const entryIndexes = [...scores.keys()]
+    .sort((a, b) => scores.get(b) - scores.get(a) || a - b)
+    .slice(0, MAX_TEXT_RESULTS);
+  const entries = await loadItems(
+    entryIndexes,
+    SEARCH_INDEX.entryChunkSize,
+    "entries",
+  );
+
+  return entries.map(([name, section, url, text]) => ({
+    name,
+    section,
+    url,
+    snippet: highlightSnippet(text, tokens),
+  }));
+}
+
+function escapeHtml(s) {
+  return s.replace(/&/g, "&amp;").replace(/</g, "&lt;").replace(/>/g, "&gt;")
+    .replace(/"/g, "&quot;");
+}
+
+/**
+ * Cuts the text around the first match of a token, and highlights the
+ * matches of the tokens at the start of words.
+ */
+function highlightSnippet(text, tokens) {
+  const pattern = new RegExp(
+    `(?<![\\p{L}\\p{N}])(?:${
+      tokens.map((token) => token.replace(/[.*+?^${}()|[\]\\]/g, "\\$&"))
+        .join("|")
+    })`,
+    "giu",
+  );
+
+  const firstMatch = text.search(pattern);
+  let start = Math.max(0, firstMatch - SNIPPET_CONTEXT);
+  let end = Math.min(text.length, start + SNIPPET_LENGTH);
+  // do not cut words
+  if (start > 0) {
+    start = text.indexOf(" ", start) + 1 || start;
+  }
+  if (end < text.length) {
+    end = text.lastIndexOf(" ", end) > start ? text.lastIndexOf(" ", end) : end;
+  }
+  const snippet = text.slice(start, end);
+
+  let html = start > 0 ? "…" : "";
+  let last = 0;
+  for (const match of snippet.matchAll(pattern)) {
+    html += escapeHtml(snippet.slice(last, match.index));
+    html += `<mark>${escapeHtml(match[0])}</mark>`;
+    last = match.index + match[0].length;
+  }
+  html += escapeHtml(snippet.slice(last));
+  if (end < text.length) {
+    html += "…";
+  }
+  return html;
 }
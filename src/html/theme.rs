@@ -0,0 +1,604 @@
+use handlebars::Context;
+use handlebars::Handlebars;
+use handlebars::Helper;
+use handlebars::HelperDef;
+use handlebars::HelperResult;
+use handlebars::Output;
+use handlebars::Path;
+use handlebars::PathSeg;
+use handlebars::RenderContext;
+use handlebars::RenderError;
+use handlebars::RenderErrorReason;
+use handlebars::ScopedJson;
+use handlebars::Template;
+use handlebars::template::Parameter;
+use handlebars::template::TemplateElement;
+use indexmap::IndexMap;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+pub type SharedHelper = Arc<dyn HelperDef + Send + Sync>;
+
+/// Customizations of the templates used to render the HTML output.
+#[derive(Clone, Default)]
+pub struct TemplateOverrides {
+  /// Replacements for built-in templates, keyed by the template name,
+  /// eg. `pages/top_nav`, `symbol_content` or `example`.
+  ///
+  /// A replacement is rendered with the same context as the template it
+  /// replaces, so it may reference any field of that context. Templates
+  /// rendered with the context of every page, like `pages/top_nav`, may
+  /// reference the fields of any page.
+  pub templates: IndexMap<String, String>,
+  /// Additional partials which can be used from any template with
+  /// `{{> name}}`, rendered with the context they are included from. These
+  /// may not share a name with a built-in template.
+  ///
+  /// A partial included without a context parameter may only reference the
+  /// fields of the templates including it.
+  pub partials: IndexMap<String, String>,
+  /// Additional helpers which can be used from any template. A helper with
+  /// the same name as a built-in one replaces it.
+  pub helpers: IndexMap<String, SharedHelper>,
+}
+
+#[derive(Debug)]
+pub enum TemplateOverrideError {
+  /// The name of an override does not match any built-in template.
+  UnknownTemplate(String),
+  /// A partial has the same name as a built-in template.
+  PartialConflict(String),
+  /// A template or partial failed to compile.
+  Syntax {
+    name: String,
+    source: Box<handlebars::TemplateError>,
+  },
+  /// A template or partial references a field that is missing from the
+  /// context it is rendered with.
+  MissingField {
+    template: String,
+    field: String,
+    available: Vec<String>,
+  },
+}
+
+impl Error for TemplateOverrideError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      Self::Syntax { source, .. } => Some(source.as_ref()),
+      _ => None,
+    }
+  }
+}
+
+impl fmt::Display for TemplateOverrideError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::UnknownTemplate(name) => {
+        write!(f, "Cannot override unknown template \"{name}\"")
+      }
+      Self::PartialConflict(name) => write!(
+        f,
+        "The partial \"{name}\" conflicts with the built-in template of the same name, use a template override instead"
+      ),
+      Self::Syntax { name, source } => {
+        write!(f, "Failed to compile template \"{name}\": {source}")
+      }
+      Self::MissingField {
+        template,
+        field,
+        available,
+      } => {
+        write!(
+          f,
+          "Template \"{template}\" references the field \"{field}\", which is not provided by its context"
+        )?;
+        if !available.is_empty() {
+          write!(f, ". Available fields: {}", available.join(", "))?;
+        }
+        Ok(())
+      }
+    }
+  }
+}
+
+/// The fields of the contexts every page is rendered with, for the templates
+/// included from all of them.
+const PAGE_FIELDS: &[&str] = &[
+  "kind",
+  "html_head_ctx",
+  "module_doc",
+  "overview",
+  "breadcrumbs_ctx",
+  "usage",
+  "toc_ctx",
+  "disable_search",
+  "categories_panel",
+  "version_switcher",
+  "content",
+  "symbol_group_ctx",
+  "lines",
+  "sections",
+  "title",
+  "stylesheet",
+  "script_js",
+  "darkmode_toggle_js",
+  "head_inject",
+];
+
+/// The fields of the contexts the built-in templates are rendered with.
+/// Templates rendered with a list or a scalar have none.
+const CONTEXT_FIELDS: &[(&str, &[&str])] = &[
+  (
+    "pages/index",
+    &[
+      "kind",
+      "html_head_ctx",
+      "module_doc",
+      "overview",
+      "breadcrumbs_ctx",
+      "usage",
+      "toc_ctx",
+      "disable_search",
+      "categories_panel",
+      "version_switcher",
+    ],
+  ),
+  (
+    "pages/all_symbols",
+    &[
+      "kind",
+      "html_head_ctx",
+      "content",
+      "breadcrumbs_ctx",
+      "disable_search",
+      "categories_panel",
+      "version_switcher",
+    ],
+  ),
+  (
+    "pages/symbol",
+    &[
+      "kind",
+      "html_head_ctx",
+      "symbol_group_ctx",
+      "breadcrumbs_ctx",
+      "toc_ctx",
+      "disable_search",
+      "categories_panel",
+      "version_switcher",
+    ],
+  ),
+  (
+    "pages/source",
+    &[
+      "kind",
+      "html_head_ctx",
+      "breadcrumbs_ctx",
+      "disable_search",
+      "version_switcher",
+      "lines",
+    ],
+  ),
+  (
+    "pages/hierarchy",
+    &[
+      "kind",
+      "html_head_ctx",
+      "breadcrumbs_ctx",
+      "disable_search",
+      "version_switcher",
+      "sections",
+    ],
+  ),
+  (
+    "pages/single_page",
+    &[
+      "kind",
+      "title",
+      "stylesheet",
+      "script_js",
+      "darkmode_toggle_js",
+      "head_inject",
+      "breadcrumbs_ctx",
+      "disable_search",
+      "version_switcher",
+      "sections",
+    ],
+  ),
+  ("pages/redirect", &["kind", "path"]),
+  (
+    "pages/html_head",
+    &[
+      "title",
+      "current_file",
+      "stylesheet_url",
+      "page_stylesheet_url",
+      "reset_stylesheet_url",
+      "url_search_index",
+      "script_js",
+      "search_js",
+      "darkmode_toggle_js",
+      "head_inject",
+      "canonical_url",
+      "description",
+      "site_name",
+      "disable_search",
+    ],
+  ),
+  ("pages/top_nav", PAGE_FIELDS),
+  ("pages/search_results", PAGE_FIELDS),
+  (
+    "category_panel",
+    &["categories", "all_symbols_href", "total_symbols"],
+  ),
+  ("breadcrumbs", &["parts"]),
+  (
+    "toc",
+    &[
+      "usages",
+      "top_symbols",
+      "document_navigation_str",
+      "document_navigation",
+    ],
+  ),
+  ("symbol_group", &["name", "symbols"]),
+  ("symbol_content", &["id", "docs", "sections"]),
+  ("section", &["header", "content"]),
+  ("anchor", &["id"]),
+  (
+    "doc_entry",
+    &[
+      "id",
+      "name",
+      "name_href",
+      "content",
+      "anchor",
+      "tags",
+      "js_doc",
+      "source_href",
+    ],
+  ),
+  (
+    "example",
+    &["anchor", "id", "title", "markdown_title", "markdown_body"],
+  ),
+  (
+    "index_signature",
+    &[
+      "id",
+      "anchor",
+      "readonly",
+      "params",
+      "ts_type",
+      "source_href",
+    ],
+  ),
+  ("namespace_section", &[]),
+  ("see", &[]),
+  ("doc_node_kind_icon", &[]),
+  ("hierarchy", &["width", "height", "nodes", "edges"]),
+  ("expanded_type", &["name", "members"]),
+  ("module_doc", &["deprecated", "sections"]),
+  ("usages", &["usages", "composed"]),
+  ("usages_large", &["usages", "composed"]),
+  ("version_switcher", &["current", "versions"]),
+  ("function", &["functions"]),
+  ("deprecated", &[]),
+  ("source_button", &[]),
+  ("tag", &["value", "large"]),
+  ("doc_block_subtitle_class", &["implements", "extends"]),
+  ("doc_block_subtitle_interface", &["extends"]),
+  ("icons/arrow", &[]),
+  ("icons/check", &[]),
+  ("icons/copy", &[]),
+  ("icons/link", &[]),
+  ("icons/menu", &[]),
+  ("icons/moon", &[]),
+  ("icons/source", &[]),
+  ("icons/sun", &["class"]),
+];
+
+pub(crate) fn context_fields(
+  template: &str,
+) -> Option<&'static [&'static str]> {
+  CONTEXT_FIELDS
+    .iter()
+    .find(|(name, _)| *name == template)
+    .map(|(_, fields)| *fields)
+}
+
+/// Applies the overrides to a registry containing the built-in templates,
+/// validating their names, their syntax and the fields they reference.
+///
+/// Partials are validated against the fields of the templates including
+/// them, and are only checked when rendering if these are unknown, as the
+/// registry is in strict mode.
+pub(crate) fn apply_overrides(
+  reg: &mut Handlebars<'static>,
+  overrides: &TemplateOverrides,
+) -> Result<(), TemplateOverrideError> {
+  for (name, helper) in &overrides.helpers {
+    reg.register_helper(name, Box::new(ForwardHelper(helper.clone())));
+  }
+
+  let is_helper = |name: &str| overrides.helpers.contains_key(name);
+
+  // the fields of the context each partial is included with, or `None` if
+  // that context is unknown
+  let mut partial_contexts = IndexMap::<&str, Option<BTreeSet<String>>>::new();
+
+  for (name, source) in &overrides.templates {
+    if !reg.has_template(name) {
+      return Err(TemplateOverrideError::UnknownTemplate(name.clone()));
+    }
+    register(reg, name, source)?;
+
+    let usage = check_fields(name, reg.get_template(name).unwrap(), is_helper)?;
+    let available = context_fields(name)
+      .map(|fields| fields.iter().map(|field| field.to_string()).collect());
+    for (partial, context) in usage.partials {
+      if let Some((partial, _)) = overrides.partials.get_key_value(&partial) {
+        let context = included_context(available.as_ref(), context);
+        merge_context(&mut partial_contexts, partial, context);
+      }
+    }
+  }
+
+  for (name, source) in &overrides.partials {
+    if reg.has_template(name) {
+      return Err(TemplateOverrideError::PartialConflict(name.clone()));
+    }
+    register(reg, name, source)?;
+  }
+
+  // propagate the contexts to the partials included from other partials,
+  // until none of them changes
+  let mut changed = true;
+  while changed {
+    changed = false;
+    for (name, _) in &overrides.partials {
+      let Some(available) = partial_contexts.get(name.as_str()).cloned() else {
+        continue;
+      };
+      let mut usage = ContextUsage::default();
+      collect_context_usage(reg.get_template(name).unwrap(), &mut usage);
+      for (partial, context) in usage.partials {
+        if let Some((partial, _)) = overrides.partials.get_key_value(&partial) {
+          let context = included_context(available.as_ref(), context);
+          changed |= merge_context(&mut partial_contexts, partial, context);
+        }
+      }
+    }
+  }
+
+  for (name, context) in partial_contexts {
+    let Some(available) = context else {
+      continue;
+    };
+    let mut usage = ContextUsage::default();
+    collect_context_usage(reg.get_template(name).unwrap(), &mut usage);
+    missing_field(name, usage.fields, available, is_helper)?;
+  }
+
+  Ok(())
+}
+
+/// The fields of the context a partial is included with, from the fields
+/// available to the template including it and the ones its hash adds.
+fn included_context(
+  available: Option<&BTreeSet<String>>,
+  hash: Option<BTreeSet<String>>,
+) -> Option<BTreeSet<String>> {
+  let mut context = available?.clone();
+  context.extend(hash?);
+  Some(context)
+}
+
+/// Merges the context a partial is included with into the contexts it was
+/// already included with, returning whether these changed.
+fn merge_context<'a>(
+  contexts: &mut IndexMap<&'a str, Option<BTreeSet<String>>>,
+  partial: &'a str,
+  context: Option<BTreeSet<String>>,
+) -> bool {
+  match (contexts.get_mut(partial), context) {
+    (None, context) => {
+      contexts.insert(partial, context);
+      true
+    }
+    (Some(None), _) => false,
+    (Some(existing), None) => {
+      *existing = None;
+      true
+    }
+    (Some(Some(existing)), Some(context)) => {
+      let len = existing.len();
+      existing.extend(context);
+      existing.len() != len
+    }
+  }
+}
+
+fn register(
+  reg: &mut Handlebars<'static>,
+  name: &str,
+  source: &str,
+) -> Result<(), TemplateOverrideError> {
+  reg.register_template_string(name, source).map_err(|err| {
+    TemplateOverrideError::Syntax {
+      name: name.to_string(),
+      source: Box::new(err),
+    }
+  })
+}
+
+/// Checks that a template only references the fields of the context it is
+/// rendered with, if that context is known, returning what it reads from it.
+pub(crate) fn check_fields(
+  name: &str,
+  template: &Template,
+  is_helper: impl Fn(&str) -> bool,
+) -> Result<ContextUsage, TemplateOverrideError> {
+  let mut usage = ContextUsage::default();
+  collect_context_usage(template, &mut usage);
+
+  if let Some(available) = context_fields(name) {
+    let available = available.iter().map(|field| field.to_string()).collect();
+    missing_field(name, usage.fields.clone(), available, is_helper)?;
+  }
+
+  Ok(usage)
+}
+
+fn missing_field(
+  name: &str,
+  used: BTreeSet<String>,
+  available: BTreeSet<String>,
+  is_helper: impl Fn(&str) -> bool,
+) -> Result<(), TemplateOverrideError> {
+  match used
+    .into_iter()
+    .find(|field| !available.contains(field) && !is_helper(field))
+  {
+    Some(field) => Err(TemplateOverrideError::MissingField {
+      template: name.to_string(),
+      field,
+      available: available.into_iter().collect(),
+    }),
+    None => Ok(()),
+  }
+}
+
+/// What a template reads from the context it is rendered with.
+#[derive(Default)]
+pub(crate) struct ContextUsage {
+  fields: BTreeSet<String>,
+  /// The partials included with the same context, with the fields added to
+  /// it by their hash, or `None` when they are included with another one.
+  partials: Vec<(String, Option<BTreeSet<String>>)>,
+}
+
+/// Collects the fields a template reads from the context it is rendered
+/// with, and the partials it includes. Blocks that change the context
+/// (`each`, `with` and custom block helpers) only contribute their
+/// parameters.
+fn collect_context_usage(template: &Template, usage: &mut ContextUsage) {
+  for element in &template.elements {
+    match element {
+      TemplateElement::Expression(helper)
+      | TemplateElement::HtmlExpression(helper) => {
+        collect_param_fields(&helper.name, &mut usage.fields);
+        helper
+          .params
+          .iter()
+          .chain(helper.hash.values())
+          .for_each(|param| collect_param_fields(param, &mut usage.fields));
+      }
+      TemplateElement::HelperBlock(helper) => {
+        helper
+          .params
+          .iter()
+          .chain(helper.hash.values())
+          .for_each(|param| collect_param_fields(param, &mut usage.fields));
+
+        let keeps_context =
+          matches!(helper.name.as_name(), Some("if") | Some("unless"));
+        if keeps_context && let Some(template) = &helper.template {
+          collect_context_usage(template, usage);
+        }
+        if let Some(inverse) = &helper.inverse {
+          collect_context_usage(inverse, usage);
+        }
+      }
+      TemplateElement::PartialExpression(partial)
+      | TemplateElement::PartialBlock(partial) => {
+        partial
+          .params
+          .iter()
+          .chain(partial.hash.values())
+          .for_each(|param| collect_param_fields(param, &mut usage.fields));
+
+        if let Some(name) = partial.name.as_name() {
+          let context = partial
+            .params
+            .is_empty()
+            .then(|| partial.hash.keys().cloned().collect());
+          usage.partials.push((name.to_string(), context));
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+fn collect_param_fields(param: &Parameter, fields: &mut BTreeSet<String>) {
+  match param {
+    Parameter::Path(Path::Relative((segments, _))) => {
+      if let Some(PathSeg::Named(name)) = segments.first() {
+        fields.insert(name.clone());
+      }
+    }
+    Parameter::Subexpression(subexpression) => {
+      if let Some(params) = subexpression.params() {
+        params
+          .iter()
+          .for_each(|param| collect_param_fields(param, fields));
+      }
+      if let Some(hash) = subexpression.hash() {
+        hash
+          .values()
+          .for_each(|param| collect_param_fields(param, fields));
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Converts an error of the registry, reporting a missing field as a
+/// [`TemplateOverrideError::MissingField`] of the template it occurred in.
+pub(crate) fn render_error(err: RenderError) -> anyhow::Error {
+  if let RenderErrorReason::MissingVariable(Some(field)) = err.reason()
+    && let Some(template) = &err.template_name
+  {
+    return TemplateOverrideError::MissingField {
+      template: template.clone(),
+      field: field.clone(),
+      available: context_fields(template)
+        .unwrap_or_default()
+        .iter()
+        .map(|field| field.to_string())
+        .collect(),
+    }
+    .into();
+  }
+  err.into()
+}
+
+/// Registers a shared helper, as the registry requires owned helpers.
+struct ForwardHelper(SharedHelper);
+
+impl HelperDef for ForwardHelper {
+  fn call_inner<'reg: 'rc, 'rc>(
+    &self,
+    h: &Helper<'rc>,
+    r: &'reg Handlebars<'reg>,
+    ctx: &'rc Context,
+    rc: &mut RenderContext<'reg, 'rc>,
+  ) -> Result<ScopedJson<'rc>, RenderError> {
+    self.0.call_inner(h, r, ctx, rc)
+  }
+
+  fn call<'reg: 'rc, 'rc>(
+    &self,
+    h: &Helper<'rc>,
+    r: &'reg Handlebars<'reg>,
+    ctx: &'rc Context,
+    rc: &mut RenderContext<'reg, 'rc>,
+    out: &mut dyn Output,
+  ) -> HelperResult {
+    self.0.call(h, r, ctx, rc, out)
+  }
+}
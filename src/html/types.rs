@@ -2,13 +2,19 @@ use super::parameters::render_params;
 use super::render_context::RenderContext;
 use super::util::*;
 
+use super::DocNodeWithContext;
+use super::GenerateCtx;
+use super::ShortPath;
+use crate::DocNode;
 use crate::js_doc::JsDoc;
 use crate::js_doc::JsDocTag;
+use crate::node::DocNodeDef;
 use crate::ts_type::LiteralDefKind;
 use crate::ts_type::TsTypeDefKind;
 use crate::ts_type_param::TsTypeParamDef;
 use deno_ast::swc::ast::MethodKind;
 use deno_ast::swc::ast::TruePlusMinus;
+use serde::Serialize;
 
 pub(crate) fn render_type_def_colon(
   ctx: &RenderContext,
@@ -248,124 +254,16 @@ pub(crate) fn render_type_def(
       )
     }
     TsTypeDefKind::TypeLiteral => {
-      let type_literal = def.type_literal.as_ref().unwrap();
+      let members =
+        render_type_literal_members(ctx, def.type_literal.as_ref().unwrap());
 
-      let mut index_signatures =
-        Vec::with_capacity(type_literal.index_signatures.len());
-
-      for index_signature in type_literal.index_signatures.iter() {
-        let readonly = if index_signature.readonly {
-          "<span>readonly </span>"
-        } else {
-          Default::default()
-        };
-
-        let ts_type = index_signature
-          .ts_type
-          .as_ref()
-          .map(|ts_type| render_type_def_colon(ctx, ts_type))
-          .unwrap_or_default();
-
-        let item = format!(
-          "{readonly}[{}]{ts_type}; ",
-          render_params(ctx, &index_signature.params)
-        );
-        index_signatures.push(item);
-      }
-
-      let index_signatures = index_signatures.join("");
-
-      let mut call_signatures =
-        Vec::with_capacity(type_literal.call_signatures.len());
-
-      for call_signature in type_literal.call_signatures.iter() {
-        let ts_type = call_signature
-          .ts_type
-          .as_ref()
-          .map(|ts_type| render_type_def_colon(ctx, ts_type))
-          .unwrap_or_default();
-
-        let item = format!(
-          "{}({}){ts_type}; ",
-          type_params_summary(ctx, &call_signature.type_params),
-          render_params(ctx, &call_signature.params)
-        );
-        call_signatures.push(item);
-      }
-      let call_signatures = call_signatures.join("");
-
-      let mut properties = Vec::with_capacity(type_literal.properties.len());
-
-      for property in type_literal.properties.iter() {
-        let readonly = if property.readonly {
-          "<span>readonly </span>"
-        } else {
-          Default::default()
-        };
-
-        let name = if property.computed {
-          format!("[{}]", html_escape::encode_text(&property.name))
-        } else {
-          html_escape::encode_text(&property.name).to_string()
-        };
-
-        let optional = if property.optional {
-          "?"
-        } else {
-          Default::default()
-        };
-
-        let ts_type = property
-          .ts_type
-          .as_ref()
-          .map(|ts_type| render_type_def_colon(ctx, ts_type))
-          .unwrap_or_default();
-
-        let item = format!("{readonly}{name}{optional}{ts_type}; ");
-        properties.push(item);
-      }
-      let properties = properties.join("");
-
-      let mut methods = Vec::with_capacity(type_literal.methods.len());
-
-      for method in type_literal.methods.iter() {
-        let kind = match method.kind {
-          MethodKind::Method => "",
-          MethodKind::Getter => "<span>get </span>",
-          MethodKind::Setter => "<span>set </span>",
-        };
-
-        let name = if method.name == "new" {
-          "<span>new </span>".to_string()
-        } else if method.computed {
-          format!("[{}]", method.name)
-        } else {
-          method.name.clone()
-        };
-
-        let optional = if method.optional {
-          "?"
-        } else {
-          Default::default()
-        };
-
-        let return_type = method
-          .return_type
-          .as_ref()
-          .map(|ts_type| render_type_def_colon(ctx, ts_type))
-          .unwrap_or_default();
-
-        let item = format!(
-          "{kind}{name}{optional}{}({}){return_type}; ",
-          type_params_summary(ctx, &method.type_params),
-          render_params(ctx, &method.params)
-        );
-
-        methods.push(item);
-      }
-      let methods = methods.join("");
-
-      format!("{{ {index_signatures}{call_signatures}{properties}{methods} }}")
+      format!(
+        "{{ {} }}",
+        members
+          .iter()
+          .map(|member| format!("{member}; "))
+          .collect::<String>()
+      )
     }
     TsTypeDefKind::TypePredicate => {
       let type_predicate = def.type_predicate.as_ref().unwrap();
@@ -416,6 +314,217 @@ pub(crate) fn render_type_def(
   }
 }
 
+/// The members of a type expanded into its structure.
+#[derive(Debug, Serialize, Clone)]
+pub struct ExpandedTypeCtx {
+  /// The name of the parameter the type belongs to, as HTML.
+  pub name: Option<String>,
+  pub members: Vec<String>,
+}
+
+impl ExpandedTypeCtx {
+  pub const TEMPLATE: &'static str = "expanded_type";
+}
+
+/// Renders a disclosure with the members of the type, if the expansion of
+/// types is enabled and the type references type aliases, interfaces or
+/// utility types which can be expanded. The name labels the disclosure when
+/// the type belongs to one of several parameters.
+pub(crate) fn render_expanded_type_section(
+  ctx: &RenderContext,
+  ts_type: &crate::ts_type::TsTypeDef,
+  name: Option<String>,
+) -> Option<SectionCtx> {
+  if !ctx.ctx.expand_types {
+    return None;
+  }
+
+  let current_resolve = ctx.get_current_resolve();
+  let current_file = current_resolve.get_file();
+  let resolve = |name: &str, scope: Option<&DocNode>| {
+    let file = match scope {
+      None if ctx.contains_type_param(name) => return None,
+      None => current_file?,
+      // the declarations of other modules reference the names in their scope
+      Some(scope) => ctx.ctx.node_module(scope)?,
+    };
+
+    resolve_type_declaration(ctx.ctx, file, name)
+  };
+
+  let expanded = ts_type.expand(resolve)?;
+  let members =
+    render_type_literal_members(ctx, expanded.type_literal.as_ref()?);
+  if members.is_empty() {
+    return None;
+  }
+
+  Some(SectionCtx::new(
+    ctx,
+    "",
+    SectionContentCtx::ExpandedType(ExpandedTypeCtx { name, members }),
+  ))
+}
+
+/// Looks up the type alias or interface a name refers to in a module, either
+/// declared in the module or imported from another documented module.
+fn resolve_type_declaration<'a>(
+  ctx: &'a GenerateCtx,
+  file: &ShortPath,
+  name: &str,
+) -> Option<&'a DocNode> {
+  fn is_declaration(node: &DocNodeWithContext, name: &str) -> bool {
+    node.get_name() == name
+      && matches!(
+        node.def,
+        DocNodeDef::TypeAlias { .. } | DocNodeDef::Interface { .. }
+      )
+  }
+
+  let nodes = ctx.doc_nodes.get(file)?;
+  if let Some(node) = nodes.iter().find(|node| is_declaration(node, name)) {
+    return Some(&**node);
+  }
+
+  let (src, imported) = nodes.iter().find_map(|node| match &node.def {
+    DocNodeDef::Import { import_def } if node.get_name() == name => {
+      Some((&import_def.src, import_def.imported.as_deref()?))
+    }
+    _ => None,
+  })?;
+  let (_, nodes) = ctx
+    .doc_nodes
+    .iter()
+    .find(|(short_path, _)| short_path.specifier.as_str() == src)?;
+  nodes
+    .iter()
+    .find(|node| is_declaration(node, imported))
+    .map(|node| &**node)
+}
+
+/// Renders the members of a type literal, without their separators.
+fn render_type_literal_members(
+  ctx: &RenderContext,
+  type_literal: &crate::ts_type::TsTypeLiteralDef,
+) -> Vec<String> {
+  let mut index_signatures =
+    Vec::with_capacity(type_literal.index_signatures.len());
+
+  for index_signature in type_literal.index_signatures.iter() {
+    let readonly = if index_signature.readonly {
+      "<span>readonly </span>"
+    } else {
+      Default::default()
+    };
+
+    let ts_type = index_signature
+      .ts_type
+      .as_ref()
+      .map(|ts_type| render_type_def_colon(ctx, ts_type))
+      .unwrap_or_default();
+
+    let item = format!(
+      "{readonly}[{}]{ts_type}",
+      render_params(ctx, &index_signature.params)
+    );
+    index_signatures.push(item);
+  }
+
+  let mut call_signatures =
+    Vec::with_capacity(type_literal.call_signatures.len());
+
+  for call_signature in type_literal.call_signatures.iter() {
+    let ts_type = call_signature
+      .ts_type
+      .as_ref()
+      .map(|ts_type| render_type_def_colon(ctx, ts_type))
+      .unwrap_or_default();
+
+    let item = format!(
+      "{}({}){ts_type}",
+      type_params_summary(ctx, &call_signature.type_params),
+      render_params(ctx, &call_signature.params)
+    );
+    call_signatures.push(item);
+  }
+
+  let mut properties = Vec::with_capacity(type_literal.properties.len());
+
+  for property in type_literal.properties.iter() {
+    let readonly = if property.readonly {
+      "<span>readonly </span>"
+    } else {
+      Default::default()
+    };
+
+    let name = if property.computed {
+      format!("[{}]", html_escape::encode_text(&property.name))
+    } else {
+      html_escape::encode_text(&property.name).to_string()
+    };
+
+    let optional = if property.optional {
+      "?"
+    } else {
+      Default::default()
+    };
+
+    let ts_type = property
+      .ts_type
+      .as_ref()
+      .map(|ts_type| render_type_def_colon(ctx, ts_type))
+      .unwrap_or_default();
+
+    let item = format!("{readonly}{name}{optional}{ts_type}");
+    properties.push(item);
+  }
+
+  let mut methods = Vec::with_capacity(type_literal.methods.len());
+
+  for method in type_literal.methods.iter() {
+    let kind = match method.kind {
+      MethodKind::Method => "",
+      MethodKind::Getter => "<span>get </span>",
+      MethodKind::Setter => "<span>set </span>",
+    };
+
+    let name = if method.name == "new" {
+      "<span>new </span>".to_string()
+    } else if method.computed {
+      format!("[{}]", method.name)
+    } else {
+      method.name.clone()
+    };
+
+    let optional = if method.optional {
+      "?"
+    } else {
+      Default::default()
+    };
+
+    let return_type = method
+      .return_type
+      .as_ref()
+      .map(|ts_type| render_type_def_colon(ctx, ts_type))
+      .unwrap_or_default();
+
+    let item = format!(
+      "{kind}{name}{optional}{}({}){return_type}",
+      type_params_summary(ctx, &method.type_params),
+      render_params(ctx, &method.params)
+    );
+
+    methods.push(item);
+  }
+
+  index_signatures
+    .into_iter()
+    .chain(call_signatures)
+    .chain(properties)
+    .chain(methods)
+    .collect()
+}
+
 fn type_def_join(
   ctx: &RenderContext,
   union: &[crate::ts_type::TsTypeDef],
@@ -624,7 +733,7 @@ pub(crate) fn render_type_params(
 
   Some(SectionCtx::new(
     ctx,
-    "Type Parameters",
+    &ctx.ctx.messages.type_parameters,
     SectionContentCtx::DocEntry(items),
   ))
 }
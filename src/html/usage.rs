@@ -335,7 +335,7 @@ pub struct UsageComposerEntry {
   pub icon: Option<Cow<'static, str>>,
 }
 
-pub trait UsageComposer {
+pub trait UsageComposer: super::MaybeSendSync {
   fn is_single_mode(&self) -> bool;
 
   fn compose(
@@ -3,6 +3,7 @@ use crate::html::DocNodeWithContext;
 use crate::html::FileMode;
 use crate::html::GenerateCtx;
 use crate::html::RenderContext;
+use crate::html::Shared;
 use crate::html::ShortPath;
 use crate::html::jsdoc::MarkdownToHTMLOptions;
 use crate::html::jsdoc::markdown_to_html;
@@ -19,7 +20,6 @@ use serde::Serialize;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use std::rc::Rc;
 
 lazy_static! {
   static ref TARGET_RE: Regex = Regex::new(r"\s*\* ?|\.").unwrap();
@@ -170,7 +170,7 @@ fn sanitize_id_part(part: &str) -> String {
 /// ["Deno", "errors", "HttpError"]
 #[derive(Clone, Debug)]
 pub(crate) struct NamespacedSymbols(
-  Rc<HashMap<Vec<String>, Option<Rc<ShortPath>>>>,
+  Shared<HashMap<Vec<String>, Option<Shared<ShortPath>>>>,
 );
 
 impl NamespacedSymbols {
@@ -182,10 +182,13 @@ impl NamespacedSymbols {
       ctx,
       Box::new(doc_nodes.iter().map(Cow::Borrowed)),
     );
-    Self(Rc::new(symbols))
+    Self(Shared::new(symbols))
   }
 
-  pub(crate) fn get(&self, path: &[String]) -> Option<&Option<Rc<ShortPath>>> {
+  pub(crate) fn get(
+    &self,
+    path: &[String],
+  ) -> Option<&Option<Shared<ShortPath>>> {
     self.0.get(path)
   }
 }
@@ -193,9 +196,9 @@ impl NamespacedSymbols {
 pub fn compute_namespaced_symbols<'a>(
   ctx: &'a GenerateCtx,
   doc_nodes: Box<dyn Iterator<Item = Cow<'a, DocNodeWithContext>> + 'a>,
-) -> HashMap<Vec<String>, Option<Rc<ShortPath>>> {
+) -> HashMap<Vec<String>, Option<Shared<ShortPath>>> {
   let mut namespaced_symbols =
-    HashMap::<Vec<String>, Option<Rc<ShortPath>>>::new();
+    HashMap::<Vec<String>, Option<Shared<ShortPath>>>::new();
 
   for doc_node in doc_nodes {
     if matches!(
@@ -207,7 +210,7 @@ pub fn compute_namespaced_symbols<'a>(
 
     // TODO: handle export aliasing
 
-    let name_path: Rc<[String]> = doc_node.sub_qualifier().into();
+    let name_path: Shared<[String]> = doc_node.sub_qualifier().into();
 
     match &doc_node.def {
       DocNodeDef::Class { class_def } => {
@@ -362,11 +365,11 @@ pub fn compute_namespaced_symbols<'a>(
 }
 
 #[derive(Clone, Default)]
-pub struct NamespacedGlobalSymbols(Rc<HashMap<Vec<String>, String>>);
+pub struct NamespacedGlobalSymbols(Shared<HashMap<Vec<String>, String>>);
 
 impl NamespacedGlobalSymbols {
   pub fn new(symbols: HashMap<Vec<String>, String>) -> Self {
-    Self(Rc::new(symbols))
+    Self(Shared::new(symbols))
   }
 
   pub fn get(&self, path: &[String]) -> Option<&String> {
@@ -407,6 +410,17 @@ impl UrlResolveKind<'_> {
 pub fn href_path_resolve(
   current: UrlResolveKind,
   target: UrlResolveKind,
+) -> String {
+  href_path_resolve_localized(current, target, super::i18n::DEFAULT_LANG)
+}
+
+/// Like [`href_path_resolve`], but names the category pages by the slug of
+/// the category in `lang`, the language of the [`Messages`](super::Messages)
+/// the documentation is generated with.
+pub fn href_path_resolve_localized(
+  current: UrlResolveKind,
+  target: UrlResolveKind,
+  lang: &str,
 ) -> String {
   let backs = match current {
     UrlResolveKind::File { file } => "../".repeat(if file.is_main {
@@ -439,13 +453,13 @@ pub fn href_path_resolve(
       format!("{backs}./{}/index.html", target_file.path)
     }
     UrlResolveKind::Category { category } => {
-      format!("{backs}./{}.html", slugify(category))
+      format!("{backs}./{}.html", slugify_localized(category, lang))
     }
   }
 }
 
 /// A trait used to define various functions used to resolve urls.
-pub trait HrefResolver {
+pub trait HrefResolver: super::MaybeSendSync {
   fn resolve_path(
     &self,
     current: UrlResolveKind,
@@ -569,6 +583,8 @@ pub enum SectionContentCtx {
   IndexSignature(Vec<super::symbols::class::IndexSignatureCtx>),
   NamespaceSection(Vec<super::namespace::NamespaceNodeCtx>),
   See(Vec<String>),
+  Hierarchy(super::HierarchyCtx),
+  ExpandedType(super::types::ExpandedTypeCtx),
   Empty,
 }
 
@@ -693,6 +709,8 @@ impl SectionCtx {
         }
       }
       SectionContentCtx::See(_) => {}
+      SectionContentCtx::Hierarchy(_) => {}
+      SectionContentCtx::ExpandedType(_) => {}
       SectionContentCtx::Empty => {}
     }
 
@@ -713,6 +731,9 @@ pub enum Tag {
   Optional,
   Unstable,
   Permissions(Box<[Box<str>]>),
+  /// The symbol is implemented by a WebAssembly module.
+  #[serde(rename = "webassembly")]
+  WebAssembly,
   Other(Box<str>),
 }
 
@@ -769,7 +790,8 @@ impl DocEntryCtx {
   ) -> Self {
     let maybe_jsdoc =
       jsdoc.map(|doc| crate::html::jsdoc::render_markdown(ctx, doc, true));
-    let source_href = ctx.ctx.href_resolver.resolve_source(location);
+    let source_href =
+      ctx.ctx.resolve_source(ctx.get_current_resolve(), location);
 
     DocEntryCtx {
       id: id.clone(),
@@ -917,11 +939,52 @@ impl ToCCtx {
   }
 }
 
-pub fn slugify(name: &str) -> String {
-  static REJECTED_CHARS: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"[^\p{L}\p{M}\p{N}\p{Pc} -]").unwrap());
+static REJECTED_SLUG_CHARS: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"[^\p{L}\p{M}\p{N}\p{Pc} -]").unwrap());
 
-  REJECTED_CHARS
+pub fn slugify(name: &str) -> String {
+  REJECTED_SLUG_CHARS
     .replace_all(&name.to_lowercase(), "")
     .replace(' ', "-")
 }
+
+/// Converts a name into a slug for the language `lang`, which is the same
+/// as [`slugify`] for the default language.
+///
+/// The language tag selects the lowercasing and transliteration rules that
+/// differ per locale, eg. the dotless `ı` in Turkish or `ß` in German.
+/// Full-width forms are folded into their ASCII counterparts, and any
+/// whitespace, including the ideographic space, becomes a hyphen.
+pub fn slugify_localized(name: &str, lang: &str) -> String {
+  if lang == super::i18n::DEFAULT_LANG {
+    return slugify(name);
+  }
+
+  let language = lang.split(['-', '_']).next().unwrap_or_default();
+  let turkic =
+    language.eq_ignore_ascii_case("tr") || language.eq_ignore_ascii_case("az");
+  let german = language.eq_ignore_ascii_case("de");
+
+  let mut lowercased = String::with_capacity(name.len());
+  for char in name.chars() {
+    let char = match char {
+      '\u{FF01}'..='\u{FF5E}' => char::from_u32(char as u32 - 0xFEE0).unwrap(),
+      _ => char,
+    };
+
+    match char {
+      char if char.is_whitespace() => lowercased.push(' '),
+      'I' if turkic => lowercased.push('ı'),
+      'İ' if turkic => lowercased.push('i'),
+      'ä' | 'Ä' if german => lowercased.push_str("ae"),
+      'ö' | 'Ö' if german => lowercased.push_str("oe"),
+      'ü' | 'Ü' if german => lowercased.push_str("ue"),
+      'ß' | 'ẞ' if german => lowercased.push_str("ss"),
+      char => lowercased.extend(char.to_lowercase()),
+    }
+  }
+
+  REJECTED_SLUG_CHARS
+    .replace_all(&lowercased, "")
+    .replace(' ', "-")
+}
@@ -0,0 +1,183 @@
+use super::GenerateCtx;
+use super::UrlResolveKind;
+use crate::html::Shared;
+use crate::node::DocNodeDef;
+use indexmap::IndexMap;
+use indexmap::IndexSet;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Options for documentation that is published for multiple versions of a
+/// package side by side.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionOptions {
+  /// The name of the version being generated, eg. "1.2.0".
+  pub current: String,
+  /// The other versions to link to from the version switcher, in the order
+  /// they are listed.
+  pub others: Vec<DocVersion>,
+  /// The root URL of the current version's own output. When set, the output
+  /// is treated as an alias of the current version (eg. "latest"), and every
+  /// page carries a canonical link to the same page under this root.
+  pub canonical_root: Option<String>,
+}
+
+/// Another version of the documentation.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocVersion {
+  /// The name of the version, eg. "1.1.0".
+  pub name: String,
+  /// The root of the version's output, either as a URL or relative to the
+  /// root of the current output, eg. `../1.1.0/`.
+  pub root: String,
+  /// The symbols documented in the version, keyed by module path, as
+  /// returned by [`collect_version_symbols`]. When not set, every page is
+  /// assumed to exist in the version.
+  pub symbols: Option<IndexMap<String, IndexSet<String>>>,
+  /// Paths relative to the version's root for symbols which have been
+  /// renamed or moved, keyed by module path and the name of the symbol in the
+  /// current version.
+  pub symbol_redirect_map: Option<IndexMap<String, IndexMap<String, String>>>,
+}
+
+/// Collects the symbols documented in the given context, to be passed as
+/// [`DocVersion::symbols`] when generating the documentation of other
+/// versions.
+pub fn collect_version_symbols(
+  ctx: &GenerateCtx,
+) -> IndexMap<String, IndexSet<String>> {
+  ctx
+    .doc_nodes
+    .iter()
+    .map(|(short_path, nodes)| {
+      let mut symbols = IndexSet::new();
+      let mut stack = nodes.iter().rev().cloned().collect::<Vec<_>>();
+      while let Some(node) = stack.pop() {
+        if matches!(node.def, DocNodeDef::ModuleDoc | DocNodeDef::Import { .. })
+        {
+          continue;
+        }
+        symbols.insert(node.get_qualified_name());
+        if let Some(namespace) = node.namespace_def() {
+          let qualifiers: Shared<[String]> = node.sub_qualifier().into();
+          stack.extend(namespace.elements.iter().map(|element| {
+            node.create_namespace_child(element.clone(), qualifiers.clone())
+          }));
+        }
+      }
+      (short_path.path.clone(), symbols)
+    })
+    .collect()
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct VersionSwitcherCtx {
+  pub current: String,
+  pub versions: Vec<VersionSwitcherEntryCtx>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct VersionSwitcherEntryCtx {
+  pub name: String,
+  pub href: String,
+}
+
+impl VersionSwitcherCtx {
+  pub const TEMPLATE: &'static str = "version_switcher";
+
+  pub fn new(ctx: &GenerateCtx, current: UrlResolveKind) -> Option<Self> {
+    let versions = ctx.versions.as_ref()?;
+    if versions.others.is_empty() {
+      return None;
+    }
+
+    let root = ctx.resolve_path(current, UrlResolveKind::Root);
+
+    Some(Self {
+      current: versions.current.clone(),
+      versions: versions
+        .others
+        .iter()
+        .map(|version| VersionSwitcherEntryCtx {
+          name: version.name.clone(),
+          href: format!(
+            "{}{}",
+            join_root(&root, &version.root),
+            page_in_version(ctx, version, current)
+          ),
+        })
+        .collect(),
+    })
+  }
+}
+
+/// Resolves the path of the page in another version, relative to that
+/// version's root. Falls back to the version's index page if the page does
+/// not exist there.
+fn page_in_version(
+  ctx: &GenerateCtx,
+  version: &DocVersion,
+  current: UrlResolveKind,
+) -> String {
+  let (file, symbol) = match current {
+    UrlResolveKind::Symbol { file, symbol } => (file, symbol),
+    UrlResolveKind::File { file } => {
+      return match &version.symbols {
+        Some(symbols) if !symbols.contains_key(&file.path) => String::new(),
+        _ => page_path(ctx, current),
+      };
+    }
+    _ => return page_path(ctx, current),
+  };
+
+  if let Some(href) = version
+    .symbol_redirect_map
+    .as_ref()
+    .and_then(|redirects| redirects.get(&file.path)?.get(symbol))
+  {
+    return href.trim_start_matches("./").to_string();
+  }
+
+  let Some(symbols) = &version.symbols else {
+    return page_path(ctx, current);
+  };
+  let Some(module_symbols) = symbols.get(&file.path) else {
+    return String::new();
+  };
+
+  // drilldown pages link to their closest parent symbol that still exists
+  let mut name = symbol;
+  loop {
+    if module_symbols.contains(name) {
+      return page_path(ctx, UrlResolveKind::Symbol { file, symbol: name });
+    }
+    match name.rsplit_once('.') {
+      Some((parent, _)) => name = parent,
+      None => return String::new(),
+    }
+  }
+}
+
+/// The path of a page relative to the root of the output.
+pub(crate) fn page_path(ctx: &GenerateCtx, target: UrlResolveKind) -> String {
+  // symbols are resolved directly, as the redirects of the current version
+  // do not apply to other versions
+  let path = if let UrlResolveKind::Symbol { .. } = target {
+    ctx.href_resolver.resolve_path(UrlResolveKind::Root, target)
+  } else {
+    ctx.resolve_path(UrlResolveKind::Root, target)
+  };
+
+  path.trim_start_matches("./").to_string()
+}
+
+pub(crate) fn join_root(page_root: &str, version_root: &str) -> String {
+  let separator = if version_root.ends_with('/') { "" } else { "/" };
+  if version_root.starts_with('/') || version_root.contains("://") {
+    format!("{version_root}{separator}")
+  } else {
+    format!("{page_root}{version_root}{separator}")
+  }
+}
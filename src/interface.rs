@@ -5,6 +5,7 @@ use deno_graph::symbols::EsModuleInfo;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::accessor::AccessorDef;
 use crate::params::ts_fn_param_to_param_def;
 use crate::ts_type::CallSignatureDef;
 use crate::ts_type::ConstructorDef;
@@ -18,6 +19,7 @@ use crate::util::swc::get_location;
 use crate::util::swc::js_doc_for_range;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct InterfaceDef {
   #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -33,6 +35,13 @@ pub struct InterfaceDef {
   pub type_params: Box<[TsTypeParamDef]>,
 }
 
+impl InterfaceDef {
+  /// The getters and setters of the interface, merged into properties.
+  pub fn accessors(&self) -> Vec<AccessorDef> {
+    crate::accessor::accessors(&self.methods)
+  }
+}
+
 pub fn expr_to_name(expr: &deno_ast::swc::ast::Expr) -> String {
   use deno_ast::swc::ast::Expr::*;
   use deno_ast::swc::ast::MemberProp;
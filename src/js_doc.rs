@@ -30,6 +30,7 @@ lazy_static! {
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct JsDoc {
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub doc: Option<Box<str>>,
@@ -129,6 +130,7 @@ impl From<String> for JsDoc {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "kind", rename_all = "lowercase")]
 pub enum JsDocTag {
   /// `@callback Predicate comment`
@@ -14,6 +14,8 @@ extern crate lazy_static;
 #[macro_use]
 extern crate serde_json;
 
+pub mod accessor;
+mod cache;
 pub mod class;
 mod decorators;
 mod diagnostics;
@@ -24,21 +26,30 @@ pub mod html;
 pub mod interface;
 pub mod js_doc;
 pub mod node;
+mod node_modules;
 mod params;
 mod parser;
+pub mod query;
+#[cfg(feature = "schema")]
+pub mod schema;
 pub mod ts_type;
 pub mod ts_type_param;
 pub mod type_alias;
+mod type_expansion;
 mod util;
 pub mod variable;
 mod visibility;
+pub mod visit;
 
 pub use node::DocNode;
 pub use node::DocNodeDef;
 pub use node::Location;
 
+pub use decorators::DecoratorDef;
 use node::ImportDef;
-use params::ParamDef;
+pub use params::ObjectPatPropDef;
+pub use params::ParamDef;
+pub use params::ParamPatternDef;
 
 cfg_if! {
   if #[cfg(feature = "rust")] {
@@ -46,12 +57,17 @@ cfg_if! {
     pub use diagnostics::DocDiagnostic;
     pub use diagnostics::DocDiagnosticKind;
     pub use printer::DocPrinter;
+    pub use printer::DocPrinterOrder;
   }
 }
 
+pub use cache::DocCache;
+pub use node_modules::NodeModules;
+pub use node_modules::NodeModulesLoader;
 pub use parser::DocError;
 pub use parser::DocParser;
 pub use parser::DocParserOptions;
+pub use parser::parse_sources;
 
 #[cfg(test)]
 mod tests;
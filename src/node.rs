@@ -1,11 +1,37 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use crate::js_doc::JsDoc;
+use indexmap::IndexMap;
 use serde::Deserialize;
 use serde::Serialize;
 use std::sync::Arc;
 
+/// The version of the serialized [`DocNode`] format. This is bumped whenever
+/// the JSON output changes in a way that is not backwards compatible.
+pub const DOC_NODE_FORMAT_VERSION: u32 = 1;
+
+/// The serialized output for a set of modules, tagged with the format version
+/// so consumers can detect breaking changes.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DocNodesDocument {
+  pub version: u32,
+  /// The documentation nodes, keyed by module specifier.
+  pub nodes: IndexMap<String, Vec<DocNode>>,
+}
+
+impl DocNodesDocument {
+  pub fn new(nodes: IndexMap<String, Vec<DocNode>>) -> Self {
+    Self {
+      version: DOC_NODE_FORMAT_VERSION,
+      nodes,
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct NamespaceDef {
   pub elements: Vec<Arc<DocNode>>,
 }
@@ -22,6 +48,7 @@ pub struct NamespaceDef {
   Clone,
   Copy,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum DocNodeKind {
   // NOTE(bartlomieju): Because of `derive(Ord), we must keep the variants
@@ -41,6 +68,7 @@ pub enum DocNodeKind {
 #[derive(
   Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Default,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Location {
   pub filename: Box<str>,
@@ -73,6 +101,7 @@ impl PartialOrd for Location {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ImportDef {
   pub src: String,
@@ -80,12 +109,14 @@ pub struct ImportDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ReferenceDef {
   pub target: Location,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum DeclarationKind {
   Private,
@@ -94,6 +125,7 @@ pub enum DeclarationKind {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DocNode {
   pub name: Box<str>,
@@ -108,6 +140,7 @@ pub struct DocNode {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "kind", rename_all = "camelCase")]
 pub enum DocNodeDef {
   #[serde(rename_all = "camelCase")]
@@ -0,0 +1,329 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use deno_ast::MediaType;
+use deno_ast::ModuleItemRef;
+use deno_ast::ParseParams;
+use deno_ast::SourceRangedForSpanned;
+use deno_ast::swc::ast::Decl;
+use deno_ast::swc::ast::Stmt;
+use deno_ast::swc::ast::TsModuleName;
+use deno_ast::swc::ast::TsNamespaceBody;
+use deno_graph::ModuleSpecifier;
+use deno_graph::Range;
+use deno_graph::resolve_import;
+use deno_graph::source::LoadError;
+use deno_graph::source::LoadFuture;
+use deno_graph::source::LoadOptions;
+use deno_graph::source::LoadResponse;
+use deno_graph::source::Loader;
+use deno_graph::source::ResolutionKind;
+use deno_graph::source::ResolveError;
+use deno_graph::source::Resolver;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Resolves npm packages and node built-in modules to their type
+/// declarations in a local `node_modules` directory, so that they are
+/// documented like any other module instead of being skipped.
+///
+/// Pass it as the resolver when building the graph, together with the loader
+/// returned by [`NodeModules::loader`]. `npm:` specifiers, and the bare
+/// specifiers which can't be resolved otherwise, resolve to the `types` or
+/// `typings` entry of the package, or to its `@types` package. `node:`
+/// specifiers resolve to the declarations of `@types/node`.
+#[derive(Debug, Clone)]
+pub struct NodeModules {
+  dir: PathBuf,
+}
+
+impl NodeModules {
+  pub fn new(dir: impl Into<PathBuf>) -> Self {
+    let dir = dir.into();
+    Self {
+      dir: std::path::absolute(&dir).unwrap_or(dir),
+    }
+  }
+
+  /// Wraps the loader of the graph to load the files of the `node_modules`
+  /// directory.
+  pub fn loader<'a>(&'a self, loader: &'a dyn Loader) -> NodeModulesLoader<'a> {
+    NodeModulesLoader {
+      node_modules: self,
+      loader,
+    }
+  }
+
+  fn path_in_dir(&self, specifier: &ModuleSpecifier) -> Option<PathBuf> {
+    if specifier.scheme() != "file" {
+      return None;
+    }
+    deno_path_util::url_to_file_path(specifier)
+      .ok()
+      .filter(|path| path.starts_with(&self.dir))
+  }
+
+  fn resolve_package(&self, specifier: &str) -> Option<PathBuf> {
+    let (name, subpath) = parse_package_specifier(specifier)?;
+    let types_name = match name.strip_prefix('@') {
+      Some(name) => name.replacen('/', "__", 1),
+      None => name.clone(),
+    };
+
+    [
+      self.dir.join(&name),
+      self.dir.join("@types").join(types_name),
+    ]
+    .iter()
+    .find_map(|package_dir| package_entry(package_dir, &subpath))
+  }
+
+  fn resolve_node(&self, module_name: &str) -> Option<PathBuf> {
+    find_declaration(&self.dir.join("@types/node").join(module_name))
+  }
+
+  fn resolve_node_modules(
+    &self,
+    specifier_text: &str,
+    referrer: &ModuleSpecifier,
+  ) -> Option<ModuleSpecifier> {
+    let path = if let Some(specifier) = specifier_text.strip_prefix("npm:") {
+      self.resolve_package(specifier.trim_start_matches('/'))?
+    } else if let Some(module_name) = specifier_text.strip_prefix("node:") {
+      self.resolve_node(module_name)?
+    } else {
+      match resolve_import(specifier_text, referrer) {
+        // map the relative imports of the packages to their declarations
+        Ok(specifier) => find_declaration(&self.path_in_dir(&specifier)?)?,
+        Err(_) => self
+          .resolve_package(specifier_text)
+          .or_else(|| self.resolve_node(specifier_text))?,
+      }
+    };
+
+    deno_path_util::url_from_file_path(&path).ok()
+  }
+
+  /// Gets the source of the file, extracting the body of the ambient module
+  /// declaration of the built-in node modules from `@types/node`, eg.
+  /// `declare module "fs" { ... }`.
+  fn module_source(&self, path: &Path, text: String) -> String {
+    let module_name = path
+      .strip_prefix(self.dir.join("@types/node"))
+      .ok()
+      .and_then(|path| path.to_str())
+      .and_then(|path| path.strip_suffix(".d.ts"))
+      .map(|module_name| module_name.replace('\\', "/"));
+
+    match module_name {
+      Some(module_name) => {
+        ambient_module_source(path, &text, &module_name).unwrap_or(text)
+      }
+      None => text,
+    }
+  }
+}
+
+impl Resolver for NodeModules {
+  fn resolve(
+    &self,
+    specifier_text: &str,
+    referrer_range: &Range,
+    _kind: ResolutionKind,
+  ) -> Result<ModuleSpecifier, ResolveError> {
+    match self.resolve_node_modules(specifier_text, &referrer_range.specifier) {
+      Some(specifier) => Ok(specifier),
+      None => Ok(resolve_import(specifier_text, &referrer_range.specifier)?),
+    }
+  }
+}
+
+/// A loader reading the files of the `node_modules` directory from the file
+/// system, and delegating the other files to the wrapped loader.
+pub struct NodeModulesLoader<'a> {
+  node_modules: &'a NodeModules,
+  loader: &'a dyn Loader,
+}
+
+impl Loader for NodeModulesLoader<'_> {
+  fn load(
+    &self,
+    specifier: &ModuleSpecifier,
+    options: LoadOptions,
+  ) -> LoadFuture {
+    let Some(path) = self.node_modules.path_in_dir(specifier) else {
+      return self.loader.load(specifier, options);
+    };
+
+    let result = std::fs::read_to_string(&path)
+      .map(|text| {
+        Some(LoadResponse::Module {
+          specifier: specifier.clone(),
+          maybe_headers: None,
+          mtime: None,
+          content: self
+            .node_modules
+            .module_source(&path, text)
+            .into_bytes()
+            .into(),
+        })
+      })
+      .map_err(|err| LoadError::Other(Arc::new(err)));
+    Box::pin(std::future::ready(result))
+  }
+}
+
+/// Splits a package specifier into the name of the package and the subpath,
+/// eg. `@scope/pkg@^1.0.0/sub` into `@scope/pkg` and `sub`.
+fn parse_package_specifier(specifier: &str) -> Option<(String, String)> {
+  let mut segments = specifier.split('/');
+  let mut name = segments.next()?.to_string();
+  if name.starts_with('@') {
+    name = format!("{name}/{}", segments.next()?);
+  }
+  if let Some(index) = name.get(1..)?.find('@') {
+    name.truncate(index + 1);
+  }
+  let subpath = segments.collect::<Vec<_>>().join("/");
+
+  (!name.is_empty() && !name.starts_with('.')).then_some((name, subpath))
+}
+
+/// Resolves the declarations of the entry of the package at the subpath,
+/// through the `types` or `typings` field of the `package.json` for the main
+/// entry, or the `types` condition of its `exports`.
+fn package_entry(package_dir: &Path, subpath: &str) -> Option<PathBuf> {
+  let package_json = std::fs::read_to_string(package_dir.join("package.json"))
+    .ok()
+    .and_then(|content| {
+      serde_json::from_str::<serde_json::Value>(&content).ok()
+    });
+  if package_json.is_none() && !package_dir.is_dir() {
+    return None;
+  }
+
+  let entry = package_json.as_ref().and_then(|package_json| {
+    subpath
+      .is_empty()
+      .then(|| package_json.get("types").or(package_json.get("typings")))
+      .flatten()
+      .and_then(|entry| entry.as_str())
+      .or_else(|| {
+        let exports = package_json.get("exports")?;
+        if subpath.is_empty() {
+          exports_entry(exports, ".")
+        } else {
+          exports_entry(exports, &format!("./{subpath}"))
+        }
+      })
+  });
+
+  match entry {
+    Some(entry) => find_declaration(&package_dir.join(entry)),
+    None if subpath.is_empty() => find_declaration(&package_dir.join("index")),
+    None => find_declaration(&package_dir.join(subpath)),
+  }
+}
+
+fn exports_entry<'a>(
+  exports: &'a serde_json::Value,
+  key: &str,
+) -> Option<&'a str> {
+  let is_subpaths = exports
+    .as_object()
+    .is_some_and(|exports| exports.keys().any(|key| key.starts_with('.')));
+
+  if is_subpaths {
+    exports_condition(exports.get(key)?)
+  } else if key == "." {
+    exports_condition(exports)
+  } else {
+    None
+  }
+}
+
+fn exports_condition(value: &serde_json::Value) -> Option<&str> {
+  match value {
+    serde_json::Value::String(entry) => Some(entry),
+    serde_json::Value::Object(conditions) => ["types", "import", "default"]
+      .iter()
+      .find_map(|condition| exports_condition(conditions.get(*condition)?)),
+    _ => None,
+  }
+}
+
+/// Finds the declaration file for the path, mapping JavaScript files to their
+/// declarations as TypeScript does, eg. `./mod.js` to `./mod.d.ts`.
+fn find_declaration(path: &Path) -> Option<PathBuf> {
+  let path = path.to_string_lossy();
+  let mut candidates =
+    [(".js", ".d.ts"), (".mjs", ".d.mts"), (".cjs", ".d.cts")]
+      .iter()
+      .filter_map(|(extension, declaration_extension)| {
+        path
+          .strip_suffix(extension)
+          .map(|stem| format!("{stem}{declaration_extension}"))
+      })
+      .collect::<Vec<_>>();
+  candidates.extend([
+    path.to_string(),
+    format!("{path}.d.ts"),
+    format!("{path}/index.d.ts"),
+  ]);
+
+  candidates
+    .into_iter()
+    .map(PathBuf::from)
+    .find(|candidate| candidate.is_file())
+}
+
+/// Blanks out everything but the body of the ambient module declaration of
+/// the module, keeping the offsets so that the locations remain correct.
+fn ambient_module_source(
+  path: &Path,
+  text: &str,
+  module_name: &str,
+) -> Option<String> {
+  let parsed_source = deno_ast::parse_module(ParseParams {
+    specifier: deno_path_util::url_from_file_path(path).ok()?,
+    text: text.into(),
+    media_type: MediaType::Dts,
+    capture_tokens: false,
+    scope_analysis: false,
+    maybe_syntax: None,
+  })
+  .ok()?;
+
+  let node_module_name = format!("node:{module_name}");
+  let body = parsed_source.program_ref().body().find_map(|item| {
+    let ModuleItemRef::Stmt(Stmt::Decl(Decl::TsModule(module_decl))) = item
+    else {
+      return None;
+    };
+    let TsModuleName::Str(name) = &module_decl.id else {
+      return None;
+    };
+    if name.value != module_name && name.value != node_module_name {
+      return None;
+    }
+    match module_decl.body.as_ref()? {
+      TsNamespaceBody::TsModuleBlock(block) => Some(block.range()),
+      TsNamespaceBody::TsNamespaceDecl(_) => None,
+    }
+  })?;
+
+  let start = parsed_source.text_info_lazy().range().start;
+  let body = body.as_byte_range(start);
+  // exclude the braces of the block
+  let body = body.start + 1..body.end - 1;
+
+  let mut source = String::with_capacity(text.len());
+  for (index, char) in text.char_indices() {
+    if body.contains(&index) || char == '\n' {
+      source.push(char);
+    } else {
+      source.extend(std::iter::repeat_n(' ', char.len_utf8()));
+    }
+  }
+  Some(source)
+}
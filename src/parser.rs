@@ -21,10 +21,15 @@ use deno_ast::swc::ast::TsTypeAliasDecl;
 use deno_ast::swc::ast::VarDecl;
 use deno_ast::swc::ast::VarDeclKind;
 use deno_ast::swc::ast::VarDeclarator;
+use deno_graph::BuildOptions;
+use deno_graph::GraphKind;
 use deno_graph::Module;
 use deno_graph::ModuleGraph;
 use deno_graph::ModuleSpecifier;
+use deno_graph::ast::CapturingModuleAnalyzer;
 use deno_graph::ast::EsParser;
+use deno_graph::source::MemoryLoader;
+use deno_graph::source::Source;
 use deno_graph::symbols::EsModuleInfo;
 use deno_graph::symbols::ExpandoPropertyRef;
 use deno_graph::symbols::ExportDeclRef;
@@ -36,6 +41,7 @@ use deno_graph::symbols::UniqueSymbolId;
 use indexmap::IndexMap;
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
@@ -44,6 +50,8 @@ use std::sync::Arc;
 
 use crate::ImportDef;
 use crate::Location;
+use crate::cache;
+use crate::cache::DocCache;
 use crate::diagnostics::DiagnosticsCollector;
 use crate::diagnostics::DocDiagnostic;
 use crate::js_doc::JsDoc;
@@ -52,10 +60,13 @@ use crate::node::DocNode;
 use crate::node::DocNodeDef;
 use crate::node::NamespaceDef;
 use crate::node::ReferenceDef;
+use crate::params::ParamPatternDef;
+use crate::ts_type::LiteralDefKind;
 use crate::ts_type::PropertyDef;
 use crate::ts_type::TsTypeDef;
 use crate::ts_type::TsTypeDefKind;
 use crate::ts_type::TsTypeLiteralDef;
+use crate::ts_type::TsTypeRefDef;
 use crate::ts_type::infer_simple_ts_type_from_init;
 use crate::util::graph::resolve_deno_graph_module;
 use crate::util::swc::get_location;
@@ -103,6 +114,10 @@ pub struct DocParserOptions {
   /// Note: Private nodes that are referenced by public nodes
   /// are always included.
   pub private: bool,
+  /// A cache of the nodes extracted from each module, to skip the extraction
+  /// of unchanged modules across runs. Not used when collecting diagnostics,
+  /// as these are only found while extracting.
+  pub cache: Option<DocCache>,
 }
 
 pub struct DocParser<'a> {
@@ -112,6 +127,7 @@ pub struct DocParser<'a> {
   visibility: SymbolVisibility,
   specifiers: &'a [ModuleSpecifier],
   diagnostics: Option<RefCell<DiagnosticsCollector<'a>>>,
+  cache: Option<DocCache>,
 }
 
 impl<'a> DocParser<'a> {
@@ -137,6 +153,7 @@ impl<'a> DocParser<'a> {
       visibility,
       specifiers,
       diagnostics,
+      cache: options.cache,
     })
   }
 
@@ -163,16 +180,7 @@ impl<'a> DocParser<'a> {
   pub fn parse(
     &self,
   ) -> Result<IndexMap<ModuleSpecifier, Vec<DocNode>>, DocError> {
-    let mut doc_nodes_by_url = self
-      .specifiers
-      .iter()
-      .map(|specifier| {
-        Ok((
-          specifier.clone(),
-          self.parse_with_reexports_inner(specifier, HashSet::new())?,
-        ))
-      })
-      .collect::<Result<IndexMap<_, _>, DocError>>()?;
+    let mut doc_nodes_by_url = self.extract_modules()?;
 
     let mut all_locations = doc_nodes_by_url
       .values()
@@ -208,6 +216,11 @@ impl<'a> DocParser<'a> {
       )?;
     }
 
+    let mut wasm_param_names = HashMap::new();
+    for nodes in doc_nodes_by_url.values_mut() {
+      self.name_wasm_params(nodes, &mut wasm_param_names);
+    }
+
     for (_, doc_nodes) in &doc_nodes_by_url {
       self.collect_diagnostics_for_nodes(doc_nodes);
     }
@@ -215,6 +228,166 @@ impl<'a> DocParser<'a> {
     Ok(doc_nodes_by_url)
   }
 
+  /// Names the parameters of the functions exported by Wasm modules after
+  /// the custom name section of the modules, as the declarations derived for
+  /// them by deno_graph name the parameters `arg0`, `arg1`, etc.
+  fn name_wasm_params(
+    &self,
+    nodes: &mut [DocNode],
+    param_names: &mut HashMap<Box<str>, HashMap<usize, Vec<String>>>,
+  ) {
+    for node in nodes {
+      match &mut node.def {
+        DocNodeDef::Namespace { namespace_def } => {
+          for element in &mut namespace_def.elements {
+            self.name_wasm_params(
+              std::slice::from_mut(Arc::make_mut(element)),
+              param_names,
+            );
+          }
+        }
+        DocNodeDef::Function { function_def } => {
+          let names = param_names
+            .entry(node.location.filename.clone())
+            .or_insert_with(|| {
+              ModuleSpecifier::parse(&node.location.filename)
+                .ok()
+                .and_then(|specifier| match self.graph.get(&specifier) {
+                  Some(Module::Wasm(module)) => {
+                    Some(crate::util::wasm::param_names_by_line(
+                      &module.source,
+                      &module.source_dts,
+                    ))
+                  }
+                  _ => None,
+                })
+                .unwrap_or_default()
+            });
+          let Some(names) = names.get(&node.location.line) else {
+            continue;
+          };
+
+          for (param, name) in function_def.params.iter_mut().zip(names) {
+            if let ParamPatternDef::Identifier {
+              name: param_name, ..
+            } = &mut param.pattern
+            {
+              *param_name = name.clone();
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Extracts the nodes of each of the specifiers, reusing the nodes in the
+  /// cache for the unchanged modules.
+  fn extract_modules(
+    &self,
+  ) -> Result<IndexMap<ModuleSpecifier, Vec<DocNode>>, DocError> {
+    let cache = self.cache.as_ref().filter(|_| self.diagnostics.is_none());
+    let graph = self.graph;
+    let private = self.private;
+    let cached = if let Some(cache) = cache {
+      crate::util::par_map(self.specifiers.iter().collect(), |specifier| {
+        let hash = cache::module_hash(graph, specifier, private);
+        let cached = cache.get(specifier, &hash);
+        (Some(hash), cached)
+      })
+    } else {
+      self.specifiers.iter().map(|_| (None, None)).collect()
+    };
+
+    let pending = self
+      .specifiers
+      .iter()
+      .zip(&cached)
+      .filter(|(_, (_, cached))| cached.is_none())
+      .map(|(specifier, _)| specifier)
+      .collect::<Vec<_>>();
+    let mut extracted = self.extract_specifiers(&pending)?.into_iter();
+
+    let mut doc_nodes_by_url = IndexMap::with_capacity(self.specifiers.len());
+    let mut uncached = vec![];
+    for (specifier, (hash, cached)) in self.specifiers.iter().zip(cached) {
+      let doc_nodes = if let Some(doc_nodes) = cached {
+        doc_nodes
+      } else {
+        if let Some(hash) = hash {
+          uncached.push((specifier, hash));
+        }
+        extracted.next().unwrap()
+      };
+      doc_nodes_by_url.insert(specifier.clone(), doc_nodes);
+    }
+
+    if let Some(cache) = cache {
+      crate::util::par_map(uncached, |(specifier, hash)| {
+        cache.set(specifier, &hash, &doc_nodes_by_url[specifier]);
+      });
+    }
+
+    Ok(doc_nodes_by_url)
+  }
+
+  /// Extracts the nodes of the specifiers, in the order of the specifiers.
+  ///
+  /// With the `parallel` feature, the specifiers are split between the
+  /// threads of the rayon thread pool. The symbols of the graph can't be
+  /// shared between threads, so each thread analyzes the graph with its own
+  /// `RootSymbol`, parsing the modules again with a `DefaultEsParser` rather
+  /// than reusing the parser of this `DocParser`. This is only done when
+  /// there are several modules to extract and no diagnostics are collected,
+  /// as these are found while extracting.
+  fn extract_specifiers(
+    &self,
+    specifiers: &[&ModuleSpecifier],
+  ) -> Result<Vec<Vec<DocNode>>, DocError> {
+    #[cfg(feature = "parallel")]
+    if specifiers.len() > 1 && self.diagnostics.is_none() {
+      let graph = self.graph;
+      let private = self.private;
+      let chunk_size = specifiers.len().div_ceil(rayon::current_num_threads());
+      let chunks = crate::util::par_map(
+        specifiers.chunks(chunk_size).collect(),
+        |chunk| -> Result<Vec<_>, DocError> {
+          let parser = deno_graph::ast::DefaultEsParser;
+          let root_symbol =
+            Rc::new(deno_graph::symbols::RootSymbol::new(graph, &parser));
+          let visibility = SymbolVisibility::build(graph, &root_symbol)?;
+          let doc_parser = DocParser {
+            graph,
+            private,
+            root_symbol,
+            visibility,
+            specifiers: &[],
+            diagnostics: None,
+            cache: None,
+          };
+          chunk
+            .iter()
+            .map(|specifier| {
+              doc_parser.parse_with_reexports_inner(specifier, HashSet::new())
+            })
+            .collect()
+        },
+      );
+      let mut doc_nodes = Vec::with_capacity(specifiers.len());
+      for chunk in chunks {
+        doc_nodes.extend(chunk?);
+      }
+      return Ok(doc_nodes);
+    }
+
+    specifiers
+      .iter()
+      .map(|specifier| {
+        self.parse_with_reexports_inner(specifier, HashSet::new())
+      })
+      .collect()
+  }
+
   fn resolve_references_for_nodes(
     &self,
     specifier: &ModuleSpecifier,
@@ -581,6 +754,7 @@ impl<'a> DocParser<'a> {
       VariableDef {
         ts_type,
         kind: VarDeclKind::Const,
+        value_preview: None,
       },
     ))
   }
@@ -990,6 +1164,7 @@ impl<'a> DocParser<'a> {
             export_expr.expr.as_ref(),
             true,
           ),
+          value_preview: None,
         },
       ))
     } else {
@@ -1440,6 +1615,63 @@ impl<'a> DocParser<'a> {
   }
 }
 
+/// Documents modules whose sources are held in memory, without having to
+/// provide a [`deno_graph::source::Loader`].
+///
+/// `sources` maps each module specifier to its source text; the media type
+/// is derived from the specifier's extension. Any specifier that is imported
+/// but not present in `sources` fails to resolve and results in an error.
+pub async fn parse_sources(
+  root_specifiers: &[ModuleSpecifier],
+  sources: IndexMap<ModuleSpecifier, String>,
+  options: DocParserOptions,
+) -> Result<IndexMap<ModuleSpecifier, Vec<DocNode>>, anyhow::Error> {
+  let sources = sources
+    .into_iter()
+    .map(|(specifier, content)| {
+      let specifier = specifier.to_string();
+      (
+        specifier.clone(),
+        Source::Module {
+          specifier,
+          maybe_headers: None,
+          content,
+        },
+      )
+    })
+    .collect();
+  let loader = MemoryLoader::new(sources, vec![]);
+  let analyzer = CapturingModuleAnalyzer::default();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      root_specifiers.to_vec(),
+      Vec::new(),
+      &loader,
+      BuildOptions {
+        module_analyzer: &analyzer,
+        ..Default::default()
+      },
+    )
+    .await;
+  graph.valid()?;
+
+  let doc_nodes_by_url =
+    DocParser::new(&graph, &analyzer, root_specifiers, options)?.parse()?;
+  Ok(doc_nodes_by_url)
+}
+
+/// The depth up to which the nested objects and arrays of a JSON module are
+/// typed, deeper values being typed as `Record<string, unknown>` and
+/// `unknown[]`.
+const JSON_MODULE_TYPE_MAX_DEPTH: usize = 4;
+/// The number of distinct types of the elements of an array above which
+/// their literal types are widened to keyword types, eg. `string`, and above
+/// which the widened types are typed as `unknown`.
+const JSON_MODULE_MAX_LITERAL_UNION: usize = 4;
+/// The maximum length of the preview of the value of a JSON module.
+const JSON_MODULE_PREVIEW_MAX_LENGTH: usize = 80;
+
 fn parse_json_module_doc_node(
   specifier: &ModuleSpecifier,
   source: &str,
@@ -1458,8 +1690,12 @@ fn parse_json_module_doc_node(
       js_doc: JsDoc::default(),
       def: DocNodeDef::Variable {
         variable_def: VariableDef {
-          kind: VarDeclKind::Var,
-          ts_type: Some(parse_json_module_type(&value)),
+          kind: VarDeclKind::Const,
+          ts_type: Some(parse_json_module_type(
+            &value,
+            JSON_MODULE_TYPE_MAX_DEPTH,
+          )),
+          value_preview: Some(json_module_preview(&value)),
         },
       },
     })
@@ -1469,7 +1705,20 @@ fn parse_json_module_doc_node(
   }
 }
 
-fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
+/// Serializes the value, truncated to [`JSON_MODULE_PREVIEW_MAX_LENGTH`]
+/// characters.
+fn json_module_preview(value: &serde_json::Value) -> String {
+  let preview = value.to_string();
+  match preview.char_indices().nth(JSON_MODULE_PREVIEW_MAX_LENGTH) {
+    Some((index, _)) => format!("{}…", &preview[..index]),
+    None => preview,
+  }
+}
+
+fn parse_json_module_type(
+  value: &serde_json::Value,
+  depth: usize,
+) -> TsTypeDef {
   match value {
     serde_json::Value::Null => TsTypeDef::keyword("null"),
     serde_json::Value::Bool(value) => TsTypeDef::bool_value(*value),
@@ -1480,10 +1729,52 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
       Some(value) => TsTypeDef::number_value(value),
       None => TsTypeDef::keyword("number"),
     },
-    serde_json::Value::Array(_) => TsTypeDef {
-      repr: "unknown[]".to_string(),
-      kind: Some(TsTypeDefKind::Array),
-      array: Some(Box::new(TsTypeDef::keyword("unknown"))),
+    serde_json::Value::Array(_) if depth == 0 => {
+      json_module_array_type(TsTypeDef::keyword("unknown"))
+    }
+    serde_json::Value::Array(elements) => {
+      let mut types = Vec::<TsTypeDef>::new();
+      let mut widened = false;
+      for element in elements {
+        let mut ts_type = parse_json_module_type(element, depth - 1);
+        // the values of the records of an array are data rather than
+        // constants, so only their shape is kept
+        if widened || ts_type.kind == Some(TsTypeDefKind::TypeLiteral) {
+          ts_type = widen_json_module_type(ts_type);
+        }
+        if types.contains(&ts_type) {
+          continue;
+        }
+        types.push(ts_type);
+
+        if types.len() > JSON_MODULE_MAX_LITERAL_UNION && !widened {
+          widened = true;
+          types = dedup_json_module_types(
+            types.into_iter().map(widen_json_module_type),
+          );
+        }
+        if types.len() > JSON_MODULE_MAX_LITERAL_UNION {
+          types = vec![TsTypeDef::keyword("unknown")];
+          break;
+        }
+      }
+
+      json_module_array_type(match types.len() {
+        0 => TsTypeDef::keyword("unknown"),
+        1 => types.remove(0),
+        _ => json_module_union_type(types),
+      })
+    }
+    serde_json::Value::Object(_) if depth == 0 => TsTypeDef {
+      repr: "Record".to_string(),
+      kind: Some(TsTypeDefKind::TypeRef),
+      type_ref: Some(TsTypeRefDef {
+        type_params: Some(Box::new([
+          TsTypeDef::keyword("string"),
+          TsTypeDef::keyword("unknown"),
+        ])),
+        type_name: "Record".to_string(),
+      }),
       ..Default::default()
     },
     serde_json::Value::Object(obj) => TsTypeDef {
@@ -1495,7 +1786,7 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
           .map(|(key, value)| PropertyDef {
             name: key.to_string(),
             js_doc: Default::default(),
-            ts_type: Some(parse_json_module_type(value)),
+            ts_type: Some(parse_json_module_type(value, depth - 1)),
             params: Vec::new(),
             readonly: false,
             computed: false,
@@ -1511,6 +1802,94 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
   }
 }
 
+/// Arrays of keywords have a repr, like the inferred `any[]` arrays, while
+/// other arrays have none, like the arrays parsed from the source.
+fn json_module_array_type(element: TsTypeDef) -> TsTypeDef {
+  let repr = if element.kind == Some(TsTypeDefKind::Keyword) {
+    format!("{}[]", element.repr)
+  } else {
+    String::new()
+  };
+  TsTypeDef {
+    repr,
+    kind: Some(TsTypeDefKind::Array),
+    array: Some(Box::new(element)),
+    ..Default::default()
+  }
+}
+
+/// Widens the literal types to their keyword types, eg. `string`, including
+/// the ones of the properties and elements of the type.
+fn widen_json_module_type(ts_type: TsTypeDef) -> TsTypeDef {
+  match ts_type.kind {
+    Some(TsTypeDefKind::Literal) => {
+      match ts_type.literal.as_ref().map(|literal| &literal.kind) {
+        Some(LiteralDefKind::Boolean) => TsTypeDef::keyword("boolean"),
+        Some(LiteralDefKind::Number) => TsTypeDef::keyword("number"),
+        Some(LiteralDefKind::String) => TsTypeDef::keyword("string"),
+        _ => ts_type,
+      }
+    }
+    Some(TsTypeDefKind::TypeLiteral) => {
+      let mut ts_type = ts_type;
+      if let Some(type_literal) = &mut ts_type.type_literal {
+        for property in &mut type_literal.properties {
+          property.ts_type =
+            property.ts_type.take().map(widen_json_module_type);
+        }
+      }
+      ts_type
+    }
+    Some(TsTypeDefKind::Array) => match ts_type.array {
+      Some(element) => json_module_array_type(widen_json_module_type(*element)),
+      None => ts_type,
+    },
+    Some(TsTypeDefKind::Parenthesized) => {
+      let Some(union) = ts_type
+        .parenthesized
+        .as_ref()
+        .and_then(|parenthesized| parenthesized.union.clone())
+      else {
+        return ts_type;
+      };
+      let mut types =
+        dedup_json_module_types(union.into_iter().map(widen_json_module_type));
+      if types.len() == 1 {
+        types.remove(0)
+      } else {
+        json_module_union_type(types)
+      }
+    }
+    _ => ts_type,
+  }
+}
+
+fn dedup_json_module_types(
+  types: impl Iterator<Item = TsTypeDef>,
+) -> Vec<TsTypeDef> {
+  let mut deduped = Vec::<TsTypeDef>::new();
+  for ts_type in types {
+    if !deduped.contains(&ts_type) {
+      deduped.push(ts_type);
+    }
+  }
+  deduped
+}
+
+fn json_module_union_type(types: Vec<TsTypeDef>) -> TsTypeDef {
+  TsTypeDef {
+    repr: "".to_string(),
+    kind: Some(TsTypeDefKind::Parenthesized),
+    parenthesized: Some(Box::new(TsTypeDef {
+      repr: "".to_string(),
+      kind: Some(TsTypeDefKind::Union),
+      union: Some(types),
+      ..Default::default()
+    })),
+    ..Default::default()
+  }
+}
+
 fn module_has_import(module_info: &EsModuleInfo) -> bool {
   module_info.source().program_ref().body().any(|m| {
     matches!(
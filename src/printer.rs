@@ -1,6 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
 use crate::Location;
+use crate::accessor::AccessorDef;
+use crate::accessor::is_accessor;
+use crate::class::ClassConstructorDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
 use crate::display::SliceDisplayer;
 use crate::display::display_abstract;
 use crate::display::display_async;
@@ -10,10 +15,15 @@ use crate::js_doc::JsDocTag;
 use crate::node::DeclarationKind;
 use crate::node::DocNode;
 use crate::node::DocNodeDef;
+use crate::ts_type::ConstructorDef;
+use crate::ts_type::IndexSignatureDef;
+use crate::ts_type::MethodDef;
+use crate::ts_type::PropertyDef;
 
 use deno_terminal::colors;
 use deno_terminal::colors::Style;
 
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -22,10 +32,138 @@ fn italic_cyan<'a, S: Display + 'a>(s: S) -> Style<Style<S>> {
   colors::italic(colors::cyan(s))
 }
 
+/// The order in which [`DocPrinter`] prints symbols and members.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DocPrinterOrder {
+  /// Grouped by kind, then sorted by name.
+  #[default]
+  Kind,
+  /// In the order they are declared, based on their [`Location`].
+  Source,
+  /// Sorted by name.
+  Alphabetical,
+  /// Grouped by their `@category` tag, then by kind and name. Items without a
+  /// category are printed last.
+  Category,
+}
+
+impl DocPrinterOrder {
+  fn compare(self, a: &SortKey, b: &SortKey) -> Ordering {
+    let by_kind = || a.kind.cmp(&b.kind);
+    let by_name = || a.name.cmp(b.name);
+    match self {
+      DocPrinterOrder::Kind => by_kind().then_with(by_name),
+      DocPrinterOrder::Source => a.location.cmp(b.location),
+      DocPrinterOrder::Alphabetical => by_name().then_with(by_kind),
+      DocPrinterOrder::Category => match (a.category, b.category) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+      }
+      .then_with(by_kind)
+      .then_with(by_name),
+    }
+  }
+}
+
+struct SortKey<'a> {
+  kind: i64,
+  name: &'a str,
+  location: &'a Location,
+  category: Option<&'a str>,
+}
+
+impl<'a> SortKey<'a> {
+  fn new(
+    kind: i64,
+    name: &'a str,
+    location: &'a Location,
+    js_doc: &'a JsDoc,
+  ) -> Self {
+    let category = js_doc.tags.iter().find_map(|tag| match tag {
+      JsDocTag::Category { doc } => Some(doc.trim()),
+      _ => None,
+    });
+    Self {
+      kind,
+      name,
+      location,
+      category,
+    }
+  }
+}
+
+trait MemberSortKey {
+  fn sort_key(&self) -> SortKey<'_>;
+}
+
+enum ClassMember<'a> {
+  Constructor(&'a ClassConstructorDef),
+  Property(&'a ClassPropertyDef),
+  Accessor(&'a AccessorDef),
+  IndexSignature(&'a IndexSignatureDef),
+  Method(&'a ClassMethodDef),
+}
+
+impl MemberSortKey for ClassMember<'_> {
+  fn sort_key(&self) -> SortKey<'_> {
+    match self {
+      ClassMember::Constructor(def) => {
+        SortKey::new(0, &def.name, &def.location, &def.js_doc)
+      }
+      ClassMember::Property(def) => {
+        SortKey::new(1, &def.name, &def.location, &def.js_doc)
+      }
+      ClassMember::Accessor(def) => {
+        SortKey::new(1, &def.name, &def.location, &def.js_doc)
+      }
+      ClassMember::IndexSignature(def) => {
+        SortKey::new(2, "", &def.location, &def.js_doc)
+      }
+      ClassMember::Method(def) => {
+        SortKey::new(3, &def.name, &def.location, &def.js_doc)
+      }
+    }
+  }
+}
+
+enum InterfaceMember<'a> {
+  Constructor(&'a ConstructorDef),
+  Property(&'a PropertyDef),
+  Accessor(&'a AccessorDef),
+  Method(&'a MethodDef),
+  IndexSignature(&'a IndexSignatureDef),
+}
+
+impl MemberSortKey for InterfaceMember<'_> {
+  fn sort_key(&self) -> SortKey<'_> {
+    match self {
+      InterfaceMember::Constructor(def) => {
+        SortKey::new(0, "constructor", &def.location, &def.js_doc)
+      }
+      InterfaceMember::Property(def) => {
+        SortKey::new(1, &def.name, &def.location, &def.js_doc)
+      }
+      InterfaceMember::Accessor(def) => {
+        SortKey::new(1, &def.name, &def.location, &def.js_doc)
+      }
+      InterfaceMember::Method(def) => {
+        SortKey::new(2, &def.name, &def.location, &def.js_doc)
+      }
+      InterfaceMember::IndexSignature(def) => {
+        SortKey::new(3, "", &def.location, &def.js_doc)
+      }
+    }
+  }
+}
+
 pub struct DocPrinter<'a> {
   doc_nodes: &'a [DocNode],
   use_color: bool,
   private: bool,
+  order: DocPrinterOrder,
+  member_order: Option<DocPrinterOrder>,
 }
 
 impl DocPrinter<'_> {
@@ -38,9 +176,25 @@ impl DocPrinter<'_> {
       doc_nodes,
       use_color,
       private,
+      order: DocPrinterOrder::default(),
+      member_order: None,
     }
   }
 
+  /// Sets the order in which the top level symbols are printed.
+  pub fn with_order(mut self, order: DocPrinterOrder) -> Self {
+    self.order = order;
+    self
+  }
+
+  /// Sets the order in which namespace elements and class and interface
+  /// members are printed. When not set, they are printed grouped by member
+  /// kind, in the order they are declared.
+  pub fn with_member_order(mut self, order: DocPrinterOrder) -> Self {
+    self.member_order = Some(order);
+    self
+  }
+
   pub fn format(&self, w: &mut Formatter<'_>) -> FmtResult {
     self.format_with_indent(w, self.doc_nodes, 0)
   }
@@ -54,13 +208,10 @@ impl DocPrinter<'_> {
     colors::set_use_color(self.use_color);
 
     let mut sorted = Vec::from(doc_nodes);
-    sorted.sort_unstable_by(|a, b| {
-      let kind_cmp = self.kind_order(a).cmp(&self.kind_order(b));
-      if kind_cmp == core::cmp::Ordering::Equal {
-        a.name.cmp(&b.name)
-      } else {
-        kind_cmp
-      }
+    sorted.sort_by(|a, b| {
+      self
+        .order
+        .compare(&self.node_sort_key(a), &self.node_sort_key(b))
     });
 
     for node in &sorted {
@@ -113,6 +264,21 @@ impl DocPrinter<'_> {
     Ok(())
   }
 
+  fn node_sort_key<'b>(&self, node: &'b DocNode) -> SortKey<'b> {
+    SortKey::new(
+      self.kind_order(node),
+      &node.name,
+      &node.location,
+      &node.js_doc,
+    )
+  }
+
+  fn sort_members(&self, members: &mut [impl MemberSortKey]) {
+    if let Some(order) = self.member_order {
+      members.sort_by(|a, b| order.compare(&a.sort_key(), &b.sort_key()));
+    }
+  }
+
   fn kind_order(&self, node: &DocNode) -> i64 {
     match node.def {
       DocNodeDef::ModuleDoc => 0,
@@ -426,48 +592,86 @@ impl DocPrinter<'_> {
 
   fn format_class(&self, w: &mut Formatter<'_>, node: &DocNode) -> FmtResult {
     let class_def = node.class_def().unwrap();
-    let has_overloads = class_def.constructors.len() > 1;
-    for node in class_def.constructors.iter() {
-      if !has_overloads || !node.has_body {
-        writeln!(w, "{}{}", Indent(1), node,)?;
-        self.format_jsdoc(w, &node.js_doc, 2)?;
-      }
-    }
-    for node in class_def.properties.iter().filter(|node| {
-      self.private
-        || node
-          .accessibility
-          .unwrap_or(deno_ast::swc::ast::Accessibility::Public)
-          != deno_ast::swc::ast::Accessibility::Private
-    }) {
-      for d in node.decorators.iter() {
-        writeln!(w, "{}{}", Indent(1), d)?;
-      }
-      writeln!(w, "{}{}", Indent(1), node,)?;
-      self.format_jsdoc(w, &node.js_doc, 2)?;
-    }
-    for index_sign_def in class_def.index_signatures.iter() {
-      writeln!(w, "{}{}", Indent(1), index_sign_def)?;
-    }
-    for node in class_def.methods.iter().filter(|node| {
-      self.private
-        || node
-          .accessibility
-          .unwrap_or(deno_ast::swc::ast::Accessibility::Public)
-          != deno_ast::swc::ast::Accessibility::Private
-    }) {
-      let has_overloads = class_def
+    let is_visible =
+      |accessibility: Option<deno_ast::swc::ast::Accessibility>| {
+        self.private
+          || accessibility.unwrap_or(deno_ast::swc::ast::Accessibility::Public)
+            != deno_ast::swc::ast::Accessibility::Private
+      };
+
+    let accessors = class_def.accessors();
+
+    let mut members = Vec::new();
+    members.extend(class_def.constructors.iter().map(ClassMember::Constructor));
+    members.extend(
+      class_def
+        .properties
+        .iter()
+        .filter(|def| is_visible(def.accessibility))
+        .map(ClassMember::Property),
+    );
+    members.extend(
+      accessors
+        .iter()
+        .filter(|def| is_visible(def.accessibility))
+        .map(ClassMember::Accessor),
+    );
+    members.extend(
+      class_def
+        .index_signatures
+        .iter()
+        .map(ClassMember::IndexSignature),
+    );
+    members.extend(
+      class_def
         .methods
         .iter()
-        .filter(|n| n.name == node.name)
-        .count()
-        > 1;
-      if !has_overloads || !node.function_def.has_body {
-        for d in node.function_def.decorators.iter() {
-          writeln!(w, "{}{}", Indent(1), d)?;
+        .filter(|def| is_visible(def.accessibility) && !is_accessor(def.kind))
+        .map(ClassMember::Method),
+    );
+    self.sort_members(&mut members);
+
+    for member in members {
+      match member {
+        ClassMember::Constructor(node) => {
+          let has_overloads = class_def.constructors.len() > 1;
+          if !has_overloads || !node.has_body {
+            writeln!(w, "{}{}", Indent(1), node,)?;
+            self.format_jsdoc(w, &node.js_doc, 2)?;
+          }
+        }
+        ClassMember::Property(node) => {
+          for d in node.decorators.iter() {
+            writeln!(w, "{}{}", Indent(1), d)?;
+          }
+          writeln!(w, "{}{}", Indent(1), node,)?;
+          self.format_jsdoc(w, &node.js_doc, 2)?;
+        }
+        ClassMember::Accessor(node) => {
+          for d in node.decorators.iter() {
+            writeln!(w, "{}{}", Indent(1), d)?;
+          }
+          writeln!(w, "{}{}", Indent(1), node)?;
+          self.format_jsdoc(w, &node.js_doc, 2)?;
+        }
+        ClassMember::IndexSignature(index_sign_def) => {
+          writeln!(w, "{}{}", Indent(1), index_sign_def)?;
+        }
+        ClassMember::Method(node) => {
+          let has_overloads = class_def
+            .methods
+            .iter()
+            .filter(|n| n.name == node.name)
+            .count()
+            > 1;
+          if !has_overloads || !node.function_def.has_body {
+            for d in node.function_def.decorators.iter() {
+              writeln!(w, "{}{}", Indent(1), d)?;
+            }
+            writeln!(w, "{}{}", Indent(1), node,)?;
+            self.format_jsdoc(w, &node.js_doc, 2)?;
+          }
         }
-        writeln!(w, "{}{}", Indent(1), node,)?;
-        self.format_jsdoc(w, &node.js_doc, 2)?;
       }
     }
     writeln!(w)
@@ -488,21 +692,59 @@ impl DocPrinter<'_> {
     node: &DocNode,
   ) -> FmtResult {
     let interface_def = node.interface_def().unwrap();
+    let accessors = interface_def.accessors();
 
-    for constructor in &interface_def.constructors {
-      writeln!(w, "{}{}", Indent(1), constructor)?;
-      self.format_jsdoc(w, &constructor.js_doc, 2)?;
-    }
-    for property_def in &interface_def.properties {
-      writeln!(w, "{}{}", Indent(1), property_def)?;
-      self.format_jsdoc(w, &property_def.js_doc, 2)?;
-    }
-    for method_def in &interface_def.methods {
-      writeln!(w, "{}{}", Indent(1), method_def)?;
-      self.format_jsdoc(w, &method_def.js_doc, 2)?;
-    }
-    for index_sign_def in &interface_def.index_signatures {
-      writeln!(w, "{}{}", Indent(1), index_sign_def)?;
+    let mut members = Vec::new();
+    members.extend(
+      interface_def
+        .constructors
+        .iter()
+        .map(InterfaceMember::Constructor),
+    );
+    members.extend(
+      interface_def
+        .properties
+        .iter()
+        .map(InterfaceMember::Property),
+    );
+    members.extend(accessors.iter().map(InterfaceMember::Accessor));
+    members.extend(
+      interface_def
+        .methods
+        .iter()
+        .filter(|def| !is_accessor(def.kind))
+        .map(InterfaceMember::Method),
+    );
+    members.extend(
+      interface_def
+        .index_signatures
+        .iter()
+        .map(InterfaceMember::IndexSignature),
+    );
+    self.sort_members(&mut members);
+
+    for member in members {
+      match member {
+        InterfaceMember::Constructor(constructor) => {
+          writeln!(w, "{}{}", Indent(1), constructor)?;
+          self.format_jsdoc(w, &constructor.js_doc, 2)?;
+        }
+        InterfaceMember::Property(property_def) => {
+          writeln!(w, "{}{}", Indent(1), property_def)?;
+          self.format_jsdoc(w, &property_def.js_doc, 2)?;
+        }
+        InterfaceMember::Accessor(accessor_def) => {
+          writeln!(w, "{}{}", Indent(1), accessor_def)?;
+          self.format_jsdoc(w, &accessor_def.js_doc, 2)?;
+        }
+        InterfaceMember::Method(method_def) => {
+          writeln!(w, "{}{}", Indent(1), method_def)?;
+          self.format_jsdoc(w, &method_def.js_doc, 2)?;
+        }
+        InterfaceMember::IndexSignature(index_sign_def) => {
+          writeln!(w, "{}{}", Indent(1), index_sign_def)?;
+        }
+      }
     }
     writeln!(w)
   }
@@ -513,7 +755,13 @@ impl DocPrinter<'_> {
     node: &DocNode,
   ) -> FmtResult {
     let elements = &node.namespace_def().unwrap().elements;
-    for node in elements {
+    let mut sorted = elements.iter().collect::<Vec<_>>();
+    if let Some(order) = self.member_order {
+      sorted.sort_by(|a, b| {
+        order.compare(&self.node_sort_key(a), &self.node_sort_key(b))
+      });
+    }
+    for node in sorted {
       let has_overloads = if matches!(node.def, DocNodeDef::Function { .. }) {
         elements
           .iter()
@@ -767,6 +1015,9 @@ impl DocPrinter<'_> {
     if let Some(ts_type) = &variable_def.ts_type {
       write!(w, ": {}", ts_type)?;
     }
+    if let Some(value_preview) = &variable_def.value_preview {
+      write!(w, " = {}", value_preview)?;
+    }
     writeln!(w)
   }
 }
@@ -0,0 +1,383 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! A small query language for selecting doc nodes.
+//!
+//! A query is a whitespace separated list of terms, all of which have to match
+//! for a node to be selected:
+//!
+//! - `Foo`, `Foo.bar`, `get*`, `Deno.**`: glob matched against the name of the
+//!   node. When the pattern contains a `.`, it is matched against the fully
+//!   qualified name instead, so that namespace elements and class or interface
+//!   members can be selected. `*` and `?` don't match across `.`, `**` does.
+//! - `/^use[A-Z]/`: regex matched against the fully qualified name.
+//! - `kind:function`, `kind:class,interface`: the kind of the node, using the
+//!   same names as the `kind` field of the serialized node.
+//! - `@deprecated`, `@experimental`, ...: the node has a JSDoc tag with that
+//!   name.
+//! - `@category Name`: the node has a `@category` tag matching the glob.
+//! - `module:*/fs/*.ts`: glob matched against the specifier of the module the
+//!   node is declared in.
+//! - `returns:Promise`: the node is a function or method whose return type,
+//!   or a type nested in it, is named after the glob.
+//!
+//! Any term can be negated by prefixing it with `!`, and values containing
+//! whitespace can be wrapped in double quotes.
+
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::DocNodeDef;
+use crate::ts_type::TsTypeDef;
+use regex::Regex;
+use serde::Serialize;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+const KINDS: &[&str] = &[
+  "class",
+  "enum",
+  "function",
+  "import",
+  "interface",
+  "moduleDoc",
+  "namespace",
+  "reference",
+  "typeAlias",
+  "variable",
+];
+
+#[derive(Debug)]
+pub enum QueryError {
+  InvalidPattern(regex::Error),
+  UnknownKind(String),
+  MissingValue(String),
+  UnterminatedQuote,
+}
+
+impl Error for QueryError {}
+
+impl fmt::Display for QueryError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidPattern(err) => write!(f, "Invalid pattern: {}", err),
+      Self::UnknownKind(kind) => write!(
+        f,
+        "Unknown kind '{}', expected one of: {}.",
+        kind,
+        KINDS.join(", ")
+      ),
+      Self::MissingValue(term) => {
+        write!(f, "Missing a value for '{}'.", term)
+      }
+      Self::UnterminatedQuote => write!(f, "Unterminated quote."),
+    }
+  }
+}
+
+impl From<regex::Error> for QueryError {
+  fn from(error: regex::Error) -> QueryError {
+    QueryError::InvalidPattern(error)
+  }
+}
+
+#[derive(Debug, Clone)]
+enum Term {
+  Name(Regex),
+  QualifiedName(Regex),
+  Kind(Vec<String>),
+  Tag(String),
+  Category(Regex),
+  Module(Regex),
+  Returns(Regex),
+  Not(Box<Term>),
+}
+
+impl Term {
+  fn matches(&self, node: &DocNode, qualified_name: &str) -> bool {
+    match self {
+      Term::Name(re) => re.is_match(&node.name),
+      Term::QualifiedName(re) => re.is_match(qualified_name),
+      Term::Kind(kinds) => kinds.iter().any(|kind| kind == kind_name(node)),
+      Term::Tag(name) => node.js_doc.tags.iter().any(|tag| tag_name(tag) == name),
+      Term::Category(re) => node.js_doc.tags.iter().any(|tag| {
+        matches!(tag, JsDocTag::Category { doc } if re.is_match(doc.trim()))
+      }),
+      Term::Module(re) => re.is_match(&node.location.filename),
+      Term::Returns(re) => node
+        .function_def()
+        .and_then(|def| def.return_type.as_ref())
+        .is_some_and(|ts_type| type_matches(ts_type, re)),
+      Term::Not(term) => !term.matches(node, qualified_name),
+    }
+  }
+}
+
+/// A doc node selected by a [`Query`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryMatch {
+  /// The name of the node, prefixed by the names of the namespaces, classes
+  /// or interfaces it is nested in, joined by `.`.
+  pub qualified_name: String,
+  pub node: DocNode,
+}
+
+/// A parsed query to select doc nodes with. See the [module level
+/// documentation](self) for the syntax.
+#[derive(Debug, Clone)]
+pub struct Query {
+  terms: Vec<Term>,
+}
+
+impl FromStr for Query {
+  type Err = QueryError;
+
+  fn from_str(query: &str) -> Result<Self, Self::Err> {
+    let mut tokens = tokenize(query)?.into_iter();
+    let mut terms = Vec::new();
+    while let Some(token) = tokens.next() {
+      let (negated, token) = match token.strip_prefix('!') {
+        Some(token) => (true, token.to_string()),
+        None => (false, token),
+      };
+      let term = if token == "@category" {
+        let value = tokens
+          .next()
+          .ok_or_else(|| QueryError::MissingValue(token.clone()))?;
+        Term::Category(glob_to_regex(&value)?)
+      } else if let Some(tag) = token.strip_prefix('@') {
+        Term::Tag(tag.to_string())
+      } else if let Some(kinds) = token.strip_prefix("kind:") {
+        let kinds = kinds
+          .split(',')
+          .map(|kind| {
+            KINDS
+              .iter()
+              .find(|k| k.eq_ignore_ascii_case(kind))
+              .map(|k| k.to_string())
+              .ok_or_else(|| QueryError::UnknownKind(kind.to_string()))
+          })
+          .collect::<Result<Vec<_>, _>>()?;
+        Term::Kind(kinds)
+      } else if let Some(module) = token.strip_prefix("module:") {
+        Term::Module(glob_to_regex(module)?)
+      } else if let Some(returns) = token.strip_prefix("returns:") {
+        Term::Returns(glob_to_regex(returns)?)
+      } else if let Some(pattern) = token
+        .strip_prefix('/')
+        .and_then(|token| token.strip_suffix('/'))
+        .filter(|pattern| !pattern.is_empty())
+      {
+        Term::QualifiedName(Regex::new(pattern)?)
+      } else if token.contains('.') {
+        Term::QualifiedName(glob_to_regex(&token)?)
+      } else {
+        Term::Name(glob_to_regex(&token)?)
+      };
+      terms.push(if negated {
+        Term::Not(Box::new(term))
+      } else {
+        term
+      });
+    }
+    Ok(Self { terms })
+  }
+}
+
+impl Query {
+  pub fn matches(&self, node: &DocNode, qualified_name: &str) -> bool {
+    self
+      .terms
+      .iter()
+      .all(|term| term.matches(node, qualified_name))
+  }
+
+  /// Selects the nodes matching the query, walking into namespace elements
+  /// and class and interface members.
+  pub fn select(&self, doc_nodes: &[DocNode]) -> Vec<QueryMatch> {
+    let mut matches = Vec::new();
+    for node in doc_nodes {
+      self.select_inner(node, "", &mut matches);
+    }
+    matches
+  }
+
+  fn select_inner(
+    &self,
+    node: &DocNode,
+    parent_name: &str,
+    matches: &mut Vec<QueryMatch>,
+  ) {
+    let qualified_name = if parent_name.is_empty() {
+      node.name.to_string()
+    } else {
+      format!("{}.{}", parent_name, node.name)
+    };
+    if self.matches(node, &qualified_name) {
+      matches.push(QueryMatch {
+        qualified_name: qualified_name.clone(),
+        node: node.clone(),
+      });
+    }
+
+    match &node.def {
+      DocNodeDef::Namespace { namespace_def } => {
+        for element in &namespace_def.elements {
+          self.select_inner(element, &qualified_name, matches);
+        }
+      }
+      DocNodeDef::Class { class_def } => {
+        for method in class_def.methods.iter() {
+          self.select_inner(&method.clone().into(), &qualified_name, matches);
+        }
+        for property in class_def.properties.iter() {
+          self.select_inner(&property.clone().into(), &qualified_name, matches);
+        }
+      }
+      DocNodeDef::Interface { interface_def } => {
+        for method in &interface_def.methods {
+          self.select_inner(&method.clone().into(), &qualified_name, matches);
+        }
+        for property in &interface_def.properties {
+          self.select_inner(&property.clone().into(), &qualified_name, matches);
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+/// Selects the nodes matching `query`, walking into namespace elements and
+/// class and interface members.
+pub fn select_nodes(
+  doc_nodes: &[DocNode],
+  query: &str,
+) -> Result<Vec<QueryMatch>, QueryError> {
+  Ok(query.parse::<Query>()?.select(doc_nodes))
+}
+
+fn tokenize(query: &str) -> Result<Vec<String>, QueryError> {
+  let mut tokens = Vec::new();
+  let mut current = String::new();
+  let mut in_quotes = false;
+  for c in query.chars() {
+    match c {
+      '"' => in_quotes = !in_quotes,
+      c if c.is_whitespace() && !in_quotes => {
+        if !current.is_empty() {
+          tokens.push(std::mem::take(&mut current));
+        }
+      }
+      c => current.push(c),
+    }
+  }
+  if in_quotes {
+    return Err(QueryError::UnterminatedQuote);
+  }
+  if !current.is_empty() {
+    tokens.push(current);
+  }
+  Ok(tokens)
+}
+
+fn glob_to_regex(glob: &str) -> Result<Regex, regex::Error> {
+  let mut pattern = String::with_capacity(glob.len() + 2);
+  pattern.push('^');
+  let mut chars = glob.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        pattern.push_str(".*");
+      }
+      '*' => pattern.push_str("[^.]*"),
+      '?' => pattern.push_str("[^.]"),
+      c => pattern.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+    }
+  }
+  pattern.push('$');
+  Regex::new(&pattern)
+}
+
+fn kind_name(node: &DocNode) -> &'static str {
+  match node.def {
+    DocNodeDef::Class { .. } => "class",
+    DocNodeDef::Enum { .. } => "enum",
+    DocNodeDef::Function { .. } => "function",
+    DocNodeDef::Import { .. } => "import",
+    DocNodeDef::Interface { .. } => "interface",
+    DocNodeDef::ModuleDoc => "moduleDoc",
+    DocNodeDef::Namespace { .. } => "namespace",
+    DocNodeDef::Reference { .. } => "reference",
+    DocNodeDef::TypeAlias { .. } => "typeAlias",
+    DocNodeDef::Variable { .. } => "variable",
+  }
+}
+
+fn tag_name(tag: &JsDocTag) -> &str {
+  match tag {
+    JsDocTag::Callback { .. } => "callback",
+    JsDocTag::Category { .. } => "category",
+    JsDocTag::Constructor => "constructor",
+    JsDocTag::Default { .. } => "default",
+    JsDocTag::Deprecated { .. } => "deprecated",
+    JsDocTag::Enum { .. } => "enum",
+    JsDocTag::Example { .. } => "example",
+    JsDocTag::Experimental => "experimental",
+    JsDocTag::Extends { .. } => "extends",
+    JsDocTag::Ignore => "ignore",
+    JsDocTag::Internal => "internal",
+    JsDocTag::Module { .. } => "module",
+    JsDocTag::Param { .. } => "param",
+    JsDocTag::Public => "public",
+    JsDocTag::Private => "private",
+    JsDocTag::Property { .. } => "property",
+    JsDocTag::Protected => "protected",
+    JsDocTag::ReadOnly => "readonly",
+    JsDocTag::Return { .. } => "return",
+    JsDocTag::Tags { .. } => "tags",
+    JsDocTag::Template { .. } => "template",
+    JsDocTag::This { .. } => "this",
+    JsDocTag::Throws { .. } => "throws",
+    JsDocTag::TypeDef { .. } => "typedef",
+    JsDocTag::TypeRef { .. } => "type",
+    JsDocTag::See { .. } => "see",
+    JsDocTag::Since { .. } => "since",
+    JsDocTag::Unsupported { value } => value
+      .split_whitespace()
+      .next()
+      .and_then(|name| name.strip_prefix('@'))
+      .unwrap_or_default(),
+  }
+}
+
+fn type_matches(ts_type: &TsTypeDef, re: &Regex) -> bool {
+  if let Some(type_ref) = &ts_type.type_ref {
+    if re.is_match(&type_ref.type_name) {
+      return true;
+    }
+    if let Some(type_params) = &type_ref.type_params
+      && type_params.iter().any(|param| type_matches(param, re))
+    {
+      return true;
+    }
+  } else if re.is_match(&ts_type.repr) {
+    return true;
+  }
+
+  let nested = ts_type
+    .union
+    .iter()
+    .chain(ts_type.intersection.iter())
+    .chain(ts_type.tuple.iter())
+    .flatten()
+    .chain(ts_type.array.as_deref())
+    .chain(ts_type.parenthesized.as_deref())
+    .chain(ts_type.optional.as_deref());
+  for ts_type in nested {
+    if type_matches(ts_type, re) {
+      return true;
+    }
+  }
+  false
+}
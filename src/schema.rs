@@ -0,0 +1,479 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! JSON Schema and TypeScript declarations for the serialized [`DocNode`]
+//! format.
+//!
+//! The schema is derived from the serde models, so it always matches what
+//! the parser emits. The TypeScript declarations are generated from the
+//! schema's definitions.
+//!
+//! [`DocNode`]: crate::DocNode
+
+use crate::node::DOC_NODE_FORMAT_VERSION;
+use crate::node::DocNodesDocument;
+use schemars::Schema;
+use schemars::SchemaGenerator;
+use schemars::generate::SchemaSettings;
+use schemars::json_schema;
+use serde_json::Map;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Returns the JSON Schema for [`DocNodesDocument`], the versioned envelope
+/// around the nodes of one or more modules.
+pub fn json_schema() -> Value {
+  let generator = SchemaSettings::draft2020_12().into_generator();
+  let mut schema = generator.into_root_schema_for::<DocNodesDocument>();
+  schema.insert(
+    "$id".to_string(),
+    format!("https://deno.land/x/deno_doc/v{DOC_NODE_FORMAT_VERSION}.json")
+      .into(),
+  );
+  schema.to_value()
+}
+
+/// Returns TypeScript declarations for every type in [`json_schema`].
+pub fn typescript_declarations() -> String {
+  let schema = json_schema();
+  let mut out = String::new();
+  writeln!(
+    out,
+    "/** The version of the serialized format described by these types. */"
+  )
+  .unwrap();
+  writeln!(
+    out,
+    "export declare const DOC_NODE_FORMAT_VERSION: {DOC_NODE_FORMAT_VERSION};"
+  )
+  .unwrap();
+
+  write_declaration(&mut out, "DocNodesDocument", &schema);
+  if let Some(defs) = schema.get("$defs").and_then(Value::as_object) {
+    for (name, def) in defs {
+      write_declaration(&mut out, name, def);
+    }
+  }
+
+  out.push('\n');
+  writeln!(
+    out,
+    "// The variants of the unions above, and the names of the types which are"
+  )
+  .unwrap();
+  writeln!(
+    out,
+    "// shared between classes, interfaces and type literals."
+  )
+  .unwrap();
+  for (name, ts_type) in NAMED_TYPES {
+    writeln!(out, "export type {name} = {ts_type};").unwrap();
+  }
+  out
+}
+
+/// Names for the variants of the generated unions and for the types of the
+/// members which the schema shares, so that they can be imported on their
+/// own.
+const NAMED_TYPES: &[(&str, &str)] = &[
+  ("DocNodeKind", r#"DocNode["kind"]"#),
+  (
+    "DocNodeModuleDoc",
+    r#"Extract<DocNode, { kind: "moduleDoc" }>"#,
+  ),
+  (
+    "DocNodeFunction",
+    r#"Extract<DocNode, { kind: "function" }>"#,
+  ),
+  (
+    "DocNodeVariable",
+    r#"Extract<DocNode, { kind: "variable" }>"#,
+  ),
+  ("DocNodeEnum", r#"Extract<DocNode, { kind: "enum" }>"#),
+  ("DocNodeClass", r#"Extract<DocNode, { kind: "class" }>"#),
+  (
+    "DocNodeTypeAlias",
+    r#"Extract<DocNode, { kind: "typeAlias" }>"#,
+  ),
+  (
+    "DocNodeNamespace",
+    r#"Extract<DocNode, { kind: "namespace" }>"#,
+  ),
+  (
+    "DocNodeInterface",
+    r#"Extract<DocNode, { kind: "interface" }>"#,
+  ),
+  ("DocNodeImport", r#"Extract<DocNode, { kind: "import" }>"#),
+  (
+    "DocNodeReference",
+    r#"Extract<DocNode, { kind: "reference" }>"#,
+  ),
+  (
+    "Accessibility",
+    r#"NonNullable<ClassMethodDef["accessibility"]>"#,
+  ),
+  ("MethodKind", r#"MethodDef["kind"]"#),
+  ("VariableDeclKind", r#"VariableDef["kind"]"#),
+  (
+    "TruePlusMinus",
+    r#"NonNullable<TsMappedTypeDef["readonly"]>"#,
+  ),
+  ("ClassIndexSignatureDef", "IndexSignatureDef"),
+  ("InterfaceCallSignatureDef", "CallSignatureDef"),
+  ("InterfaceIndexSignatureDef", "IndexSignatureDef"),
+  ("InterfaceMethodDef", "MethodDef"),
+  ("InterfacePropertyDef", "PropertyDef"),
+  ("LiteralCallSignatureDef", "CallSignatureDef"),
+  ("LiteralIndexSignatureDef", "IndexSignatureDef"),
+  ("LiteralMethodDef", "MethodDef"),
+  ("LiteralPropertyDef", "PropertyDef"),
+  ("JsDocTagKind", r#"JsDocTag["kind"]"#),
+  ("JsDocTagBase", "{ kind: JsDocTagKind }"),
+  (
+    "JsDocTagOnly",
+    r#"Extract<JsDocTag, { kind: "constructor" | "experimental" | "ignore" | "internal" | "module" | "public" | "private" | "protected" | "readonly" }>"#,
+  ),
+  (
+    "JsDocTagDoc",
+    r#"Extract<JsDocTag, { kind: "deprecated" }>"#,
+  ),
+  (
+    "JsDocTagDocRequired",
+    r#"Extract<JsDocTag, { kind: "category" | "example" | "see" | "since" }>"#,
+  ),
+  (
+    "JsDocTagNamed",
+    r#"Extract<JsDocTag, { kind: "callback" | "template" }>"#,
+  ),
+  (
+    "JsDocTagValued",
+    r#"Extract<JsDocTag, { kind: "default" }>"#,
+  ),
+  (
+    "JsDocTagTyped",
+    r#"Extract<JsDocTag, { kind: "enum" | "extends" | "this" | "type" }>"#,
+  ),
+  (
+    "JsDocTagNamedTyped",
+    r#"Extract<JsDocTag, { kind: "property" | "typedef" }>"#,
+  ),
+  ("JsDocTagParam", r#"Extract<JsDocTag, { kind: "param" }>"#),
+  ("JsDocTagReturn", r#"Extract<JsDocTag, { kind: "return" }>"#),
+  ("JsDocTagTags", r#"Extract<JsDocTag, { kind: "tags" }>"#),
+  (
+    "JsDocTagUnsupported",
+    r#"Extract<JsDocTag, { kind: "unsupported" }>"#,
+  ),
+  (
+    "LiteralDefNumber",
+    r#"LiteralDef & { kind: "number"; number: number }"#,
+  ),
+  (
+    "LiteralDefBigInt",
+    r#"LiteralDef & { kind: "bigInt"; string: string }"#,
+  ),
+  (
+    "LiteralDefString",
+    r#"LiteralDef & { kind: "string"; string: string }"#,
+  ),
+  (
+    "LiteralDefTemplate",
+    r#"LiteralDef & { kind: "template"; tsTypes: TsTypeDef[] }"#,
+  ),
+  (
+    "LiteralDefBoolean",
+    r#"LiteralDef & { kind: "boolean"; boolean: boolean }"#,
+  ),
+  ("ParamArrayDef", r#"Extract<ParamDef, { kind: "array" }>"#),
+  ("ParamAssignDef", r#"Extract<ParamDef, { kind: "assign" }>"#),
+  (
+    "ParamIdentifierDef",
+    r#"Extract<ParamDef, { kind: "identifier" }>"#,
+  ),
+  ("ParamObjectDef", r#"Extract<ParamDef, { kind: "object" }>"#),
+  ("ParamRestDef", r#"Extract<ParamDef, { kind: "rest" }>"#),
+  (
+    "ObjectPatPropAssignDef",
+    r#"Extract<ObjectPatPropDef, { kind: "assign" }>"#,
+  ),
+  (
+    "ObjectPatPropKeyValueDef",
+    r#"Extract<ObjectPatPropDef, { kind: "keyValue" }>"#,
+  ),
+  (
+    "ObjectPatPropRestDef",
+    r#"Extract<ObjectPatPropDef, { kind: "rest" }>"#,
+  ),
+  (
+    "TsTypeKeywordDef",
+    r#"TsTypeDef & { kind: "keyword"; keyword: string }"#,
+  ),
+  (
+    "TsTypeDefLiteral",
+    r#"TsTypeDef & { kind: "literal"; literal: LiteralDef }"#,
+  ),
+  (
+    "TsTypeTypeRefDef",
+    r#"TsTypeDef & { kind: "typeRef"; typeRef: TsTypeRefDef }"#,
+  ),
+  (
+    "TsTypeUnionDef",
+    r#"TsTypeDef & { kind: "union"; union: TsTypeDef[] }"#,
+  ),
+  (
+    "TsTypeIntersectionDef",
+    r#"TsTypeDef & { kind: "intersection"; intersection: TsTypeDef[] }"#,
+  ),
+  (
+    "TsTypeArrayDef",
+    r#"TsTypeDef & { kind: "array"; array: TsTypeDef }"#,
+  ),
+  (
+    "TsTypeTupleDef",
+    r#"TsTypeDef & { kind: "tuple"; tuple: TsTypeDef[] }"#,
+  ),
+  (
+    "TsTypeTypeOperatorDef",
+    r#"TsTypeDef & { kind: "typeOperator"; typeOperator: TsTypeOperatorDef }"#,
+  ),
+  (
+    "TsTypeParenthesizedDef",
+    r#"TsTypeDef & { kind: "parenthesized"; parenthesized: TsTypeDef }"#,
+  ),
+  (
+    "TsTypeRestDef",
+    r#"TsTypeDef & { kind: "rest"; rest: TsTypeDef }"#,
+  ),
+  (
+    "TsTypeOptionalDef",
+    r#"TsTypeDef & { kind: "optional"; optional: TsTypeDef }"#,
+  ),
+  (
+    "TsTypeQueryDef",
+    r#"TsTypeDef & { kind: "typeQuery"; typeQuery: string }"#,
+  ),
+  (
+    "TsTypeThisDef",
+    r#"TsTypeDef & { kind: "this"; this: boolean }"#,
+  ),
+  (
+    "TsTypeFnOrConstructorDef",
+    r#"TsTypeDef & { kind: "fnOrConstructor"; fnOrConstructor: TsFnOrConstructorDef }"#,
+  ),
+  (
+    "TsTypeConditionalDef",
+    r#"TsTypeDef & { kind: "conditional"; conditionalType: TsConditionalDef }"#,
+  ),
+  (
+    "TsTypeInferDef",
+    r#"TsTypeDef & { kind: "infer"; infer: TsInferDef }"#,
+  ),
+  (
+    "TsTypeIndexedAccessDef",
+    r#"TsTypeDef & { kind: "indexedAccess"; indexedAccess: TsIndexedAccessDef }"#,
+  ),
+  (
+    "TsTypeMappedDef",
+    r#"TsTypeDef & { kind: "mapped"; mappedType: TsMappedTypeDef }"#,
+  ),
+  (
+    "TsTypeTypeLiteralDef",
+    r#"TsTypeDef & { kind: "typeLiteral"; typeLiteral: TsTypeLiteralDef }"#,
+  ),
+  (
+    "TsTypeTypePredicateDef",
+    r#"TsTypeDef & { kind: "typePredicate"; typePredicate: TsTypePredicateDef }"#,
+  ),
+  (
+    "TsTypeImportTypeDef",
+    r#"TsTypeDef & { kind: "importType"; importType: TsImportTypeDef }"#,
+  ),
+];
+
+fn write_declaration(out: &mut String, name: &str, schema: &Value) {
+  out.push('\n');
+  if let Some(description) = schema.get("description").and_then(Value::as_str) {
+    write_js_doc(out, description, "");
+  }
+  writeln!(out, "export type {name} = {};", to_ts(schema, "")).unwrap();
+}
+
+fn write_js_doc(out: &mut String, description: &str, indent: &str) {
+  let mut lines = description.lines();
+  match (lines.next(), lines.next()) {
+    (Some(line), None) => {
+      writeln!(out, "{indent}/** {line} */").unwrap();
+    }
+    _ => {
+      writeln!(out, "{indent}/**").unwrap();
+      for line in description.lines() {
+        if line.is_empty() {
+          writeln!(out, "{indent} *").unwrap();
+        } else {
+          writeln!(out, "{indent} * {line}").unwrap();
+        }
+      }
+      writeln!(out, "{indent} */").unwrap();
+    }
+  }
+}
+
+fn to_ts(schema: &Value, indent: &str) -> String {
+  let object = match schema {
+    Value::Bool(true) => return "unknown".to_string(),
+    Value::Bool(false) => return "never".to_string(),
+    Value::Object(object) => object,
+    _ => return "unknown".to_string(),
+  };
+
+  if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+    return reference.rsplit('/').next().unwrap().to_string();
+  }
+  if let Some(value) = object.get("const") {
+    return value.to_string();
+  }
+  if let Some(values) = object.get("enum").and_then(Value::as_array) {
+    return join_union(values.iter().map(Value::to_string).collect());
+  }
+
+  let mut parts = vec![];
+  if let Some(ty) = object.get("type") {
+    parts.push(match ty {
+      Value::Array(types) => join_union(
+        types
+          .iter()
+          .filter_map(Value::as_str)
+          .map(|ty| primitive_to_ts(ty, object, indent))
+          .collect(),
+      ),
+      Value::String(ty) => primitive_to_ts(ty, object, indent),
+      _ => "unknown".to_string(),
+    });
+  }
+  for key in ["oneOf", "anyOf"] {
+    if let Some(variants) = object.get(key).and_then(Value::as_array) {
+      let union = join_union(
+        variants
+          .iter()
+          .map(|variant| to_ts(variant, indent))
+          .collect(),
+      );
+      parts.push(if parts.is_empty() {
+        union
+      } else {
+        format!("({union})")
+      });
+    }
+  }
+  if let Some(all_of) = object.get("allOf").and_then(Value::as_array) {
+    parts.extend(all_of.iter().map(|item| to_ts(item, indent)));
+  }
+
+  if parts.is_empty() {
+    "unknown".to_string()
+  } else {
+    parts.join(" & ")
+  }
+}
+
+fn primitive_to_ts(
+  ty: &str,
+  object: &Map<String, Value>,
+  indent: &str,
+) -> String {
+  match ty {
+    "string" => "string".to_string(),
+    "integer" | "number" => "number".to_string(),
+    "boolean" => "boolean".to_string(),
+    "null" => "null".to_string(),
+    "array" => match object.get("items") {
+      Some(items) => {
+        let item = to_ts(items, indent);
+        if item.contains(' ') {
+          format!("({item})[]")
+        } else {
+          format!("{item}[]")
+        }
+      }
+      None => "unknown[]".to_string(),
+    },
+    "object" => object_to_ts(object, indent),
+    _ => "unknown".to_string(),
+  }
+}
+
+fn object_to_ts(object: &Map<String, Value>, indent: &str) -> String {
+  let properties = object.get("properties").and_then(Value::as_object);
+  let additional = object
+    .get("additionalProperties")
+    .filter(|value| !matches!(value, Value::Bool(false)));
+
+  let Some(properties) = properties.filter(|p| !p.is_empty()) else {
+    return match additional {
+      Some(value) => format!("Record<string, {}>", to_ts(value, indent)),
+      None => "Record<string, never>".to_string(),
+    };
+  };
+
+  let required: Vec<&str> = object
+    .get("required")
+    .and_then(Value::as_array)
+    .map(|required| required.iter().filter_map(Value::as_str).collect())
+    .unwrap_or_default();
+  let inner_indent = format!("{indent}  ");
+
+  let mut out = String::from("{\n");
+  for (name, property) in properties {
+    if let Some(description) =
+      property.get("description").and_then(Value::as_str)
+    {
+      write_js_doc(&mut out, description, &inner_indent);
+    }
+    let optional = if required.contains(&name.as_str()) {
+      ""
+    } else {
+      "?"
+    };
+    writeln!(
+      out,
+      "{inner_indent}{name}{optional}: {};",
+      to_ts(property, &inner_indent)
+    )
+    .unwrap();
+  }
+  write!(out, "{indent}}}").unwrap();
+  out
+}
+
+fn join_union(mut variants: Vec<String>) -> String {
+  variants.dedup();
+  variants.join(" | ")
+}
+
+// Schemas for the swc types that are serialized directly as part of the doc
+// nodes.
+
+pub(crate) fn accessibility(_: &mut SchemaGenerator) -> Schema {
+  json_schema!({
+    "enum": ["public", "protected", "private", null]
+  })
+}
+
+pub(crate) fn method_kind(_: &mut SchemaGenerator) -> Schema {
+  json_schema!({
+    "type": "string",
+    "enum": ["method", "getter", "setter"]
+  })
+}
+
+pub(crate) fn var_decl_kind(_: &mut SchemaGenerator) -> Schema {
+  json_schema!({
+    "type": "string",
+    "enum": ["var", "let", "const"]
+  })
+}
+
+pub(crate) fn true_plus_minus(_: &mut SchemaGenerator) -> Schema {
+  json_schema!({
+    "enum": [true, "+", "-", null]
+  })
+}
@@ -1,9 +1,11 @@
 // Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
 
+use crate::DocCache;
 use crate::DocParserOptions;
 use crate::node::DocNodeDef;
 use crate::parser::DocParser;
 use crate::printer::DocPrinter;
+use crate::printer::DocPrinterOrder;
 use deno_graph::BuildOptions;
 use deno_graph::GraphKind;
 use deno_graph::ModuleGraph;
@@ -51,6 +53,19 @@ pub(crate) async fn setup<S: AsRef<str> + Copy>(
   (graph, analyzer, root)
 }
 
+/// Creates an empty directory for a test, unique to the test run so that
+/// concurrent runs don't share it.
+fn create_temp_dir(name: &str) -> std::path::PathBuf {
+  let nanos = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap()
+    .as_nanos();
+  let dir = std::env::temp_dir()
+    .join(format!("deno_doc_{name}_{}_{nanos}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  dir
+}
+
 #[tokio::test]
 async fn content_type_handling() {
   let sources = vec![(
@@ -169,6 +184,54 @@ async fn types_header_handling() {
   );
 }
 
+#[tokio::test]
+async fn parse_sources_in_memory() {
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let sources = indexmap::IndexMap::from([
+    (
+      root.clone(),
+      r#"export { b } from "./b.ts";
+/** JSDoc for a */
+export const a = 1;"#
+        .to_string(),
+    ),
+    (
+      ModuleSpecifier::parse("file:///b.ts").unwrap(),
+      "/** JSDoc for b */\nexport function b(): void {}".to_string(),
+    ),
+  ]);
+  let doc_nodes_by_url = crate::parse_sources(
+    std::slice::from_ref(&root),
+    sources,
+    DocParserOptions::default(),
+  )
+  .await
+  .unwrap();
+  assert_eq!(doc_nodes_by_url.len(), 1);
+  let mut names = doc_nodes_by_url[&root]
+    .iter()
+    .map(|node| node.name.to_string())
+    .collect::<Vec<_>>();
+  names.sort();
+  assert_eq!(names, vec!["a", "b"]);
+}
+
+#[tokio::test]
+async fn parse_sources_missing_module() {
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let sources = indexmap::IndexMap::from([(
+    root.clone(),
+    r#"export { b } from "./b.ts";"#.to_string(),
+  )]);
+  let result = crate::parse_sources(
+    std::slice::from_ref(&root),
+    sources,
+    DocParserOptions::default(),
+  )
+  .await;
+  assert!(result.is_err());
+}
+
 #[test]
 fn location_byte_index_serde_default() {
   serde_json::from_value::<crate::Location>(json!({
@@ -683,6 +746,367 @@ export namespace Deno {
   assert_eq!(found.len(), 0);
 }
 
+#[tokio::test]
+async fn select_nodes_by_query() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"
+/** @category Net */
+export function connect(): Promise<Conn> {}
+/** @deprecated */
+export function listen(): Conn {}
+/** @experimental */
+export interface Conn {
+  close(): void;
+  read(): Promise<number>;
+  remoteAddr: string;
+}
+export namespace Deno {
+  export const version = "1";
+  export function exit(): never {}
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    &[specifier],
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap()
+  .into_values()
+  .next()
+  .unwrap();
+
+  let select = |query: &str| {
+    crate::query::select_nodes(&entries, query)
+      .unwrap()
+      .into_iter()
+      .map(|m| m.qualified_name)
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(select("Conn.close"), vec!["Conn.close"]);
+  assert_eq!(select("Deno.*"), vec!["Deno.version", "Deno.exit"]);
+  assert_eq!(select("/^(connect|listen)$/"), vec!["connect", "listen"]);
+  assert_eq!(select("r* kind:function"), vec!["Conn.read"]);
+  assert_eq!(select("kind:interface,namespace"), vec!["Conn", "Deno"]);
+  assert_eq!(select("@deprecated"), vec!["listen"]);
+  assert_eq!(select("@experimental"), vec!["Conn"]);
+  assert_eq!(select("@category Net"), vec!["connect"]);
+  assert_eq!(select("returns:Conn"), vec!["connect", "listen"]);
+  assert_eq!(
+    select("returns:Promise !kind:variable"),
+    vec!["connect", "Conn.read"]
+  );
+  assert_eq!(select("module:file:///*.ts kind:namespace"), vec!["Deno"]);
+  assert_eq!(select("Conn.** kind:variable"), vec!["Conn.remoteAddr"]);
+
+  assert!(crate::query::select_nodes(&entries, "kind:nope").is_err());
+  assert!(crate::query::select_nodes(&entries, "@category").is_err());
+  assert!(crate::query::select_nodes(&entries, "/(/").is_err());
+}
+
+#[tokio::test]
+async fn visit_doc_nodes() {
+  use crate::js_doc::JsDocTag;
+  use crate::node::NamespaceDef;
+  use crate::ts_type::TsTypeRefDef;
+  use crate::visit::Visit;
+  use crate::visit::VisitMut;
+  use crate::visit::walk_mut_namespace_def;
+  use crate::visit::walk_ts_type_ref_def;
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"
+export class A extends Base implements I<Set<string>> {
+  method({ a }: { a: Map<string, B> }): Promise<C[]> {}
+}
+export namespace N {
+  /** @internal */
+  export function internal(): D {}
+  export function external(): E {}
+}
+"#,
+    )],
+  )
+  .await;
+  let mut entries = DocParser::new(
+    &graph,
+    &analyzer,
+    &[specifier],
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap()
+  .into_values()
+  .next()
+  .unwrap();
+
+  #[derive(Default)]
+  struct TypeRefCollector(Vec<String>);
+
+  impl Visit for TypeRefCollector {
+    fn visit_ts_type_ref_def(&mut self, node: &TsTypeRefDef) {
+      self.0.push(node.type_name.clone());
+      walk_ts_type_ref_def(self, node);
+    }
+  }
+
+  struct InternalStripper;
+
+  impl VisitMut for InternalStripper {
+    fn visit_mut_namespace_def(&mut self, node: &mut NamespaceDef) {
+      node.elements.retain(|element| {
+        !element
+          .js_doc
+          .tags
+          .iter()
+          .any(|tag| matches!(tag, JsDocTag::Internal))
+      });
+      walk_mut_namespace_def(self, node);
+    }
+  }
+
+  for entry in &mut entries {
+    InternalStripper.visit_mut_doc_node(entry);
+  }
+
+  let mut collector = TypeRefCollector::default();
+  for entry in &entries {
+    collector.visit_doc_node(entry);
+  }
+  collector.0.sort();
+  assert_eq!(
+    collector.0,
+    vec!["B", "C", "E", "I", "Map", "Promise", "Set"]
+  );
+}
+
+#[cfg(feature = "schema")]
+#[tokio::test]
+async fn json_schema() {
+  use crate::node::DocNodesDocument;
+
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      "export class A { private readonly a = 1; get b(): string {} }",
+    )],
+  )
+  .await;
+  let nodes = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&specifier),
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap()
+  .shift_remove(&specifier)
+  .unwrap();
+  let document = DocNodesDocument::new(
+    [(specifier.to_string(), nodes)].into_iter().collect(),
+  );
+  let actual = serde_json::to_value(&document).unwrap();
+  assert_eq!(actual["version"], crate::node::DOC_NODE_FORMAT_VERSION);
+
+  let schema = crate::schema::json_schema();
+  let defs = schema["$defs"].as_object().unwrap();
+  for name in ["DocNode", "ClassDef", "TsTypeDef", "JsDoc", "ParamDef"] {
+    assert!(defs.contains_key(name), "missing definition {name}");
+  }
+  assert_eq!(schema["required"], json!(["version", "nodes"]));
+
+  let dts = crate::schema::typescript_declarations();
+  assert!(dts.contains("export declare const DOC_NODE_FORMAT_VERSION: 1;"));
+  assert!(dts.contains("export type DocNodesDocument = {"));
+  assert!(dts.contains(
+    "  accessibility?: \"public\" | \"protected\" | \"private\" | null;"
+  ));
+  assert!(dts.contains("  kind: \"method\" | \"getter\" | \"setter\";"));
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn js_types_match_schema() {
+  let path =
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("js/types.d.ts");
+  let expected = format!(
+    "// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.\n\
+     // Generated by `deno_doc::schema::typescript_declarations`. Run\n\
+     // `UPDATE=1 cargo test --features schema js_types_match_schema` to update.\n\n{}",
+    crate::schema::typescript_declarations()
+  );
+  if std::env::var("UPDATE").as_deref() == Ok("1") {
+    std::fs::write(&path, &expected).unwrap();
+  }
+  let actual = std::fs::read_to_string(&path).unwrap();
+  assert!(
+    actual == expected,
+    "js/types.d.ts is out of date with the schema, run `UPDATE=1 cargo test \
+     --features schema js_types_match_schema` to update it"
+  );
+
+  // js/mod.ts re-exports every type, so removing one breaks its importers
+  let mut exported = actual
+    .lines()
+    .filter_map(|line| {
+      line
+        .strip_prefix("export type ")
+        .or_else(|| line.strip_prefix("export declare const "))
+    })
+    .map(|line| line.split([' ', ':']).next().unwrap())
+    .collect::<Vec<_>>();
+  exported.sort();
+  assert_eq!(
+    exported,
+    [
+      "Accessibility",
+      "CallSignatureDef",
+      "ClassConstructorDef",
+      "ClassConstructorParamDef",
+      "ClassDef",
+      "ClassIndexSignatureDef",
+      "ClassMethodDef",
+      "ClassPropertyDef",
+      "ConstructorDef",
+      "DOC_NODE_FORMAT_VERSION",
+      "DeclarationKind",
+      "DecoratorDef",
+      "DocNode",
+      "DocNodeClass",
+      "DocNodeEnum",
+      "DocNodeFunction",
+      "DocNodeImport",
+      "DocNodeInterface",
+      "DocNodeKind",
+      "DocNodeModuleDoc",
+      "DocNodeNamespace",
+      "DocNodeReference",
+      "DocNodeTypeAlias",
+      "DocNodeVariable",
+      "DocNodesDocument",
+      "EnumDef",
+      "EnumMemberDef",
+      "FunctionDef",
+      "ImportDef",
+      "IndexSignatureDef",
+      "InterfaceCallSignatureDef",
+      "InterfaceDef",
+      "InterfaceIndexSignatureDef",
+      "InterfaceMethodDef",
+      "InterfacePropertyDef",
+      "JsDoc",
+      "JsDocTag",
+      "JsDocTagBase",
+      "JsDocTagDoc",
+      "JsDocTagDocRequired",
+      "JsDocTagKind",
+      "JsDocTagNamed",
+      "JsDocTagNamedTyped",
+      "JsDocTagOnly",
+      "JsDocTagParam",
+      "JsDocTagReturn",
+      "JsDocTagTags",
+      "JsDocTagTyped",
+      "JsDocTagUnsupported",
+      "JsDocTagValued",
+      "LiteralCallSignatureDef",
+      "LiteralDef",
+      "LiteralDefBigInt",
+      "LiteralDefBoolean",
+      "LiteralDefKind",
+      "LiteralDefNumber",
+      "LiteralDefString",
+      "LiteralDefTemplate",
+      "LiteralIndexSignatureDef",
+      "LiteralMethodDef",
+      "LiteralPropertyDef",
+      "Location",
+      "MethodDef",
+      "MethodKind",
+      "NamespaceDef",
+      "ObjectPatPropAssignDef",
+      "ObjectPatPropDef",
+      "ObjectPatPropKeyValueDef",
+      "ObjectPatPropRestDef",
+      "ParamArrayDef",
+      "ParamAssignDef",
+      "ParamDef",
+      "ParamIdentifierDef",
+      "ParamObjectDef",
+      "ParamRestDef",
+      "PropertyDef",
+      "ReferenceDef",
+      "ThisOrIdent",
+      "TruePlusMinus",
+      "TsConditionalDef",
+      "TsFnOrConstructorDef",
+      "TsImportTypeDef",
+      "TsIndexedAccessDef",
+      "TsInferDef",
+      "TsMappedTypeDef",
+      "TsTypeArrayDef",
+      "TsTypeConditionalDef",
+      "TsTypeDef",
+      "TsTypeDefKind",
+      "TsTypeDefLiteral",
+      "TsTypeFnOrConstructorDef",
+      "TsTypeImportTypeDef",
+      "TsTypeIndexedAccessDef",
+      "TsTypeInferDef",
+      "TsTypeIntersectionDef",
+      "TsTypeKeywordDef",
+      "TsTypeLiteralDef",
+      "TsTypeMappedDef",
+      "TsTypeOperatorDef",
+      "TsTypeOptionalDef",
+      "TsTypeParamDef",
+      "TsTypeParenthesizedDef",
+      "TsTypePredicateDef",
+      "TsTypeQueryDef",
+      "TsTypeRefDef",
+      "TsTypeRestDef",
+      "TsTypeThisDef",
+      "TsTypeTupleDef",
+      "TsTypeTypeLiteralDef",
+      "TsTypeTypeOperatorDef",
+      "TsTypeTypePredicateDef",
+      "TsTypeTypeRefDef",
+      "TsTypeUnionDef",
+      "TypeAliasDef",
+      "VariableDeclKind",
+      "VariableDef",
+    ]
+  );
+
+  // the version is also a value, which js/mod.ts provides at runtime
+  let mod_ts = std::fs::read_to_string(
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("js/mod.ts"),
+  )
+  .unwrap();
+  assert!(mod_ts.contains(&format!(
+    "export const DOC_NODE_FORMAT_VERSION = {};",
+    crate::node::DOC_NODE_FORMAT_VERSION
+  )));
+}
+
 #[tokio::test]
 async fn exports_imported_earlier() {
   let foo_source_code = r#"export const foo: string = "foo";"#;
@@ -971,6 +1395,90 @@ async fn exports_imported_earlier_private() {
   assert_eq!(actual, expected_json);
 }
 
+#[tokio::test]
+async fn printer_order() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"
+/** @category B */
+export interface Zeta {
+  b: string;
+  a(): void;
+}
+/** @category A */
+export const beta = 1;
+export function alpha() {}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    &[specifier],
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap()
+  .into_values()
+  .next()
+  .unwrap();
+
+  let positions = |output: String, needles: &[&str]| {
+    needles
+      .iter()
+      .map(|needle| output.find(needle).unwrap())
+      .collect::<Vec<_>>()
+  };
+  let is_sorted = |positions: Vec<usize>| positions.is_sorted();
+  let symbols = &["function alpha", "const beta", "interface Zeta"];
+
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert!(is_sorted(positions(output, symbols)));
+
+  let output = DocPrinter::new(&entries, false, false)
+    .with_order(DocPrinterOrder::Source)
+    .to_string();
+  assert!(is_sorted(positions(
+    output,
+    &["interface Zeta", "const beta", "function alpha"]
+  )));
+
+  let output = DocPrinter::new(&entries, false, false)
+    .with_order(DocPrinterOrder::Alphabetical)
+    .to_string();
+  assert!(is_sorted(positions(
+    output,
+    &["interface Zeta", "function alpha", "const beta"]
+  )));
+
+  let output = DocPrinter::new(&entries, false, false)
+    .with_order(DocPrinterOrder::Category)
+    .to_string();
+  assert!(is_sorted(positions(
+    output,
+    &["const beta", "interface Zeta", "function alpha"]
+  )));
+
+  let output = DocPrinter::new(&entries, false, false).to_string();
+  assert!(is_sorted(positions(
+    output,
+    &["  b: string", "  a(): void"]
+  )));
+
+  let output = DocPrinter::new(&entries, false, false)
+    .with_member_order(DocPrinterOrder::Alphabetical)
+    .to_string();
+  assert!(is_sorted(positions(
+    output,
+    &["  a(): void", "  b: string"]
+  )));
+}
+
 #[tokio::test]
 async fn variable_syntax() {
   let (graph, analyzer, specifier) = setup(
@@ -1101,12 +1609,30 @@ async fn json_module() {
               "computed": false,
               "optional": false,
               "tsType": {
-                "repr": "unknown[]",
+                "repr": "",
                 "kind": "array",
                 "array": {
-                  "repr": "unknown",
-                  "kind": "keyword",
-                  "keyword": "unknown",
+                  "repr": "",
+                  "kind": "parenthesized",
+                  "parenthesized": {
+                    "repr": "",
+                    "kind": "union",
+                    "union": [{
+                      "repr": "1",
+                      "kind": "literal",
+                      "literal": {
+                        "kind": "number",
+                        "number": 1.0,
+                      },
+                    }, {
+                      "repr": "2",
+                      "kind": "literal",
+                      "literal": {
+                        "kind": "number",
+                        "number": 2.0,
+                      },
+                    }],
+                  },
                 },
               },
               "typeParams": []
@@ -1158,7 +1684,8 @@ async fn json_module() {
             "indexSignatures": [],
           },
         },
-        "kind": "var",
+        "kind": "const",
+        "valuePreview": r#"{"a":5,"b":"text","c":null,"d":[1,2],"e":{"a":1}}"#,
       },
     },
   ]);
@@ -1166,6 +1693,808 @@ async fn json_module() {
   assert_eq!(actual, expected_json);
 }
 
+#[tokio::test]
+async fn json_module_types() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        "export { default as config } from './config.json' with { type: 'json' };",
+      ),
+      (
+        "file:///config.json",
+        None,
+        r#"{
+  "tags": ["a", "b", "a"],
+  "mixed": [1, "a", { "b": true }, null],
+  "versions": ["1.0.0", "1.1.0", "1.2.0", "2.0.0", "2.1.0", 3],
+  "empty": [],
+  "records": [
+    { "id": 1, "name": "a", "tags": ["x"] },
+    { "id": 2, "name": "b", "tags": ["y", "z"] },
+    { "id": 3, "name": "c", "tags": [] }
+  ],
+  "shapes": [{ "a": 1 }, { "b": 2 }, { "c": 3 }, { "d": 4 }, { "e": 5 }],
+  "a": { "b": { "c": { "d": { "e": 1 } }, "list": [[[1]]] } },
+  "description": "a description long enough to truncate the preview of the value"
+}"#,
+      ),
+    ],
+  )
+  .await;
+
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&specifier),
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap();
+  let node = &entries[&specifier][0];
+  assert_eq!(&*node.name, "config");
+
+  let variable_def = node.variable_def().unwrap();
+  assert_eq!(variable_def.kind, deno_ast::swc::ast::VarDeclKind::Const);
+  let properties = &variable_def
+    .ts_type
+    .as_ref()
+    .unwrap()
+    .type_literal
+    .as_ref()
+    .unwrap()
+    .properties;
+  let types = properties
+    .iter()
+    .map(|property| {
+      (
+        property.name.as_str(),
+        console_static_text::ansi::strip_ansi_codes(
+          &property.ts_type.as_ref().unwrap().to_string(),
+        )
+        .to_string(),
+      )
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    types,
+    vec![
+      ("tags", r#"("a" | "b")[]"#.to_string()),
+      ("mixed", r#"(1 | "a" | { b: boolean; } | null)[]"#.to_string()),
+      // widened past the number of literals
+      ("versions", "(string | number)[]".to_string()),
+      ("empty", "unknown[]".to_string()),
+      // the records are typed by their shape
+      (
+        "records",
+        "({ id: number; name: string; tags: string[]; } | { id: number; name: string; tags: unknown[]; })[]"
+          .to_string()
+      ),
+      // and their distinct shapes are capped like the literals
+      ("shapes", "unknown[]".to_string()),
+      // typed up to the depth limit
+      (
+        "a",
+        "{ b: { c: { d: Record<string, unknown>; }; list: unknown[][]; }; }"
+          .to_string()
+      ),
+      (
+        "description",
+        r#""a description long enough to truncate the preview of the value""#
+          .to_string()
+      ),
+    ]
+  );
+
+  assert!(node.js_doc.is_empty());
+  assert_eq!(
+    variable_def.value_preview.as_deref().unwrap(),
+    r#"{"tags":["a","b","a"],"mixed":[1,"a",{"b":true},null],"versions":["1.0.0","1.1.0…"#
+  );
+  let output = DocPrinter::new(&entries[&specifier], false, false).to_string();
+  assert!(output.contains(r#"; } = {"tags":["a","b","a"],"#));
+
+  // only arrays of keywords have a repr, as for the other array types
+  let reprs = properties
+    .iter()
+    .map(|property| property.ts_type.as_ref().unwrap().repr.as_str())
+    .collect::<Vec<_>>();
+  assert_eq!(reprs[..4], ["", "", "", "unknown[]"]);
+}
+
+#[tokio::test]
+async fn wasm_module() {
+  // (module
+  //   (func (export "add") (param $lhs i32) (param $rhs i64) (result i64)
+  //     local.get $rhs))
+  let wasm = [
+    "\0asm\x01\0\0\0",
+    // type section
+    "\x01\x07\x01\x60\x02\x7f\x7e\x01\x7e",
+    // function section
+    "\x03\x02\x01\0",
+    // export section
+    "\x07\x07\x01\x03add\0\0",
+    // code section
+    "\x0a\x06\x01\x04\0\x20\x01\x0b",
+    // name section with the local names subsection
+    "\0\x14\x04name\x02\x0d\x01\0\x02\0\x03lhs\x01\x03rhs",
+  ]
+  .concat();
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"export { add } from "./add.wasm";"#,
+      ),
+      ("file:///add.wasm", None, &wasm),
+    ],
+  )
+  .await;
+  graph.valid().unwrap();
+
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&specifier),
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap();
+  let node = &entries[&specifier][0];
+  assert_eq!(&*node.name, "add");
+  assert_eq!(&*node.location.filename, "file:///add.wasm");
+  assert_eq!(
+    console_static_text::ansi::strip_ansi_codes(
+      &crate::DocPrinter::new(std::slice::from_ref(node), false, false)
+        .to_string()
+    )
+    .lines()
+    .nth(2)
+    .unwrap()
+    .trim(),
+    "function add(lhs: number, rhs: bigint | number): bigint"
+  );
+}
+
+#[tokio::test]
+async fn wasm_module_exports() {
+  async fn function_signatures(source: &str, wasm: &str) -> Vec<String> {
+    let (graph, analyzer, specifier) = setup(
+      "file:///mod.ts",
+      vec![
+        ("file:///mod.ts", None, source),
+        ("file:///mod.wasm", None, wasm),
+      ],
+    )
+    .await;
+    graph.valid().unwrap();
+
+    let entries = DocParser::new(
+      &graph,
+      &analyzer,
+      std::slice::from_ref(&specifier),
+      DocParserOptions::default(),
+    )
+    .unwrap()
+    .parse()
+    .unwrap();
+    entries[&specifier]
+      .iter()
+      .filter(|node| node.function_def().is_some())
+      .map(|node| {
+        console_static_text::ansi::strip_ansi_codes(
+          &crate::DocPrinter::new(std::slice::from_ref(node), false, false)
+            .to_string(),
+        )
+        .lines()
+        .nth(2)
+        .unwrap()
+        .trim()
+        .to_string()
+      })
+      .collect()
+  }
+
+  // an export which isn't a valid identifier is declared on two lines
+  //
+  // (module
+  //   (func (export "my-add") (param $lhs i32) (param $rhs i32) (result i32)
+  //     local.get $lhs)
+  //   (func (export "sub") (param $a i32) (param $b i32) (result i32)
+  //     local.get $b))
+  let wasm = [
+    "\0asm\x01\0\0\0",
+    // type section
+    "\x01\x07\x01\x60\x02\x7f\x7f\x01\x7f",
+    // function section
+    "\x03\x03\x02\0\0",
+    // export section
+    "\x07\x10\x02\x06my-add\0\0\x03sub\0\x01",
+    // code section
+    "\x0a\x0b\x02\x04\0\x20\0\x0b\x04\0\x20\x01\x0b",
+    // name section with the local names subsection
+    "\0\x1c\x04name\x02\x15\x02\0\x02\0\x03lhs\x01\x03rhs\x01\x02\0\x01a\x01\x01b",
+  ]
+  .concat();
+  assert_eq!(
+    function_signatures(
+      r#"export { "my-add" as myAdd, sub } from "./mod.wasm";"#,
+      &wasm,
+    )
+    .await,
+    vec![
+      "function myAdd(lhs: number, rhs: number): number",
+      "function sub(a: number, b: number): number",
+    ]
+  );
+
+  // (module
+  //   (memory (export "memory") 1)
+  //   (func (export "add") (param $lhs i32) (param $rhs i32) (result i32)
+  //     local.get $lhs))
+  let wasm = [
+    "\0asm\x01\0\0\0",
+    // type section
+    "\x01\x07\x01\x60\x02\x7f\x7f\x01\x7f",
+    // function section
+    "\x03\x02\x01\0",
+    // memory section
+    "\x05\x03\x01\0\x01",
+    // export section
+    "\x07\x10\x02\x06memory\x02\0\x03add\0\0",
+    // code section
+    "\x0a\x06\x01\x04\0\x20\0\x0b",
+    // name section with the local names subsection
+    "\0\x14\x04name\x02\x0d\x01\0\x02\0\x03lhs\x01\x03rhs",
+  ]
+  .concat();
+  assert_eq!(
+    function_signatures(r#"export * from "./mod.wasm";"#, &wasm).await,
+    vec!["function add(lhs: number, rhs: number): number"]
+  );
+}
+
+#[tokio::test]
+async fn accessors() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"
+export class Foo {
+  /** The value. */
+  get value(): string {
+    return "";
+  }
+  /**
+   * Also accepts numbers.
+   * @deprecated
+   */
+  set value(value: string | number) {}
+  get readonlyValue(): number {
+    return 0;
+  }
+  static set writeonlyValue(value: boolean) {}
+}
+
+export interface Bar {
+  get size(): number;
+  set size(value: number);
+}
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&specifier),
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap();
+  let entries = &entries[&specifier];
+
+  let class_accessors = entries[0].class_def().unwrap().accessors();
+  assert_eq!(class_accessors.len(), 3);
+  let value = &class_accessors[0];
+  assert_eq!(&*value.name, "value");
+  assert!(!value.is_readonly() && !value.is_writeonly());
+  assert!(value.has_divergent_types());
+  assert_eq!(value.ts_type().unwrap().repr, "string");
+  assert_eq!(
+    value.js_doc.doc.as_deref(),
+    Some("The value.\n\nAlso accepts numbers.")
+  );
+  assert_eq!(
+    &*value.js_doc.tags,
+    [crate::js_doc::JsDocTag::Deprecated { doc: None }]
+  );
+  assert!(class_accessors[1].is_readonly());
+  assert!(class_accessors[2].is_writeonly() && class_accessors[2].is_static);
+
+  let interface_accessors = entries[1].interface_def().unwrap().accessors();
+  assert_eq!(interface_accessors.len(), 1);
+  assert!(!interface_accessors[0].has_divergent_types());
+
+  let output = console_static_text::ansi::strip_ansi_codes(
+    &DocPrinter::new(entries, false, false).to_string(),
+  )
+  .to_string();
+  assert!(output.contains(
+    "  value: string (set: string | number)\n    The value.\n    \n    Also accepts numbers.\n\n    @deprecated\n"
+  ));
+  assert!(output.contains("  readonly readonlyValue: number\n"));
+  assert!(output.contains("  static writeonly writeonlyValue: boolean\n"));
+  assert!(output.contains("  size: number\n"));
+}
+
+#[tokio::test]
+async fn extract_modules() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![
+      (
+        "file:///mod.ts",
+        None,
+        r#"
+export * from "./a.ts";
+export { B } from "./b.ts";
+/** The main class. */
+export class Main extends B {}
+"#,
+      ),
+      (
+        "file:///a.ts",
+        None,
+        r#"
+import type { Private } from "./b.ts";
+/** A function. */
+export function a(value: Private): void {}
+"#,
+      ),
+      (
+        "file:///b.ts",
+        None,
+        r#"
+export * from "./a.ts";
+interface Private {
+  value: string;
+}
+/** A class. */
+export class B {
+  private value: Private;
+}
+"#,
+      ),
+    ],
+  )
+  .await;
+  let specifiers = vec![
+    specifier,
+    ModuleSpecifier::parse("file:///a.ts").unwrap(),
+    ModuleSpecifier::parse("file:///b.ts").unwrap(),
+  ];
+
+  // with the `parallel` feature, the modules are only extracted in parallel
+  // when no diagnostics are collected
+  let parse = |diagnostics: bool| {
+    let entries = DocParser::new(
+      &graph,
+      &analyzer,
+      &specifiers,
+      DocParserOptions {
+        diagnostics,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse()
+    .unwrap();
+    serde_json::to_value(&entries).unwrap()
+  };
+
+  let actual = parse(false);
+  assert_eq!(
+    actual.as_object().unwrap().keys().collect::<Vec<_>>(),
+    vec!["file:///mod.ts", "file:///a.ts", "file:///b.ts"]
+  );
+  assert_eq!(actual, parse(true));
+}
+
+#[tokio::test]
+async fn doc_cache() {
+  let dir = create_temp_dir("cache_test");
+
+  let parse = |dep_source: &'static str, cache: Option<DocCache>| async move {
+    let (graph, analyzer, specifier) = setup(
+      "file:///mod.ts",
+      vec![
+        (
+          "file:///mod.ts",
+          None,
+          r#"
+export * from "./dep.ts";
+/** A namespace. */
+export namespace Ns {
+  export class Foo {
+    bar(): string;
+  }
+}
+"#,
+        ),
+        ("file:///dep.ts", None, dep_source),
+      ],
+    )
+    .await;
+    let entries = DocParser::new(
+      &graph,
+      &analyzer,
+      &[specifier],
+      DocParserOptions {
+        cache,
+        ..Default::default()
+      },
+    )
+    .unwrap()
+    .parse()
+    .unwrap();
+    serde_json::to_value(&entries).unwrap()
+  };
+
+  let cache = DocCache::new(&dir);
+  let expected = parse("export const dep = 1;", None).await;
+  assert_eq!(
+    parse("export const dep = 1;", Some(cache.clone())).await,
+    expected
+  );
+
+  let entries = std::fs::read_dir(&dir)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .collect::<Vec<_>>();
+  assert_eq!(entries.len(), 1);
+  // the names of the entries don't depend on the toolchain
+  assert_eq!(entries[0].file_name().unwrap(), "v1-09454b118bb07f95.json");
+
+  // the nodes are read from the cache while the sources are unchanged
+  let content = std::fs::read_to_string(&entries[0]).unwrap();
+  std::fs::write(&entries[0], content.replace("\"dep\"", "\"cached\""))
+    .unwrap();
+  let actual = parse("export const dep = 1;", Some(cache.clone())).await;
+  let names = actual["file:///mod.ts"]
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|node| node["name"].as_str().unwrap())
+    .collect::<Vec<_>>();
+  assert!(names.contains(&"cached"));
+
+  // and extracted again once a dependency changes
+  let expected = parse("export const dep = 2;", None).await;
+  assert_eq!(parse("export const dep = 2;", Some(cache)).await, expected);
+
+  std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn node_modules() {
+  let dir = create_temp_dir("node_modules_test");
+  for (path, content) in [
+    ("zod/package.json", r#"{ "types": "./lib/index.d.ts" }"#),
+    ("zod/lib/index.d.ts", r#"export * from "./types.js";"#),
+    (
+      "zod/lib/types.d.ts",
+      r#"/** A string schema. */
+export declare class ZodString {}
+/** Creates a string schema. */
+export declare function string(): ZodString;
+"#,
+    ),
+    (
+      "@types/node/fs.d.ts",
+      r#"declare module "fs" {
+  /** Reads the entire contents of a file. */
+  export function readFileSync(path: string): string;
+}
+declare module "node:fs" {
+  export * from "fs";
+}
+"#,
+    ),
+  ] {
+    let path = dir.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+  }
+
+  let memory_loader = MemoryLoader::new(
+    vec![(
+      "file:///mod.ts",
+      Source::Module {
+        specifier: "file:///mod.ts",
+        maybe_headers: None,
+        content: r#"export { string, ZodString } from "npm:zod@^3.22.0";
+export { readFileSync } from "node:fs";
+"#,
+      },
+    )],
+    vec![],
+  );
+  let node_modules = crate::NodeModules::new(&dir);
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let analyzer = create_analyzer();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      vec![root.clone()],
+      Vec::new(),
+      &node_modules.loader(&memory_loader),
+      BuildOptions {
+        module_analyzer: &analyzer,
+        resolver: Some(&node_modules),
+        ..Default::default()
+      },
+    )
+    .await;
+  graph.valid().unwrap();
+
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&root),
+    Default::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap();
+  let mut nodes = entries[&root]
+    .iter()
+    .map(|node| {
+      (
+        node.name.to_string(),
+        node.js_doc.doc.as_deref().unwrap_or_default().to_string(),
+        node
+          .location
+          .filename
+          .rsplit('/')
+          .next()
+          .unwrap()
+          .to_string(),
+        node.location.line,
+      )
+    })
+    .collect::<Vec<_>>();
+  nodes.sort();
+
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  assert_eq!(
+    nodes,
+    vec![
+      (
+        "ZodString".to_string(),
+        "A string schema.".to_string(),
+        "types.d.ts".to_string(),
+        2
+      ),
+      (
+        "readFileSync".to_string(),
+        "Reads the entire contents of a file.".to_string(),
+        "fs.d.ts".to_string(),
+        3
+      ),
+      (
+        "string".to_string(),
+        "Creates a string schema.".to_string(),
+        "types.d.ts".to_string(),
+        4
+      ),
+    ]
+  );
+}
+
 fn create_analyzer() -> CapturingModuleAnalyzer {
   CapturingModuleAnalyzer::default()
 }
+
+#[tokio::test]
+async fn type_expansion() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"
+export interface Base {
+  id: string;
+}
+
+export interface Config extends Base {
+  name: string;
+  readonly port: number;
+  debug?: boolean;
+}
+
+export type Options = Partial<Config>;
+export type Named = Pick<Config, "id" | "name">;
+export type Unnamed = Omit<Config, "name">;
+export type Flags = Record<"a" | "b", boolean>;
+export type Dictionary = Record<string, number>;
+export type Wrapped<T> = { value: T };
+export type WrappedConfig = Readonly<Wrapped<Config>> & { extra: number };
+export type Tree = Tree[];
+export type Cycle = Partial<Cycle>;
+export type Literal = { value: string };
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&specifier),
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap();
+  let entries = &entries[&specifier];
+
+  let resolve = |name: &str, _: Option<&crate::DocNode>| {
+    entries.iter().find(|node| node.get_name() == name)
+  };
+  let expand = |name: &str| {
+    resolve(name, None)
+      .unwrap()
+      .type_alias_def()
+      .unwrap()
+      .ts_type
+      .expand(resolve)
+      .and_then(|ts_type| ts_type.type_literal)
+  };
+  let property_names = |name: &str| {
+    expand(name)
+      .unwrap()
+      .properties
+      .into_iter()
+      .map(|property| property.name)
+      .collect::<Vec<_>>()
+  };
+
+  let options = expand("Options").unwrap();
+  assert_eq!(
+    options
+      .properties
+      .iter()
+      .map(|property| (property.name.as_str(), property.optional))
+      .collect::<Vec<_>>(),
+    [
+      ("id", true),
+      ("name", true),
+      ("port", true),
+      ("debug", true)
+    ]
+  );
+  assert!(options.properties[2].readonly);
+
+  assert_eq!(property_names("Named"), ["id", "name"]);
+  assert_eq!(property_names("Unnamed"), ["id", "port", "debug"]);
+
+  let flags = expand("Flags").unwrap();
+  assert_eq!(
+    flags
+      .properties
+      .iter()
+      .map(|property| property.ts_type.as_ref().unwrap().repr.as_str())
+      .collect::<Vec<_>>(),
+    ["boolean", "boolean"]
+  );
+
+  let dictionary = expand("Dictionary").unwrap();
+  assert!(dictionary.properties.is_empty());
+  assert_eq!(dictionary.index_signatures.len(), 1);
+  assert_eq!(
+    dictionary.index_signatures[0].params[0]
+      .ts_type
+      .as_ref()
+      .unwrap()
+      .repr,
+    "string"
+  );
+
+  let wrapped_config = expand("WrappedConfig").unwrap();
+  assert_eq!(wrapped_config.properties.len(), 2);
+  assert!(wrapped_config.properties[0].readonly);
+  assert_eq!(
+    wrapped_config.properties[0].ts_type.as_ref().unwrap().repr,
+    "Config"
+  );
+  assert_eq!(wrapped_config.properties[1].name, "extra");
+
+  assert!(expand("Tree").is_none());
+  assert!(expand("Cycle").is_none());
+  assert!(expand("Literal").is_none());
+}
+
+#[tokio::test]
+async fn type_expansion_declarations() {
+  let (graph, analyzer, specifier) = setup(
+    "file:///mod.ts",
+    vec![(
+      "file:///mod.ts",
+      None,
+      r#"
+export type Pick<T, K> = { picked: T; keys: K };
+export type Picked = Pick<string, "a">;
+
+export interface Box<T> {
+  value: T;
+  map<T>(value: T): T;
+  get: <T>() => T;
+}
+export type StringBox = Box<string>;
+"#,
+    )],
+  )
+  .await;
+  let entries = DocParser::new(
+    &graph,
+    &analyzer,
+    std::slice::from_ref(&specifier),
+    DocParserOptions::default(),
+  )
+  .unwrap()
+  .parse()
+  .unwrap();
+  let entries = &entries[&specifier];
+
+  let resolve = |name: &str, _: Option<&crate::DocNode>| {
+    entries.iter().find(|node| node.get_name() == name)
+  };
+  let expand = |name: &str| {
+    resolve(name, None)
+      .unwrap()
+      .type_alias_def()
+      .unwrap()
+      .ts_type
+      .expand(resolve)
+      .and_then(|ts_type| ts_type.type_literal)
+      .unwrap()
+  };
+
+  // the declared `Pick` is expanded instead of the utility type
+  let picked = expand("Picked");
+  assert_eq!(
+    picked
+      .properties
+      .iter()
+      .map(|property| (
+        property.name.as_str(),
+        property.ts_type.as_ref().unwrap().repr.as_str()
+      ))
+      .collect::<Vec<_>>(),
+    [("picked", "string"), ("keys", "a")]
+  );
+
+  // the members declaring their own `T` keep referencing it
+  let string_box = expand("StringBox");
+  assert_eq!(
+    string_box.properties[0].ts_type.as_ref().unwrap().repr,
+    "string"
+  );
+  let map = &string_box.methods[0];
+  assert_eq!(map.params[0].ts_type.as_ref().unwrap().repr, "T");
+  assert_eq!(map.return_type.as_ref().unwrap().repr, "T");
+  let get = string_box.properties[1].ts_type.as_ref().unwrap();
+  assert_eq!(get.fn_or_constructor.as_ref().unwrap().ts_type.repr, "T");
+}
@@ -3,8 +3,10 @@
 use crate::DocNode;
 use crate::Location;
 use crate::ParamDef;
+use crate::accessor::AccessorDef;
 use crate::display::SliceDisplayer;
 use crate::display::display_computed;
+use crate::display::display_method;
 use crate::display::display_optional;
 use crate::display::display_readonly;
 use crate::interface::expr_to_name;
@@ -735,6 +737,7 @@ fn ts_entity_name_to_name(entity_name: &TsEntityName) -> String {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsTypeRefDef {
   pub type_params: Option<Box<[TsTypeDef]>>,
@@ -742,6 +745,7 @@ pub struct TsTypeRefDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum LiteralDefKind {
   Number,
@@ -752,6 +756,7 @@ pub enum LiteralDefKind {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LiteralDef {
   pub kind: LiteralDefKind,
@@ -770,6 +775,7 @@ pub struct LiteralDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsTypeOperatorDef {
   pub operator: String,
@@ -777,6 +783,7 @@ pub struct TsTypeOperatorDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsFnOrConstructorDef {
   pub constructor: bool,
@@ -838,6 +845,7 @@ impl TsFnOrConstructorDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsConditionalDef {
   pub check_type: Box<TsTypeDef>,
@@ -847,12 +855,14 @@ pub struct TsConditionalDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsInferDef {
   pub type_param: Box<TsTypeParamDef>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsImportTypeDef {
   pub specifier: String,
@@ -863,6 +873,7 @@ pub struct TsImportTypeDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsIndexedAccessDef {
   pub readonly: bool,
@@ -883,20 +894,30 @@ pub struct TsIndexedAccessDef {
 /// - `ts_type` = `Some(TsTypeDef)` (`Type[Properties]`)
 ///
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsMappedTypeDef {
   #[serde(skip_serializing_if = "Option::is_none", default)]
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::true_plus_minus")
+  )]
   pub readonly: Option<TruePlusMinus>,
   pub type_param: Box<TsTypeParamDef>,
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub name_type: Option<Box<TsTypeDef>>,
   #[serde(skip_serializing_if = "Option::is_none", default)]
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::true_plus_minus")
+  )]
   pub optional: Option<TruePlusMinus>,
   #[serde(skip_serializing_if = "Option::is_none", default)]
   pub ts_type: Option<Box<TsTypeDef>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ConstructorDef {
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
@@ -919,11 +940,16 @@ impl Display for ConstructorDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct MethodDef {
   pub name: String,
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
   pub js_doc: JsDoc,
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::method_kind")
+  )]
   pub kind: MethodKind,
   #[serde(default)]
   pub location: Location,
@@ -961,7 +987,8 @@ impl Display for MethodDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     write!(
       f,
-      "{}{}({})",
+      "{}{}{}({})",
+      display_method(self.kind),
       display_computed(self.computed, &self.name),
       display_optional(self.optional),
       SliceDisplayer::new(&self.params, ", ", false),
@@ -974,6 +1001,7 @@ impl Display for MethodDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PropertyDef {
   pub name: String,
@@ -1001,6 +1029,7 @@ impl From<PropertyDef> for DocNode {
       VariableDef {
         ts_type: def.ts_type,
         kind: VarDeclKind::Const,
+        value_preview: None,
       },
     )
   }
@@ -1023,6 +1052,7 @@ impl Display for PropertyDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CallSignatureDef {
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
@@ -1045,6 +1075,7 @@ impl Display for CallSignatureDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct IndexSignatureDef {
   #[serde(skip_serializing_if = "JsDoc::is_empty", default)]
@@ -1072,6 +1103,7 @@ impl Display for IndexSignatureDef {
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsTypeLiteralDef {
   #[serde(default)]
@@ -1082,7 +1114,15 @@ pub struct TsTypeLiteralDef {
   pub index_signatures: Vec<IndexSignatureDef>,
 }
 
+impl TsTypeLiteralDef {
+  /// The getters and setters of the type literal, merged into properties.
+  pub fn accessors(&self) -> Vec<AccessorDef> {
+    crate::accessor::accessors(&self.methods)
+  }
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum TsTypeDefKind {
   Keyword,
@@ -1109,6 +1149,7 @@ pub enum TsTypeDefKind {
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsTypeDef {
   pub repr: String,
@@ -1180,6 +1221,7 @@ pub struct TsTypeDef {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum ThisOrIdent {
   This,
@@ -1204,6 +1246,7 @@ impl From<&TsThisTypeOrIdent> for ThisOrIdent {
 ///                           (1)     (2)      (3)
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TsTypePredicateDef {
   /// (1) Whether the predicate includes `asserts` keyword or not
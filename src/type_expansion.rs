@@ -0,0 +1,409 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+
+use crate::DocNode;
+use crate::Location;
+use crate::ParamDef;
+use crate::ParamPatternDef;
+use crate::ts_type::CallSignatureDef;
+use crate::ts_type::ConstructorDef;
+use crate::ts_type::IndexSignatureDef;
+use crate::ts_type::LiteralDefKind;
+use crate::ts_type::MethodDef;
+use crate::ts_type::PropertyDef;
+use crate::ts_type::TsFnOrConstructorDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeLiteralDef;
+use crate::ts_type_param::TsTypeParamDef;
+use crate::visit::VisitMut;
+use crate::visit::walk_mut_call_signature_def;
+use crate::visit::walk_mut_constructor_def;
+use crate::visit::walk_mut_method_def;
+use crate::visit::walk_mut_property_def;
+use crate::visit::walk_mut_ts_fn_or_constructor_def;
+use crate::visit::walk_mut_ts_type_def;
+
+/// The maximum number of nested type aliases and interfaces which are
+/// expanded, to bound the work done for deep or recursive types.
+const EXPANSION_MAX_DEPTH: usize = 4;
+
+impl TsTypeDef {
+  /// Expands the type into its structure, resolving the type aliases and
+  /// interfaces it references through `resolve`, which looks up the
+  /// declaration of a type by name. It is given the declaration the name is
+  /// referenced from, or `None` for the references of the type itself, so
+  /// that the names used in the declarations of other modules can be looked
+  /// up in the scope of these modules.
+  ///
+  /// The `Partial`, `Readonly`, `Pick`, `Omit` and `Record` utility types are
+  /// applied to the members of their argument, so that eg. `Partial<Config>`
+  /// expands to a type literal with the optional properties of `Config`,
+  /// unless a declaration of the same name resolves.
+  /// Intersections of types which expand to type literals are merged.
+  ///
+  /// Returns `None` if the type doesn't reference anything which can be
+  /// expanded, or if it can't be expanded within the depth limit, eg. because
+  /// it is recursive.
+  pub fn expand<'a>(
+    &self,
+    resolve: impl Fn(&str, Option<&'a DocNode>) -> Option<&'a DocNode>,
+  ) -> Option<TsTypeDef> {
+    if self.kind == Some(TsTypeDefKind::TypeLiteral) {
+      return None;
+    }
+
+    TypeExpander {
+      resolve: &resolve,
+      stack: vec![],
+    }
+    .expand(self)
+  }
+}
+
+struct TypeExpander<'a, 'b> {
+  resolve: &'b dyn Fn(&str, Option<&'a DocNode>) -> Option<&'a DocNode>,
+  /// The type aliases and interfaces being expanded.
+  stack: Vec<&'a DocNode>,
+}
+
+impl<'a> TypeExpander<'a, '_> {
+  fn expand(&mut self, ts_type: &TsTypeDef) -> Option<TsTypeDef> {
+    match ts_type.kind.as_ref()? {
+      TsTypeDefKind::Parenthesized => {
+        self.expand(ts_type.parenthesized.as_deref()?)
+      }
+      TsTypeDefKind::TypeLiteral => Some(ts_type.clone()),
+      TsTypeDefKind::Intersection => {
+        let mut merged = TsTypeLiteralDef::default();
+        for ts_type in ts_type.intersection.as_ref()? {
+          merge_members(&mut merged, self.members(ts_type)?);
+        }
+        Some(type_literal(merged))
+      }
+      TsTypeDefKind::TypeRef => self.expand_type_ref(ts_type),
+      _ => None,
+    }
+  }
+
+  /// Expands the type into the members of a type literal.
+  fn members(&mut self, ts_type: &TsTypeDef) -> Option<TsTypeLiteralDef> {
+    self.expand(ts_type)?.type_literal
+  }
+
+  /// Expands a reference to a declaration, or to one of the utility types
+  /// when no declaration of that name resolves.
+  fn expand_type_ref(&mut self, ts_type: &TsTypeDef) -> Option<TsTypeDef> {
+    let type_ref = ts_type.type_ref.as_ref()?;
+    let args = type_ref.type_params.as_deref().unwrap_or_default();
+
+    if let Some(node) =
+      (self.resolve)(&type_ref.type_name, self.stack.last().copied())
+    {
+      return self.expand_declaration(node, args);
+    }
+
+    match (type_ref.type_name.as_str(), args) {
+      ("Partial", [ts_type]) => {
+        let mut members = self.members(ts_type)?;
+        for property in &mut members.properties {
+          property.optional = true;
+        }
+        for method in &mut members.methods {
+          method.optional = true;
+        }
+        Some(type_literal(members))
+      }
+      ("Readonly", [ts_type]) => {
+        let mut members = self.members(ts_type)?;
+        for property in &mut members.properties {
+          property.readonly = true;
+        }
+        for index_signature in &mut members.index_signatures {
+          index_signature.readonly = true;
+        }
+        Some(type_literal(members))
+      }
+      ("Pick", [ts_type, keys]) | ("Omit", [ts_type, keys]) => {
+        let is_pick = type_ref.type_name == "Pick";
+        let keys = self.keys(keys)?;
+        let mut members = self.members(ts_type)?;
+        members
+          .properties
+          .retain(|property| keys.contains(&property.name) == is_pick);
+        members
+          .methods
+          .retain(|method| keys.contains(&method.name) == is_pick);
+        if is_pick {
+          members.constructors.clear();
+          members.call_signatures.clear();
+          members.index_signatures.clear();
+        }
+        Some(type_literal(members))
+      }
+      ("Record", [keys, value]) => self.expand_record(keys, value),
+      _ => None,
+    }
+  }
+
+  fn expand_record(
+    &mut self,
+    keys: &TsTypeDef,
+    value: &TsTypeDef,
+  ) -> Option<TsTypeDef> {
+    let mut members = TsTypeLiteralDef::default();
+
+    if let Some(keyword @ ("string" | "number" | "symbol")) =
+      keys.keyword.as_deref()
+    {
+      members.index_signatures.push(IndexSignatureDef {
+        js_doc: Default::default(),
+        readonly: false,
+        params: vec![ParamDef {
+          pattern: ParamPatternDef::Identifier {
+            name: "key".to_string(),
+            optional: false,
+          },
+          decorators: Box::new([]),
+          ts_type: Some(TsTypeDef::keyword(keyword)),
+        }],
+        ts_type: Some(value.clone()),
+        location: Location::default(),
+      });
+    } else {
+      members.properties = self
+        .keys(keys)?
+        .into_iter()
+        .map(|name| PropertyDef {
+          name,
+          js_doc: Default::default(),
+          location: Location::default(),
+          params: vec![],
+          readonly: false,
+          computed: false,
+          optional: false,
+          ts_type: Some(value.clone()),
+          type_params: Box::new([]),
+        })
+        .collect();
+    }
+
+    Some(type_literal(members))
+  }
+
+  /// Expands a type alias or an interface, substituting its type parameters
+  /// with the arguments.
+  fn expand_declaration(
+    &mut self,
+    node: &'a DocNode,
+    args: &[TsTypeDef],
+  ) -> Option<TsTypeDef> {
+    if self.stack.len() >= EXPANSION_MAX_DEPTH {
+      return None;
+    }
+    if self.stack.iter().any(|expanding| {
+      expanding.name == node.name && expanding.location == node.location
+    }) {
+      return None;
+    }
+
+    self.stack.push(node);
+    let expanded = if let Some(type_alias_def) = node.type_alias_def() {
+      let mut ts_type = type_alias_def.ts_type.clone();
+      substitute_type_params(&mut ts_type, &type_alias_def.type_params, args);
+      Some(self.expand(&ts_type).unwrap_or(ts_type))
+    } else if let Some(interface_def) = node.interface_def() {
+      let mut members = TsTypeLiteralDef::default();
+      for extends in &interface_def.extends {
+        if let Some(extends_members) = self.members(extends) {
+          merge_members(&mut members, extends_members);
+        }
+      }
+      merge_members(
+        &mut members,
+        TsTypeLiteralDef {
+          constructors: interface_def.constructors.clone(),
+          methods: interface_def.methods.clone(),
+          properties: interface_def.properties.clone(),
+          call_signatures: interface_def.call_signatures.clone(),
+          index_signatures: interface_def.index_signatures.clone(),
+        },
+      );
+
+      let mut ts_type = type_literal(members);
+      substitute_type_params(&mut ts_type, &interface_def.type_params, args);
+      Some(ts_type)
+    } else {
+      None
+    };
+    self.stack.pop();
+
+    expanded
+  }
+
+  /// Gets the property names of a type used as keys, like the union of
+  /// string literals in `Pick<T, "a" | "b">`.
+  fn keys(&mut self, ts_type: &TsTypeDef) -> Option<Vec<String>> {
+    match ts_type.kind.as_ref()? {
+      TsTypeDefKind::Literal => {
+        let literal = ts_type.literal.as_ref()?;
+        match literal.kind {
+          LiteralDefKind::String => literal.string.clone().map(|key| vec![key]),
+          LiteralDefKind::Number => {
+            literal.number.map(|key| vec![key.to_string()])
+          }
+          _ => None,
+        }
+      }
+      TsTypeDefKind::Union => {
+        let mut keys = vec![];
+        for ts_type in ts_type.union.as_ref()? {
+          keys.extend(self.keys(ts_type)?);
+        }
+        Some(keys)
+      }
+      TsTypeDefKind::Parenthesized => {
+        self.keys(ts_type.parenthesized.as_deref()?)
+      }
+      TsTypeDefKind::TypeOperator => {
+        let type_operator = ts_type.type_operator.as_ref()?;
+        if type_operator.operator != "keyof" {
+          return None;
+        }
+        let members = self.members(&type_operator.ts_type)?;
+        Some(
+          members
+            .properties
+            .into_iter()
+            .map(|property| property.name)
+            .chain(members.methods.into_iter().map(|method| method.name))
+            .collect(),
+        )
+      }
+      TsTypeDefKind::TypeRef => {
+        let expanded = self.expand_type_ref(ts_type)?;
+        self.keys(&expanded)
+      }
+      _ => None,
+    }
+  }
+}
+
+fn type_literal(members: TsTypeLiteralDef) -> TsTypeDef {
+  TsTypeDef {
+    repr: "".to_string(),
+    kind: Some(TsTypeDefKind::TypeLiteral),
+    type_literal: Some(members),
+    ..Default::default()
+  }
+}
+
+/// Adds the members to the type literal, replacing the properties of the
+/// same name.
+fn merge_members(target: &mut TsTypeLiteralDef, members: TsTypeLiteralDef) {
+  target.properties.retain(|property| {
+    !members
+      .properties
+      .iter()
+      .any(|other| other.name == property.name)
+  });
+  target.constructors.extend(members.constructors);
+  target.methods.extend(members.methods);
+  target.properties.extend(members.properties);
+  target.call_signatures.extend(members.call_signatures);
+  target.index_signatures.extend(members.index_signatures);
+}
+
+/// Replaces the references to the type parameters with the arguments, or
+/// their defaults when there are less arguments than parameters. The
+/// references within members and types which declare a type parameter of the
+/// same name refer to that one, so they are kept.
+fn substitute_type_params(
+  ts_type: &mut TsTypeDef,
+  type_params: &[TsTypeParamDef],
+  args: &[TsTypeDef],
+) {
+  struct Substitute<'a>(HashMap<&'a str, &'a TsTypeDef>);
+
+  impl<'a> Substitute<'a> {
+    /// Removes the substitutions of the type parameters, returning them to
+    /// be restored once the node declaring these has been walked.
+    fn shadow(
+      &mut self,
+      type_params: &[TsTypeParamDef],
+    ) -> Vec<(&'a str, &'a TsTypeDef)> {
+      type_params
+        .iter()
+        .filter_map(|type_param| self.0.remove_entry(type_param.name.as_str()))
+        .collect()
+    }
+  }
+
+  impl VisitMut for Substitute<'_> {
+    fn visit_mut_ts_type_def(&mut self, node: &mut TsTypeDef) {
+      if let Some(type_ref) = &node.type_ref
+        && type_ref.type_params.is_none()
+        && let Some(arg) = self.0.get(type_ref.type_name.as_str())
+      {
+        *node = (*arg).clone();
+        return;
+      }
+      let shadowed = match &node.mapped_type {
+        Some(mapped_type) => {
+          self.shadow(std::slice::from_ref(&mapped_type.type_param))
+        }
+        None => vec![],
+      };
+      walk_mut_ts_type_def(self, node);
+      self.0.extend(shadowed);
+    }
+
+    fn visit_mut_ts_fn_or_constructor_def(
+      &mut self,
+      node: &mut TsFnOrConstructorDef,
+    ) {
+      let shadowed = self.shadow(&node.type_params);
+      walk_mut_ts_fn_or_constructor_def(self, node);
+      self.0.extend(shadowed);
+    }
+
+    fn visit_mut_constructor_def(&mut self, node: &mut ConstructorDef) {
+      let shadowed = self.shadow(&node.type_params);
+      walk_mut_constructor_def(self, node);
+      self.0.extend(shadowed);
+    }
+
+    fn visit_mut_method_def(&mut self, node: &mut MethodDef) {
+      let shadowed = self.shadow(&node.type_params);
+      walk_mut_method_def(self, node);
+      self.0.extend(shadowed);
+    }
+
+    fn visit_mut_property_def(&mut self, node: &mut PropertyDef) {
+      let shadowed = self.shadow(&node.type_params);
+      walk_mut_property_def(self, node);
+      self.0.extend(shadowed);
+    }
+
+    fn visit_mut_call_signature_def(&mut self, node: &mut CallSignatureDef) {
+      let shadowed = self.shadow(&node.type_params);
+      walk_mut_call_signature_def(self, node);
+      self.0.extend(shadowed);
+    }
+  }
+
+  let substitutions = type_params
+    .iter()
+    .enumerate()
+    .filter_map(|(i, type_param)| {
+      Some((
+        type_param.name.as_str(),
+        args.get(i).or(type_param.default.as_ref())?,
+      ))
+    })
+    .collect::<HashMap<_, _>>();
+  if !substitutions.is_empty() {
+    Substitute(substitutions).visit_mut_ts_type_def(ts_type);
+  }
+}
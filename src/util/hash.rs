@@ -0,0 +1,35 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Hashes content with SHA-256, for the hashes which are persisted between
+/// runs. Unlike `DefaultHasher`, whose algorithm may change with any Rust
+/// release, the hashes only depend on the bytes written.
+#[derive(Default)]
+pub(crate) struct StableHasher(Sha256);
+
+impl StableHasher {
+  /// Writes the bytes, prefixed by their length so that where one write ends
+  /// and the next begins is part of the hash.
+  pub fn write(&mut self, bytes: impl AsRef<[u8]>) {
+    let bytes = bytes.as_ref();
+    self.0.update((bytes.len() as u64).to_le_bytes());
+    self.0.update(bytes);
+  }
+
+  /// Returns the first 64 bits of the digest, as 16 hex digits.
+  pub fn finish(self) -> String {
+    self.0.finalize()[..8]
+      .iter()
+      .map(|byte| format!("{byte:02x}"))
+      .collect()
+  }
+}
+
+/// Hashes the content with a [`StableHasher`].
+pub(crate) fn stable_hash(content: impl AsRef<[u8]>) -> String {
+  let mut hasher = StableHasher::default();
+  hasher.write(content);
+  hasher.finish()
+}
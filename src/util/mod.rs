@@ -1,5 +1,24 @@
 // Copyright 2020-2023 the Deno authors. All rights reserved. MIT license.
 
 pub mod graph;
+pub mod hash;
 pub mod swc;
 pub mod symbol;
+pub mod wasm;
+
+/// Maps the items, on the rayon thread pool with the `parallel` feature. The
+/// results are in the order of the items either way.
+#[cfg(feature = "parallel")]
+pub(crate) fn par_map<T: Send, R: Send>(
+  items: Vec<T>,
+  f: impl Fn(T) -> R + Send + Sync,
+) -> Vec<R> {
+  use rayon::prelude::*;
+
+  items.into_par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn par_map<T, R>(items: Vec<T>, f: impl Fn(T) -> R) -> Vec<R> {
+  items.into_iter().map(f).collect()
+}
@@ -0,0 +1,168 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+use std::collections::HashMap;
+
+use wasm_dep_analyzer::ExportType;
+use wasm_dep_analyzer::ParseOptions;
+use wasm_dep_analyzer::WasmDeps;
+
+/// Gets the names of the parameters of the functions exported by a Wasm
+/// module from its custom name section, by the line of their declaration in
+/// the TypeScript declarations derived for the module by deno_graph.
+///
+/// The parameters without a valid name are named `arg0`, `arg1`, etc. like in
+/// the declarations, and the functions missing from the name section are
+/// omitted.
+pub fn param_names_by_line(
+  source: &[u8],
+  source_dts: &str,
+) -> HashMap<usize, Vec<String>> {
+  let Ok(wasm_deps) =
+    WasmDeps::parse(source, ParseOptions { skip_types: false })
+  else {
+    return HashMap::new();
+  };
+  let local_names = local_names(source).unwrap_or_default();
+  let function_lines = function_lines(source_dts);
+
+  let mut param_names_by_line = HashMap::new();
+  for (i, export) in wasm_deps.exports.iter().enumerate() {
+    let ExportType::Function(Ok(signature)) = &export.export_type else {
+      continue;
+    };
+    let Some(locals) = local_names.get(&export.index) else {
+      continue;
+    };
+    // the exports which aren't valid identifiers are declared under a
+    // placeholder name, and exported under their name on the next line
+    let declared_name = if is_valid_ident(export.name) {
+      export.name.to_string()
+    } else {
+      format!("__deno_wasm_export_{i}__")
+    };
+    let Some(line) = function_lines.get(declared_name.as_str()) else {
+      continue;
+    };
+
+    let param_names = (0..signature.params.len() as u32)
+      .map(|index| {
+        locals
+          .get(&index)
+          .filter(|name| is_valid_ident(name))
+          .cloned()
+          .unwrap_or_else(|| format!("arg{index}"))
+      })
+      .collect::<Vec<_>>();
+    param_names_by_line.insert(*line, param_names);
+  }
+
+  param_names_by_line
+}
+
+/// Gets the lines of the functions declared in the TypeScript declarations of
+/// a Wasm module, by their name. Lines are 1-indexed, as in the locations of
+/// the nodes.
+fn function_lines(source_dts: &str) -> HashMap<&str, usize> {
+  source_dts
+    .lines()
+    .enumerate()
+    .filter_map(|(index, line)| {
+      let declaration = line.strip_prefix("export ").unwrap_or(line);
+      let (name, _) = declaration
+        .strip_prefix("declare function ")?
+        .split_once('(')?;
+      Some((name, index + 1))
+    })
+    .collect()
+}
+
+fn is_valid_ident(name: &str) -> bool {
+  !name.is_empty() && deno_ast::swc::ast::Ident::verify_symbol(name).is_ok()
+}
+
+/// Reads the local names subsection of the custom name section, by function
+/// index and then local index.
+fn local_names(source: &[u8]) -> Option<HashMap<u32, HashMap<u32, String>>> {
+  let mut reader = Reader::new(source);
+  if reader.read_bytes(4)? != b"\0asm" {
+    return None;
+  }
+  reader.read_bytes(4)?; // version
+
+  while !reader.is_empty() {
+    let id = reader.read_u8()?;
+    let size = reader.read_u32()?;
+    let mut section = Reader::new(reader.read_bytes(size as usize)?);
+    if id != 0 || section.read_name()? != "name" {
+      continue;
+    }
+
+    while !section.is_empty() {
+      let subsection_id = section.read_u8()?;
+      let size = section.read_u32()?;
+      let mut subsection = Reader::new(section.read_bytes(size as usize)?);
+      if subsection_id != 2 {
+        continue;
+      }
+
+      let mut names = HashMap::new();
+      for _ in 0..subsection.read_u32()? {
+        let function_index = subsection.read_u32()?;
+        let mut locals = HashMap::new();
+        for _ in 0..subsection.read_u32()? {
+          let local_index = subsection.read_u32()?;
+          locals.insert(local_index, subsection.read_name()?.to_string());
+        }
+        names.insert(function_index, locals);
+      }
+      return Some(names);
+    }
+  }
+
+  None
+}
+
+struct Reader<'a> {
+  bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+  fn new(bytes: &'a [u8]) -> Self {
+    Self { bytes }
+  }
+
+  fn is_empty(&self) -> bool {
+    self.bytes.is_empty()
+  }
+
+  fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+    if len > self.bytes.len() {
+      return None;
+    }
+    let (bytes, rest) = self.bytes.split_at(len);
+    self.bytes = rest;
+    Some(bytes)
+  }
+
+  fn read_u8(&mut self) -> Option<u8> {
+    Some(self.read_bytes(1)?[0])
+  }
+
+  /// Reads an unsigned LEB128 integer.
+  fn read_u32(&mut self) -> Option<u32> {
+    let mut value = 0u32;
+    for shift in (0..35).step_by(7) {
+      let byte = self.read_u8()?;
+      value |= ((byte & 0x7f) as u32).checked_shl(shift)?;
+      if byte & 0x80 == 0 {
+        return Some(value);
+      }
+    }
+    None
+  }
+
+  fn read_name(&mut self) -> Option<&'a str> {
+    let len = self.read_u32()?;
+    std::str::from_utf8(self.read_bytes(len as usize)?).ok()
+  }
+}
@@ -12,10 +12,19 @@ use crate::ts_type::TsTypeDefKind;
 use crate::ts_type::infer_simple_ts_type_from_init;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct VariableDef {
   pub ts_type: Option<TsTypeDef>,
+  #[cfg_attr(
+    feature = "schema",
+    schemars(schema_with = "crate::schema::var_decl_kind")
+  )]
   pub kind: VarDeclKind,
+  /// A preview of the value, truncated, for the variables documenting JSON
+  /// modules.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub value_preview: Option<String>,
 }
 
 pub fn get_docs_for_var_declarator(
@@ -83,6 +92,7 @@ pub fn get_docs_for_var_declarator(
       let variable_def = VariableDef {
         ts_type: maybe_ts_type,
         kind: var_decl.kind,
+        value_preview: None,
       };
       items.push((var_name, variable_def));
     }
@@ -181,7 +191,11 @@ fn get_vars_from_obj_destructuring(
         .map(|type_ann| TsTypeDef::new(module_info, &type_ann.type_ann))
     };
 
-    let variable_def = VariableDef { ts_type, kind };
+    let variable_def = VariableDef {
+      ts_type,
+      kind,
+      value_preview: None,
+    };
     items.push((reassign_name.unwrap_or(name), variable_def));
   }
 }
@@ -256,7 +270,11 @@ fn get_vars_from_array_destructuring(
         })
     };
 
-    let variable_def = VariableDef { ts_type, kind };
+    let variable_def = VariableDef {
+      ts_type,
+      kind,
+      value_preview: None,
+    };
     items.push((name, variable_def));
   }
 }
@@ -0,0 +1,1183 @@
+// Copyright 2018-2024 the Deno authors. All rights reserved. MIT license.
+
+//! Visitors over the doc node model.
+//!
+//! [`Visit`] walks a [`DocNode`] tree by reference and [`VisitMut`] by mutable
+//! reference. Every method has a default implementation which calls the
+//! matching `walk_*` function to visit the children, so an implementor only
+//! overrides the methods for the items it is interested in, calling the
+//! `walk_*` function itself to keep descending.
+//!
+//! ```
+//! use deno_doc::ts_type::TsTypeRefDef;
+//! use deno_doc::visit::Visit;
+//! use deno_doc::visit::walk_ts_type_ref_def;
+//!
+//! #[derive(Default)]
+//! struct TypeRefCollector(Vec<String>);
+//!
+//! impl Visit for TypeRefCollector {
+//!   fn visit_ts_type_ref_def(&mut self, node: &TsTypeRefDef) {
+//!     self.0.push(node.type_name.clone());
+//!     walk_ts_type_ref_def(self, node);
+//!   }
+//! }
+//! ```
+
+use crate::class::ClassConstructorDef;
+use crate::class::ClassConstructorParamDef;
+use crate::class::ClassDef;
+use crate::class::ClassMethodDef;
+use crate::class::ClassPropertyDef;
+use crate::decorators::DecoratorDef;
+use crate::r#enum::EnumDef;
+use crate::r#enum::EnumMemberDef;
+use crate::function::FunctionDef;
+use crate::interface::InterfaceDef;
+use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
+use crate::node::DocNode;
+use crate::node::DocNodeDef;
+use crate::node::ImportDef;
+use crate::node::NamespaceDef;
+use crate::node::ReferenceDef;
+use crate::params::ObjectPatPropDef;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
+use crate::ts_type::CallSignatureDef;
+use crate::ts_type::ConstructorDef;
+use crate::ts_type::IndexSignatureDef;
+use crate::ts_type::MethodDef;
+use crate::ts_type::PropertyDef;
+use crate::ts_type::TsFnOrConstructorDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeLiteralDef;
+use crate::ts_type::TsTypeRefDef;
+use crate::ts_type_param::TsTypeParamDef;
+use crate::type_alias::TypeAliasDef;
+use crate::variable::VariableDef;
+use std::sync::Arc;
+
+pub trait Visit {
+  fn visit_doc_node(&mut self, node: &DocNode) {
+    walk_doc_node(self, node)
+  }
+
+  fn visit_doc_node_def(&mut self, node: &DocNodeDef) {
+    walk_doc_node_def(self, node)
+  }
+
+  fn visit_namespace_def(&mut self, node: &NamespaceDef) {
+    walk_namespace_def(self, node)
+  }
+
+  fn visit_import_def(&mut self, _node: &ImportDef) {}
+
+  fn visit_reference_def(&mut self, _node: &ReferenceDef) {}
+
+  fn visit_function_def(&mut self, node: &FunctionDef) {
+    walk_function_def(self, node)
+  }
+
+  fn visit_variable_def(&mut self, node: &VariableDef) {
+    walk_variable_def(self, node)
+  }
+
+  fn visit_enum_def(&mut self, node: &EnumDef) {
+    walk_enum_def(self, node)
+  }
+
+  fn visit_enum_member_def(&mut self, node: &EnumMemberDef) {
+    walk_enum_member_def(self, node)
+  }
+
+  fn visit_class_def(&mut self, node: &ClassDef) {
+    walk_class_def(self, node)
+  }
+
+  fn visit_class_constructor_def(&mut self, node: &ClassConstructorDef) {
+    walk_class_constructor_def(self, node)
+  }
+
+  fn visit_class_constructor_param_def(
+    &mut self,
+    node: &ClassConstructorParamDef,
+  ) {
+    walk_class_constructor_param_def(self, node)
+  }
+
+  fn visit_class_property_def(&mut self, node: &ClassPropertyDef) {
+    walk_class_property_def(self, node)
+  }
+
+  fn visit_class_method_def(&mut self, node: &ClassMethodDef) {
+    walk_class_method_def(self, node)
+  }
+
+  fn visit_type_alias_def(&mut self, node: &TypeAliasDef) {
+    walk_type_alias_def(self, node)
+  }
+
+  fn visit_interface_def(&mut self, node: &InterfaceDef) {
+    walk_interface_def(self, node)
+  }
+
+  fn visit_constructor_def(&mut self, node: &ConstructorDef) {
+    walk_constructor_def(self, node)
+  }
+
+  fn visit_method_def(&mut self, node: &MethodDef) {
+    walk_method_def(self, node)
+  }
+
+  fn visit_property_def(&mut self, node: &PropertyDef) {
+    walk_property_def(self, node)
+  }
+
+  fn visit_call_signature_def(&mut self, node: &CallSignatureDef) {
+    walk_call_signature_def(self, node)
+  }
+
+  fn visit_index_signature_def(&mut self, node: &IndexSignatureDef) {
+    walk_index_signature_def(self, node)
+  }
+
+  fn visit_ts_type_def(&mut self, node: &TsTypeDef) {
+    walk_ts_type_def(self, node)
+  }
+
+  fn visit_ts_type_ref_def(&mut self, node: &TsTypeRefDef) {
+    walk_ts_type_ref_def(self, node)
+  }
+
+  fn visit_ts_fn_or_constructor_def(&mut self, node: &TsFnOrConstructorDef) {
+    walk_ts_fn_or_constructor_def(self, node)
+  }
+
+  fn visit_ts_type_literal_def(&mut self, node: &TsTypeLiteralDef) {
+    walk_ts_type_literal_def(self, node)
+  }
+
+  fn visit_ts_type_param_def(&mut self, node: &TsTypeParamDef) {
+    walk_ts_type_param_def(self, node)
+  }
+
+  fn visit_param_def(&mut self, node: &ParamDef) {
+    walk_param_def(self, node)
+  }
+
+  fn visit_param_pattern_def(&mut self, node: &ParamPatternDef) {
+    walk_param_pattern_def(self, node)
+  }
+
+  fn visit_object_pat_prop_def(&mut self, node: &ObjectPatPropDef) {
+    walk_object_pat_prop_def(self, node)
+  }
+
+  fn visit_decorator_def(&mut self, _node: &DecoratorDef) {}
+
+  fn visit_js_doc(&mut self, node: &JsDoc) {
+    walk_js_doc(self, node)
+  }
+
+  fn visit_js_doc_tag(&mut self, _node: &JsDocTag) {}
+}
+
+pub fn walk_doc_node<V: Visit + ?Sized>(v: &mut V, node: &DocNode) {
+  v.visit_js_doc(&node.js_doc);
+  v.visit_doc_node_def(&node.def);
+}
+
+pub fn walk_doc_node_def<V: Visit + ?Sized>(v: &mut V, node: &DocNodeDef) {
+  match node {
+    DocNodeDef::Function { function_def } => v.visit_function_def(function_def),
+    DocNodeDef::Variable { variable_def } => v.visit_variable_def(variable_def),
+    DocNodeDef::Enum { enum_def } => v.visit_enum_def(enum_def),
+    DocNodeDef::Class { class_def } => v.visit_class_def(class_def),
+    DocNodeDef::TypeAlias { type_alias_def } => {
+      v.visit_type_alias_def(type_alias_def)
+    }
+    DocNodeDef::Namespace { namespace_def } => {
+      v.visit_namespace_def(namespace_def)
+    }
+    DocNodeDef::Interface { interface_def } => {
+      v.visit_interface_def(interface_def)
+    }
+    DocNodeDef::Import { import_def } => v.visit_import_def(import_def),
+    DocNodeDef::Reference { reference_def } => {
+      v.visit_reference_def(reference_def)
+    }
+    DocNodeDef::ModuleDoc => {}
+  }
+}
+
+pub fn walk_namespace_def<V: Visit + ?Sized>(v: &mut V, node: &NamespaceDef) {
+  for element in node.elements.iter() {
+    v.visit_doc_node(element);
+  }
+}
+
+pub fn walk_function_def<V: Visit + ?Sized>(v: &mut V, node: &FunctionDef) {
+  for decorator in node.decorators.iter() {
+    v.visit_decorator_def(decorator);
+  }
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  if let Some(return_type) = &node.return_type {
+    v.visit_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_variable_def<V: Visit + ?Sized>(v: &mut V, node: &VariableDef) {
+  if let Some(ts_type) = &node.ts_type {
+    v.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_enum_def<V: Visit + ?Sized>(v: &mut V, node: &EnumDef) {
+  for member in node.members.iter() {
+    v.visit_enum_member_def(member);
+  }
+}
+
+pub fn walk_enum_member_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &EnumMemberDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  if let Some(init) = &node.init {
+    v.visit_ts_type_def(init);
+  }
+}
+
+pub fn walk_class_def<V: Visit + ?Sized>(v: &mut V, node: &ClassDef) {
+  for decorator in node.decorators.iter() {
+    v.visit_decorator_def(decorator);
+  }
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for super_type_param in node.super_type_params.iter() {
+    v.visit_ts_type_def(super_type_param);
+  }
+  for implements in node.implements.iter() {
+    v.visit_ts_type_def(implements);
+  }
+  for constructor in node.constructors.iter() {
+    v.visit_class_constructor_def(constructor);
+  }
+  for property in node.properties.iter() {
+    v.visit_class_property_def(property);
+  }
+  for index_signature in node.index_signatures.iter() {
+    v.visit_index_signature_def(index_signature);
+  }
+  for method in node.methods.iter() {
+    v.visit_class_method_def(method);
+  }
+}
+
+pub fn walk_class_constructor_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ClassConstructorDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  for param in node.params.iter() {
+    v.visit_class_constructor_param_def(param);
+  }
+}
+
+pub fn walk_class_constructor_param_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ClassConstructorParamDef,
+) {
+  v.visit_param_def(&node.param);
+}
+
+pub fn walk_class_property_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ClassPropertyDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  for decorator in node.decorators.iter() {
+    v.visit_decorator_def(decorator);
+  }
+  if let Some(ts_type) = &node.ts_type {
+    v.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_class_method_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ClassMethodDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  v.visit_function_def(&node.function_def);
+}
+
+pub fn walk_type_alias_def<V: Visit + ?Sized>(v: &mut V, node: &TypeAliasDef) {
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  v.visit_ts_type_def(&node.ts_type);
+}
+
+pub fn walk_interface_def<V: Visit + ?Sized>(v: &mut V, node: &InterfaceDef) {
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for extends in node.extends.iter() {
+    v.visit_ts_type_def(extends);
+  }
+  for constructor in node.constructors.iter() {
+    v.visit_constructor_def(constructor);
+  }
+  for property in node.properties.iter() {
+    v.visit_property_def(property);
+  }
+  for method in node.methods.iter() {
+    v.visit_method_def(method);
+  }
+  for call_signature in node.call_signatures.iter() {
+    v.visit_call_signature_def(call_signature);
+  }
+  for index_signature in node.index_signatures.iter() {
+    v.visit_index_signature_def(index_signature);
+  }
+}
+
+pub fn walk_constructor_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ConstructorDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  if let Some(return_type) = &node.return_type {
+    v.visit_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_method_def<V: Visit + ?Sized>(v: &mut V, node: &MethodDef) {
+  v.visit_js_doc(&node.js_doc);
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  if let Some(return_type) = &node.return_type {
+    v.visit_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_property_def<V: Visit + ?Sized>(v: &mut V, node: &PropertyDef) {
+  v.visit_js_doc(&node.js_doc);
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  if let Some(ts_type) = &node.ts_type {
+    v.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_call_signature_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &CallSignatureDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  if let Some(ts_type) = &node.ts_type {
+    v.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_index_signature_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &IndexSignatureDef,
+) {
+  v.visit_js_doc(&node.js_doc);
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  if let Some(ts_type) = &node.ts_type {
+    v.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_ts_type_def<V: Visit + ?Sized>(v: &mut V, node: &TsTypeDef) {
+  if let Some(literal) = &node.literal
+    && let Some(ts_types) = &literal.ts_types
+  {
+    for ts_type in ts_types.iter() {
+      v.visit_ts_type_def(ts_type);
+    }
+  }
+  if let Some(type_ref) = &node.type_ref {
+    v.visit_ts_type_ref_def(type_ref);
+  }
+  for ts_type in node
+    .union
+    .iter()
+    .chain(node.intersection.iter())
+    .chain(node.tuple.iter())
+    .flatten()
+  {
+    v.visit_ts_type_def(ts_type);
+  }
+  for ts_type in node
+    .array
+    .iter()
+    .chain(node.parenthesized.iter())
+    .chain(node.rest.iter())
+    .chain(node.optional.iter())
+  {
+    v.visit_ts_type_def(ts_type);
+  }
+  if let Some(type_operator) = &node.type_operator {
+    v.visit_ts_type_def(&type_operator.ts_type);
+  }
+  if let Some(fn_or_constructor) = &node.fn_or_constructor {
+    v.visit_ts_fn_or_constructor_def(fn_or_constructor);
+  }
+  if let Some(conditional_type) = &node.conditional_type {
+    v.visit_ts_type_def(&conditional_type.check_type);
+    v.visit_ts_type_def(&conditional_type.extends_type);
+    v.visit_ts_type_def(&conditional_type.true_type);
+    v.visit_ts_type_def(&conditional_type.false_type);
+  }
+  if let Some(infer) = &node.infer {
+    v.visit_ts_type_param_def(&infer.type_param);
+  }
+  if let Some(indexed_access) = &node.indexed_access {
+    v.visit_ts_type_def(&indexed_access.obj_type);
+    v.visit_ts_type_def(&indexed_access.index_type);
+  }
+  if let Some(mapped_type) = &node.mapped_type {
+    v.visit_ts_type_param_def(&mapped_type.type_param);
+    if let Some(name_type) = &mapped_type.name_type {
+      v.visit_ts_type_def(name_type);
+    }
+    if let Some(ts_type) = &mapped_type.ts_type {
+      v.visit_ts_type_def(ts_type);
+    }
+  }
+  if let Some(type_literal) = &node.type_literal {
+    v.visit_ts_type_literal_def(type_literal);
+  }
+  if let Some(type_predicate) = &node.type_predicate
+    && let Some(ts_type) = &type_predicate.r#type
+  {
+    v.visit_ts_type_def(ts_type);
+  }
+  if let Some(import_type) = &node.import_type
+    && let Some(type_params) = &import_type.type_params
+  {
+    for type_param in type_params.iter() {
+      v.visit_ts_type_def(type_param);
+    }
+  }
+}
+
+pub fn walk_ts_type_ref_def<V: Visit + ?Sized>(v: &mut V, node: &TsTypeRefDef) {
+  if let Some(type_params) = &node.type_params {
+    for type_param in type_params.iter() {
+      v.visit_ts_type_def(type_param);
+    }
+  }
+}
+
+pub fn walk_ts_fn_or_constructor_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &TsFnOrConstructorDef,
+) {
+  for type_param in node.type_params.iter() {
+    v.visit_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter() {
+    v.visit_param_def(param);
+  }
+  v.visit_ts_type_def(&node.ts_type);
+}
+
+pub fn walk_ts_type_literal_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &TsTypeLiteralDef,
+) {
+  for constructor in node.constructors.iter() {
+    v.visit_constructor_def(constructor);
+  }
+  for property in node.properties.iter() {
+    v.visit_property_def(property);
+  }
+  for method in node.methods.iter() {
+    v.visit_method_def(method);
+  }
+  for call_signature in node.call_signatures.iter() {
+    v.visit_call_signature_def(call_signature);
+  }
+  for index_signature in node.index_signatures.iter() {
+    v.visit_index_signature_def(index_signature);
+  }
+}
+
+pub fn walk_ts_type_param_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &TsTypeParamDef,
+) {
+  if let Some(constraint) = &node.constraint {
+    v.visit_ts_type_def(constraint);
+  }
+  if let Some(default) = &node.default {
+    v.visit_ts_type_def(default);
+  }
+}
+
+pub fn walk_param_def<V: Visit + ?Sized>(v: &mut V, node: &ParamDef) {
+  for decorator in node.decorators.iter() {
+    v.visit_decorator_def(decorator);
+  }
+  v.visit_param_pattern_def(&node.pattern);
+  if let Some(ts_type) = &node.ts_type {
+    v.visit_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_param_pattern_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ParamPatternDef,
+) {
+  match node {
+    ParamPatternDef::Array { elements, .. } => {
+      for element in elements.iter().flatten() {
+        v.visit_param_def(element);
+      }
+    }
+    ParamPatternDef::Assign { left, .. } => v.visit_param_def(left),
+    ParamPatternDef::Identifier { .. } => {}
+    ParamPatternDef::Object { props, .. } => {
+      for prop in props.iter() {
+        v.visit_object_pat_prop_def(prop);
+      }
+    }
+    ParamPatternDef::Rest { arg } => v.visit_param_def(arg),
+  }
+}
+
+pub fn walk_object_pat_prop_def<V: Visit + ?Sized>(
+  v: &mut V,
+  node: &ObjectPatPropDef,
+) {
+  match node {
+    ObjectPatPropDef::Assign { .. } => {}
+    ObjectPatPropDef::KeyValue { value, .. } => v.visit_param_def(value),
+    ObjectPatPropDef::Rest { arg } => v.visit_param_def(arg),
+  }
+}
+
+pub fn walk_js_doc<V: Visit + ?Sized>(v: &mut V, node: &JsDoc) {
+  for tag in node.tags.iter() {
+    v.visit_js_doc_tag(tag);
+  }
+}
+
+pub trait VisitMut {
+  fn visit_mut_doc_node(&mut self, node: &mut DocNode) {
+    walk_mut_doc_node(self, node)
+  }
+
+  fn visit_mut_doc_node_def(&mut self, node: &mut DocNodeDef) {
+    walk_mut_doc_node_def(self, node)
+  }
+
+  fn visit_mut_namespace_def(&mut self, node: &mut NamespaceDef) {
+    walk_mut_namespace_def(self, node)
+  }
+
+  fn visit_mut_import_def(&mut self, _node: &mut ImportDef) {}
+
+  fn visit_mut_reference_def(&mut self, _node: &mut ReferenceDef) {}
+
+  fn visit_mut_function_def(&mut self, node: &mut FunctionDef) {
+    walk_mut_function_def(self, node)
+  }
+
+  fn visit_mut_variable_def(&mut self, node: &mut VariableDef) {
+    walk_mut_variable_def(self, node)
+  }
+
+  fn visit_mut_enum_def(&mut self, node: &mut EnumDef) {
+    walk_mut_enum_def(self, node)
+  }
+
+  fn visit_mut_enum_member_def(&mut self, node: &mut EnumMemberDef) {
+    walk_mut_enum_member_def(self, node)
+  }
+
+  fn visit_mut_class_def(&mut self, node: &mut ClassDef) {
+    walk_mut_class_def(self, node)
+  }
+
+  fn visit_mut_class_constructor_def(
+    &mut self,
+    node: &mut ClassConstructorDef,
+  ) {
+    walk_mut_class_constructor_def(self, node)
+  }
+
+  fn visit_mut_class_constructor_param_def(
+    &mut self,
+    node: &mut ClassConstructorParamDef,
+  ) {
+    walk_mut_class_constructor_param_def(self, node)
+  }
+
+  fn visit_mut_class_property_def(&mut self, node: &mut ClassPropertyDef) {
+    walk_mut_class_property_def(self, node)
+  }
+
+  fn visit_mut_class_method_def(&mut self, node: &mut ClassMethodDef) {
+    walk_mut_class_method_def(self, node)
+  }
+
+  fn visit_mut_type_alias_def(&mut self, node: &mut TypeAliasDef) {
+    walk_mut_type_alias_def(self, node)
+  }
+
+  fn visit_mut_interface_def(&mut self, node: &mut InterfaceDef) {
+    walk_mut_interface_def(self, node)
+  }
+
+  fn visit_mut_constructor_def(&mut self, node: &mut ConstructorDef) {
+    walk_mut_constructor_def(self, node)
+  }
+
+  fn visit_mut_method_def(&mut self, node: &mut MethodDef) {
+    walk_mut_method_def(self, node)
+  }
+
+  fn visit_mut_property_def(&mut self, node: &mut PropertyDef) {
+    walk_mut_property_def(self, node)
+  }
+
+  fn visit_mut_call_signature_def(&mut self, node: &mut CallSignatureDef) {
+    walk_mut_call_signature_def(self, node)
+  }
+
+  fn visit_mut_index_signature_def(&mut self, node: &mut IndexSignatureDef) {
+    walk_mut_index_signature_def(self, node)
+  }
+
+  fn visit_mut_ts_type_def(&mut self, node: &mut TsTypeDef) {
+    walk_mut_ts_type_def(self, node)
+  }
+
+  fn visit_mut_ts_type_ref_def(&mut self, node: &mut TsTypeRefDef) {
+    walk_mut_ts_type_ref_def(self, node)
+  }
+
+  fn visit_mut_ts_fn_or_constructor_def(
+    &mut self,
+    node: &mut TsFnOrConstructorDef,
+  ) {
+    walk_mut_ts_fn_or_constructor_def(self, node)
+  }
+
+  fn visit_mut_ts_type_literal_def(&mut self, node: &mut TsTypeLiteralDef) {
+    walk_mut_ts_type_literal_def(self, node)
+  }
+
+  fn visit_mut_ts_type_param_def(&mut self, node: &mut TsTypeParamDef) {
+    walk_mut_ts_type_param_def(self, node)
+  }
+
+  fn visit_mut_param_def(&mut self, node: &mut ParamDef) {
+    walk_mut_param_def(self, node)
+  }
+
+  fn visit_mut_param_pattern_def(&mut self, node: &mut ParamPatternDef) {
+    walk_mut_param_pattern_def(self, node)
+  }
+
+  fn visit_mut_object_pat_prop_def(&mut self, node: &mut ObjectPatPropDef) {
+    walk_mut_object_pat_prop_def(self, node)
+  }
+
+  fn visit_mut_decorator_def(&mut self, _node: &mut DecoratorDef) {}
+
+  fn visit_mut_js_doc(&mut self, node: &mut JsDoc) {
+    walk_mut_js_doc(self, node)
+  }
+
+  fn visit_mut_js_doc_tag(&mut self, _node: &mut JsDocTag) {}
+}
+
+pub fn walk_mut_doc_node<V: VisitMut + ?Sized>(v: &mut V, node: &mut DocNode) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  v.visit_mut_doc_node_def(&mut node.def);
+}
+
+pub fn walk_mut_doc_node_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut DocNodeDef,
+) {
+  match node {
+    DocNodeDef::Function { function_def } => {
+      v.visit_mut_function_def(function_def)
+    }
+    DocNodeDef::Variable { variable_def } => {
+      v.visit_mut_variable_def(variable_def)
+    }
+    DocNodeDef::Enum { enum_def } => v.visit_mut_enum_def(enum_def),
+    DocNodeDef::Class { class_def } => v.visit_mut_class_def(class_def),
+    DocNodeDef::TypeAlias { type_alias_def } => {
+      v.visit_mut_type_alias_def(type_alias_def)
+    }
+    DocNodeDef::Namespace { namespace_def } => {
+      v.visit_mut_namespace_def(namespace_def)
+    }
+    DocNodeDef::Interface { interface_def } => {
+      v.visit_mut_interface_def(interface_def)
+    }
+    DocNodeDef::Import { import_def } => v.visit_mut_import_def(import_def),
+    DocNodeDef::Reference { reference_def } => {
+      v.visit_mut_reference_def(reference_def)
+    }
+    DocNodeDef::ModuleDoc => {}
+  }
+}
+
+pub fn walk_mut_namespace_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut NamespaceDef,
+) {
+  for element in node.elements.iter_mut() {
+    v.visit_mut_doc_node(Arc::make_mut(element));
+  }
+}
+
+pub fn walk_mut_function_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut FunctionDef,
+) {
+  for decorator in node.decorators.iter_mut() {
+    v.visit_mut_decorator_def(decorator);
+  }
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  if let Some(return_type) = &mut node.return_type {
+    v.visit_mut_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_mut_variable_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut VariableDef,
+) {
+  if let Some(ts_type) = &mut node.ts_type {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_mut_enum_def<V: VisitMut + ?Sized>(v: &mut V, node: &mut EnumDef) {
+  for member in node.members.iter_mut() {
+    v.visit_mut_enum_member_def(member);
+  }
+}
+
+pub fn walk_mut_enum_member_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut EnumMemberDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  if let Some(init) = &mut node.init {
+    v.visit_mut_ts_type_def(init);
+  }
+}
+
+pub fn walk_mut_class_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ClassDef,
+) {
+  for decorator in node.decorators.iter_mut() {
+    v.visit_mut_decorator_def(decorator);
+  }
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for super_type_param in node.super_type_params.iter_mut() {
+    v.visit_mut_ts_type_def(super_type_param);
+  }
+  for implements in node.implements.iter_mut() {
+    v.visit_mut_ts_type_def(implements);
+  }
+  for constructor in node.constructors.iter_mut() {
+    v.visit_mut_class_constructor_def(constructor);
+  }
+  for property in node.properties.iter_mut() {
+    v.visit_mut_class_property_def(property);
+  }
+  for index_signature in node.index_signatures.iter_mut() {
+    v.visit_mut_index_signature_def(index_signature);
+  }
+  for method in node.methods.iter_mut() {
+    v.visit_mut_class_method_def(method);
+  }
+}
+
+pub fn walk_mut_class_constructor_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ClassConstructorDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for param in node.params.iter_mut() {
+    v.visit_mut_class_constructor_param_def(param);
+  }
+}
+
+pub fn walk_mut_class_constructor_param_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ClassConstructorParamDef,
+) {
+  v.visit_mut_param_def(&mut node.param);
+}
+
+pub fn walk_mut_class_property_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ClassPropertyDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for decorator in node.decorators.iter_mut() {
+    v.visit_mut_decorator_def(decorator);
+  }
+  if let Some(ts_type) = &mut node.ts_type {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_mut_class_method_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ClassMethodDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  v.visit_mut_function_def(&mut node.function_def);
+}
+
+pub fn walk_mut_type_alias_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TypeAliasDef,
+) {
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  v.visit_mut_ts_type_def(&mut node.ts_type);
+}
+
+pub fn walk_mut_interface_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut InterfaceDef,
+) {
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for extends in node.extends.iter_mut() {
+    v.visit_mut_ts_type_def(extends);
+  }
+  for constructor in node.constructors.iter_mut() {
+    v.visit_mut_constructor_def(constructor);
+  }
+  for property in node.properties.iter_mut() {
+    v.visit_mut_property_def(property);
+  }
+  for method in node.methods.iter_mut() {
+    v.visit_mut_method_def(method);
+  }
+  for call_signature in node.call_signatures.iter_mut() {
+    v.visit_mut_call_signature_def(call_signature);
+  }
+  for index_signature in node.index_signatures.iter_mut() {
+    v.visit_mut_index_signature_def(index_signature);
+  }
+}
+
+pub fn walk_mut_constructor_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ConstructorDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  if let Some(return_type) = &mut node.return_type {
+    v.visit_mut_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_mut_method_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut MethodDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  if let Some(return_type) = &mut node.return_type {
+    v.visit_mut_ts_type_def(return_type);
+  }
+}
+
+pub fn walk_mut_property_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut PropertyDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  if let Some(ts_type) = &mut node.ts_type {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_mut_call_signature_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut CallSignatureDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  if let Some(ts_type) = &mut node.ts_type {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_mut_index_signature_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut IndexSignatureDef,
+) {
+  v.visit_mut_js_doc(&mut node.js_doc);
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  if let Some(ts_type) = &mut node.ts_type {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_mut_ts_type_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TsTypeDef,
+) {
+  if let Some(literal) = &mut node.literal
+    && let Some(ts_types) = &mut literal.ts_types
+  {
+    for ts_type in ts_types.iter_mut() {
+      v.visit_mut_ts_type_def(ts_type);
+    }
+  }
+  if let Some(type_ref) = &mut node.type_ref {
+    v.visit_mut_ts_type_ref_def(type_ref);
+  }
+  for ts_type in node
+    .union
+    .iter_mut()
+    .chain(node.intersection.iter_mut())
+    .chain(node.tuple.iter_mut())
+    .flatten()
+  {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+  for ts_type in node
+    .array
+    .iter_mut()
+    .chain(node.parenthesized.iter_mut())
+    .chain(node.rest.iter_mut())
+    .chain(node.optional.iter_mut())
+  {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+  if let Some(type_operator) = &mut node.type_operator {
+    v.visit_mut_ts_type_def(&mut type_operator.ts_type);
+  }
+  if let Some(fn_or_constructor) = &mut node.fn_or_constructor {
+    v.visit_mut_ts_fn_or_constructor_def(fn_or_constructor);
+  }
+  if let Some(conditional_type) = &mut node.conditional_type {
+    v.visit_mut_ts_type_def(&mut conditional_type.check_type);
+    v.visit_mut_ts_type_def(&mut conditional_type.extends_type);
+    v.visit_mut_ts_type_def(&mut conditional_type.true_type);
+    v.visit_mut_ts_type_def(&mut conditional_type.false_type);
+  }
+  if let Some(infer) = &mut node.infer {
+    v.visit_mut_ts_type_param_def(&mut infer.type_param);
+  }
+  if let Some(indexed_access) = &mut node.indexed_access {
+    v.visit_mut_ts_type_def(&mut indexed_access.obj_type);
+    v.visit_mut_ts_type_def(&mut indexed_access.index_type);
+  }
+  if let Some(mapped_type) = &mut node.mapped_type {
+    v.visit_mut_ts_type_param_def(&mut mapped_type.type_param);
+    if let Some(name_type) = &mut mapped_type.name_type {
+      v.visit_mut_ts_type_def(name_type);
+    }
+    if let Some(ts_type) = &mut mapped_type.ts_type {
+      v.visit_mut_ts_type_def(ts_type);
+    }
+  }
+  if let Some(type_literal) = &mut node.type_literal {
+    v.visit_mut_ts_type_literal_def(type_literal);
+  }
+  if let Some(type_predicate) = &mut node.type_predicate
+    && let Some(ts_type) = &mut type_predicate.r#type
+  {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+  if let Some(import_type) = &mut node.import_type
+    && let Some(type_params) = &mut import_type.type_params
+  {
+    for type_param in type_params.iter_mut() {
+      v.visit_mut_ts_type_def(type_param);
+    }
+  }
+}
+
+pub fn walk_mut_ts_type_ref_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TsTypeRefDef,
+) {
+  if let Some(type_params) = &mut node.type_params {
+    for type_param in type_params.iter_mut() {
+      v.visit_mut_ts_type_def(type_param);
+    }
+  }
+}
+
+pub fn walk_mut_ts_fn_or_constructor_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TsFnOrConstructorDef,
+) {
+  for type_param in node.type_params.iter_mut() {
+    v.visit_mut_ts_type_param_def(type_param);
+  }
+  for param in node.params.iter_mut() {
+    v.visit_mut_param_def(param);
+  }
+  v.visit_mut_ts_type_def(&mut node.ts_type);
+}
+
+pub fn walk_mut_ts_type_literal_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TsTypeLiteralDef,
+) {
+  for constructor in node.constructors.iter_mut() {
+    v.visit_mut_constructor_def(constructor);
+  }
+  for property in node.properties.iter_mut() {
+    v.visit_mut_property_def(property);
+  }
+  for method in node.methods.iter_mut() {
+    v.visit_mut_method_def(method);
+  }
+  for call_signature in node.call_signatures.iter_mut() {
+    v.visit_mut_call_signature_def(call_signature);
+  }
+  for index_signature in node.index_signatures.iter_mut() {
+    v.visit_mut_index_signature_def(index_signature);
+  }
+}
+
+pub fn walk_mut_ts_type_param_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TsTypeParamDef,
+) {
+  if let Some(constraint) = &mut node.constraint {
+    v.visit_mut_ts_type_def(constraint);
+  }
+  if let Some(default) = &mut node.default {
+    v.visit_mut_ts_type_def(default);
+  }
+}
+
+pub fn walk_mut_param_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ParamDef,
+) {
+  for decorator in node.decorators.iter_mut() {
+    v.visit_mut_decorator_def(decorator);
+  }
+  v.visit_mut_param_pattern_def(&mut node.pattern);
+  if let Some(ts_type) = &mut node.ts_type {
+    v.visit_mut_ts_type_def(ts_type);
+  }
+}
+
+pub fn walk_mut_param_pattern_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ParamPatternDef,
+) {
+  match node {
+    ParamPatternDef::Array { elements, .. } => {
+      for element in elements.iter_mut().flatten() {
+        v.visit_mut_param_def(element);
+      }
+    }
+    ParamPatternDef::Assign { left, .. } => v.visit_mut_param_def(left),
+    ParamPatternDef::Identifier { .. } => {}
+    ParamPatternDef::Object { props, .. } => {
+      for prop in props.iter_mut() {
+        v.visit_mut_object_pat_prop_def(prop);
+      }
+    }
+    ParamPatternDef::Rest { arg } => v.visit_mut_param_def(arg),
+  }
+}
+
+pub fn walk_mut_object_pat_prop_def<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut ObjectPatPropDef,
+) {
+  match node {
+    ObjectPatPropDef::Assign { .. } => {}
+    ObjectPatPropDef::KeyValue { value, .. } => v.visit_mut_param_def(value),
+    ObjectPatPropDef::Rest { arg } => v.visit_mut_param_def(arg),
+  }
+}
+
+pub fn walk_mut_js_doc<V: VisitMut + ?Sized>(v: &mut V, node: &mut JsDoc) {
+  for tag in node.tags.iter_mut() {
+    v.visit_mut_js_doc_tag(tag);
+  }
+}
@@ -33,6 +33,7 @@ const extraColors = {
   "private": TAG_CYAN,
   "optional": TAG_CYAN,
   "permissions": TAG_CYAN,
+  "webassembly": "#654FF0", // WebAssembly purple
   "other": "#57534E", // stone 600
 };
 
@@ -69,6 +69,7 @@ impl TestBuilder {
       DocParserOptions {
         private: self.private,
         diagnostics: true,
+        cache: None,
       },
     )
     .unwrap();
@@ -4,6 +4,7 @@ use deno_ast::ModuleSpecifier;
 use deno_doc::DocNode;
 use deno_doc::DocParser;
 use deno_doc::DocParserOptions;
+use deno_doc::html::Shared;
 use deno_doc::html::pages::SymbolPage;
 use deno_doc::html::*;
 use deno_graph::BuildOptions;
@@ -18,7 +19,6 @@ use deno_graph::source::Loader;
 use futures::future;
 use indexmap::IndexMap;
 use std::fs;
-use std::rc::Rc;
 use std::sync::Arc;
 
 struct SourceFileLoader {}
@@ -150,6 +150,7 @@ async fn get_files(subpath: &str) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
     DocParserOptions {
       diagnostics: false,
       private: false,
+      cache: None,
     },
   )
   .unwrap()
@@ -163,17 +164,26 @@ async fn html_doc_dts() {
     GenerateOptions {
       package_name: None,
       main_entrypoint: None,
-      href_resolver: Rc::new(EmptyResolver),
-      usage_composer: Rc::new(EmptyResolver),
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
       rewrite_map: None,
       category_docs: None,
       disable_search: false,
       symbol_redirect_map: None,
       default_symbol_map: None,
       markdown_renderer: comrak::create_renderer(None, None, None),
-      markdown_stripper: Rc::new(comrak::strip),
+      markdown_stripper: Shared::new(comrak::strip),
       head_inject: None,
       id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
     },
     get_files("dts").await,
   )
@@ -197,18 +207,29 @@ async fn html_doc_dts() {
       "./~/WebSocket.prototype.html",
       "comrak.css",
       "darkmode_toggle.js",
-      "fuse.js",
+      "inventory.json",
       "page.css",
       "reset.css",
       "script.js",
       "search.js",
+      "search/nodes_0.js",
+      "search/terms_a.js",
+      "search/terms_b.js",
+      "search/terms_i.js",
+      "search/terms_o.js",
+      "search/terms_p.js",
+      "search/terms_r.js",
+      "search/terms_s.js",
+      "search/terms_t.js",
+      "search/terms_w.js",
       "search_index.js",
       "styles.css"
     ]
   );
 
   for file_name in file_names {
-    if !file_name.ends_with(".css") {
+    // the chunks of the search index are covered by `search_index_chunks`
+    if !file_name.ends_with(".css") && !file_name.starts_with("search/") {
       insta::assert_snapshot!(files.get(file_name).unwrap());
     }
   }
@@ -220,17 +241,26 @@ async fn html_doc_files_single() {
     GenerateOptions {
       package_name: None,
       main_entrypoint: None,
-      href_resolver: Rc::new(EmptyResolver),
-      usage_composer: Rc::new(EmptyResolver),
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
       rewrite_map: None,
       category_docs: None,
       disable_search: false,
       symbol_redirect_map: None,
       default_symbol_map: None,
       markdown_renderer: comrak::create_renderer(None, None, None),
-      markdown_stripper: Rc::new(comrak::strip),
+      markdown_stripper: Shared::new(comrak::strip),
       head_inject: None,
       id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
     },
     get_files("single").await,
   )
@@ -253,18 +283,21 @@ async fn html_doc_files_single() {
       "./~/Foobar.prototype.html",
       "comrak.css",
       "darkmode_toggle.js",
-      "fuse.js",
+      "inventory.json",
       "page.css",
       "reset.css",
       "script.js",
       "search.js",
+      "search/nodes_0.js",
+      "search/terms_b.js",
+      "search/terms_f.js",
       "search_index.js",
       "styles.css",
     ]
   );
 
   for file_name in file_names {
-    if !file_name.ends_with(".css") {
+    if !file_name.ends_with(".css") && !file_name.starts_with("search/") {
       insta::assert_snapshot!(files.get(file_name).unwrap());
     }
   }
@@ -298,17 +331,26 @@ async fn html_doc_files_multiple() {
     GenerateOptions {
       package_name: None,
       main_entrypoint: Some(main_specifier),
-      href_resolver: Rc::new(EmptyResolver),
-      usage_composer: Rc::new(EmptyResolver),
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
       rewrite_map: Some(rewrite_map),
       category_docs: None,
       disable_search: false,
       symbol_redirect_map: None,
       default_symbol_map: None,
       markdown_renderer: comrak::create_renderer(None, None, None),
-      markdown_stripper: Rc::new(comrak::strip),
+      markdown_stripper: Shared::new(comrak::strip),
       head_inject: None,
       id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
     },
     get_files("multiple").await,
   )
@@ -391,18 +433,42 @@ async fn html_doc_files_multiple() {
       "foo/index.html",
       "foo/~/default.html",
       "foo/~/x.html",
-      "fuse.js",
+      "inventory.json",
       "page.css",
       "reset.css",
       "script.js",
       "search.js",
+      "search/nodes_0.js",
+      "search/terms_1.js",
+      "search/terms_2.js",
+      "search/terms_4.js",
+      "search/terms_a.js",
+      "search/terms_b.js",
+      "search/terms_c.js",
+      "search/terms_d.js",
+      "search/terms_e.js",
+      "search/terms_f.js",
+      "search/terms_g.js",
+      "search/terms_h.js",
+      "search/terms_i.js",
+      "search/terms_j.js",
+      "search/terms_m.js",
+      "search/terms_o.js",
+      "search/terms_p.js",
+      "search/terms_q.js",
+      "search/terms_r.js",
+      "search/terms_s.js",
+      "search/terms_t.js",
+      "search/terms_v.js",
+      "search/terms_w.js",
+      "search/terms_x.js",
       "search_index.js",
       "styles.css"
     ]
   );
 
   for file_name in file_names {
-    if !file_name.ends_with(".css") {
+    if !file_name.ends_with(".css") && !file_name.starts_with("search/") {
       insta::assert_snapshot!(files.get(file_name).unwrap());
     }
   }
@@ -442,17 +508,26 @@ async fn symbol_group() {
       main_entrypoint: Some(
         ModuleSpecifier::from_file_path(multiple_dir.join("a.ts")).unwrap(),
       ),
-      href_resolver: Rc::new(EmptyResolver),
-      usage_composer: Rc::new(EmptyResolver),
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
       rewrite_map: Some(rewrite_map),
       category_docs: None,
       disable_search: false,
       symbol_redirect_map: None,
       default_symbol_map: None,
       markdown_renderer: comrak::create_renderer(None, None, None),
-      markdown_stripper: Rc::new(comrak::strip),
+      markdown_stripper: Shared::new(comrak::strip),
       head_inject: None,
       id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
     },
     None,
     Default::default(),
@@ -474,17 +549,17 @@ async fn symbol_group() {
             symbol_group_ctx,
             toc_ctx,
             categories_panel,
+            ..
           } => {
-            let root = ctx.resolve_path(
-              UrlResolveKind::Symbol {
-                file: short_path,
-                symbol: &symbol_group_ctx.name,
-              },
-              UrlResolveKind::Root,
-            );
+            let current_resolve = UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &symbol_group_ctx.name,
+            };
+            let root = ctx.resolve_path(current_resolve, UrlResolveKind::Root);
 
             let html_head_ctx = pages::HtmlHeadCtx::new(
               &ctx,
+              current_resolve,
               &root,
               Some(&symbol_group_ctx.name),
               Some(short_path),
@@ -497,6 +572,7 @@ async fn symbol_group() {
               toc_ctx,
               disable_search: false,
               categories_panel,
+              version_switcher: None,
             })
           }
           SymbolPage::Redirect { .. } => None,
@@ -542,17 +618,26 @@ async fn symbol_search() {
       main_entrypoint: Some(
         ModuleSpecifier::from_file_path(multiple_dir.join("a.ts")).unwrap(),
       ),
-      href_resolver: Rc::new(EmptyResolver),
-      usage_composer: Rc::new(EmptyResolver),
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
       rewrite_map: Some(rewrite_map),
       category_docs: None,
       disable_search: false,
       symbol_redirect_map: None,
       default_symbol_map: None,
       markdown_renderer: comrak::create_renderer(None, None, None),
-      markdown_stripper: Rc::new(comrak::strip),
+      markdown_stripper: Shared::new(comrak::strip),
       head_inject: None,
       id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
     },
     None,
     Default::default(),
@@ -599,17 +684,26 @@ async fn module_doc() {
       main_entrypoint: Some(
         ModuleSpecifier::from_file_path(multiple_dir.join("a.ts")).unwrap(),
       ),
-      href_resolver: Rc::new(EmptyResolver),
-      usage_composer: Rc::new(EmptyResolver),
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
       rewrite_map: Some(rewrite_map),
       category_docs: None,
       disable_search: false,
       symbol_redirect_map: None,
       default_symbol_map: None,
       markdown_renderer: comrak::create_renderer(None, None, None),
-      markdown_stripper: Rc::new(comrak::strip),
+      markdown_stripper: Shared::new(comrak::strip),
       head_inject: None,
       id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
     },
     None,
     FileMode::Single,
@@ -632,3 +726,1379 @@ async fn module_doc() {
 
   insta::assert_json_snapshot!(module_docs);
 }
+
+#[tokio::test]
+async fn template_overrides() {
+  fn options(template_overrides: TemplateOverrides) -> GenerateOptions {
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: Some(template_overrides),
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    }
+  }
+
+  handlebars::handlebars_helper!(shout: |s: str| s.to_uppercase());
+  handlebars::handlebars_helper!(year: | | "2024");
+
+  let mut overrides = TemplateOverrides::default();
+  overrides.templates.insert(
+    "pages/top_nav".to_string(),
+    "<nav id=\"portal-nav\" data-title=\"{{html_head_ctx.title}}\">{{> portal_logo}}{{#unless disable_search}}{{shout \"search\"}}{{/unless}}</nav>".to_string(),
+  );
+  overrides.partials.insert(
+    "portal_logo".to_string(),
+    "<img alt=\"logo {{year}}\">".to_string(),
+  );
+  overrides
+    .helpers
+    .insert("shout".to_string(), std::sync::Arc::new(shout));
+  overrides
+    .helpers
+    .insert("year".to_string(), std::sync::Arc::new(year));
+
+  let ctx = GenerateCtx::create_basic(
+    options(overrides.clone()),
+    get_files("single").await,
+  )
+  .unwrap();
+  let files = generate(ctx).unwrap();
+  let index = files.get("./index.html").unwrap();
+  assert!(index.contains(
+    "<nav id=\"portal-nav\" data-title=\"documentation\"><img alt=\"logo 2024\">SEARCH</nav>"
+  ));
+  assert!(!index.contains("id=\"topnav\""));
+
+  let mut unknown = overrides.clone();
+  unknown
+    .templates
+    .insert("pages/footer".to_string(), "".to_string());
+  let err =
+    GenerateCtx::create_basic(options(unknown), get_files("single").await)
+      .err()
+      .unwrap();
+  assert_eq!(
+    err.to_string(),
+    "Cannot override unknown template \"pages/footer\""
+  );
+
+  // the fields are validated before generating, so no page is written
+  let mut missing_field = overrides.clone();
+  missing_field
+    .templates
+    .insert("pages/top_nav".to_string(), "{{package_name}}".to_string());
+  let err = GenerateCtx::create_basic(
+    options(missing_field),
+    get_files("single").await,
+  )
+  .err()
+  .unwrap();
+  assert_eq!(
+    err.to_string(),
+    "Template \"pages/top_nav\" references the field \"package_name\", which is not provided by its context. Available fields: breadcrumbs_ctx, categories_panel, content, darkmode_toggle_js, disable_search, head_inject, html_head_ctx, kind, lines, module_doc, overview, script_js, sections, stylesheet, symbol_group_ctx, title, toc_ctx, usage, version_switcher"
+  );
+
+  let mut missing_partial_field = overrides.clone();
+  missing_partial_field
+    .partials
+    .insert("portal_logo".to_string(), "{{logo_url}}".to_string());
+  let err = GenerateCtx::create_basic(
+    options(missing_partial_field),
+    get_files("single").await,
+  )
+  .err()
+  .unwrap();
+  assert!(err.to_string().starts_with(
+    "Template \"portal_logo\" references the field \"logo_url\", which is not provided by its context. Available fields: "
+  ));
+
+  let mut syntax = overrides;
+  syntax
+    .partials
+    .insert("broken".to_string(), "{{#if}}".to_string());
+  let err =
+    GenerateCtx::create_basic(options(syntax), get_files("single").await)
+      .err()
+      .unwrap();
+  assert!(
+    err
+      .to_string()
+      .starts_with("Failed to compile template \"broken\"")
+  );
+}
+
+#[tokio::test]
+async fn localized_messages() {
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: Some(Messages {
+        lang: "de".to_string(),
+        parameters: "Parameter".to_string(),
+        return_type: "Rückgabetyp".to_string(),
+        all_symbols: "Alle Symbole".to_string(),
+        search_placeholder: "Suchen...".to_string(),
+        documentation: "Dokumentation".to_string(),
+        ..Default::default()
+      }),
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("multiple").await,
+  )
+  .unwrap();
+  let files = generate(ctx).unwrap();
+
+  let all_symbols = files.get("./all_symbols.html").unwrap();
+  assert!(all_symbols.starts_with("<!DOCTYPE html>\n<html lang=\"de\">"));
+  assert!(all_symbols.contains("<title>Alle Symbole - Dokumentation</title>"));
+  assert!(all_symbols.contains("data-placeholder=\"Suchen...\""));
+
+  assert!(files.values().any(|file| file.contains(">Parameter</a>")));
+  assert!(files.values().any(|file| file.contains(">Rückgabetyp</a>")));
+  assert!(!files.values().any(|file| file.contains("Return Type")));
+}
+
+#[tokio::test]
+async fn version_switcher() {
+  let options = |canonical_root: Option<&str>| GenerateOptions {
+    package_name: None,
+    main_entrypoint: None,
+    href_resolver: Shared::new(EmptyResolver),
+    usage_composer: Shared::new(EmptyResolver),
+    rewrite_map: None,
+    category_docs: None,
+    disable_search: false,
+    symbol_redirect_map: None,
+    default_symbol_map: None,
+    markdown_renderer: comrak::create_renderer(None, None, None),
+    markdown_stripper: Shared::new(comrak::strip),
+    head_inject: None,
+    id_prefix: None,
+    template_overrides: None,
+    messages: None,
+    versions: Some(VersionOptions {
+      current: "2.0.0".to_string(),
+      others: vec![
+        DocVersion {
+          name: "1.0.0".to_string(),
+          root: "../1.0.0".to_string(),
+          symbols: Some(IndexMap::from([(
+            "a.ts".to_string(),
+            ["Foo".to_string()].into_iter().collect(),
+          )])),
+          symbol_redirect_map: Some(IndexMap::from([(
+            "b.ts".to_string(),
+            IndexMap::from([("x".to_string(), "./b.ts/~/y.html".to_string())]),
+          )])),
+        },
+        DocVersion {
+          name: "3.0.0".to_string(),
+          root: "https://example.com/3.0.0/".to_string(),
+          symbols: None,
+          symbol_redirect_map: None,
+        },
+      ],
+      canonical_root: canonical_root.map(String::from),
+    }),
+    source_files: None,
+    class_hierarchy: false,
+    full_text_search: false,
+    base_url: None,
+    inventories: None,
+    expand_types: false,
+  };
+
+  let ctx =
+    GenerateCtx::create_basic(options(None), get_files("multiple").await)
+      .unwrap();
+  let symbols = collect_version_symbols(&ctx);
+  assert!(symbols["a.ts"].contains("Testing.t"));
+  let files = generate(ctx).unwrap();
+
+  let a = files.get("a.ts/~/Foo.html").unwrap();
+  assert!(a.contains("<summary aria-label=\"Select version\">2.0.0</summary>"));
+  assert!(a.contains(
+    "href=\"..&#x2F;..&#x2F;..&#x2F;1.0.0&#x2F;a.ts&#x2F;~&#x2F;Foo.html\""
+  ));
+  assert!(a.contains(
+    "href=\"https:&#x2F;&#x2F;example.com&#x2F;3.0.0&#x2F;a.ts&#x2F;~&#x2F;Foo.html\""
+  ));
+  assert!(!a.contains("rel=\"canonical\""));
+
+  // renamed symbols follow the redirect map
+  let b = files.get("b.ts/~/x.html").unwrap();
+  assert!(b.contains(
+    "href=\"..&#x2F;..&#x2F;..&#x2F;1.0.0&#x2F;b.ts&#x2F;~&#x2F;y.html\""
+  ));
+
+  // missing symbols fall back to the index of the version
+  let c = files.get("c.ts/~/x.html").unwrap();
+  assert!(c.contains("href=\"..&#x2F;..&#x2F;..&#x2F;1.0.0&#x2F;\""));
+
+  // drilldown pages fall back to their parent symbol
+  let method = files.get("a.ts/~/Foo.prototype.foo.html").unwrap();
+  assert!(method.contains(
+    "href=\"..&#x2F;..&#x2F;..&#x2F;1.0.0&#x2F;a.ts&#x2F;~&#x2F;Foo.html\""
+  ));
+
+  let ctx = GenerateCtx::create_basic(
+    options(Some("https://example.com/2.0.0/")),
+    get_files("multiple").await,
+  )
+  .unwrap();
+  let files = generate(ctx).unwrap();
+
+  let a = files.get("a.ts/~/Foo.html").unwrap();
+  assert!(a.contains(
+    "<link rel=\"canonical\" href=\"https:&#x2F;&#x2F;example.com&#x2F;2.0.0&#x2F;a.ts&#x2F;~&#x2F;Foo.html\">"
+  ));
+}
+
+#[tokio::test]
+async fn source_pages() {
+  let doc_nodes_by_url = get_files("multiple").await;
+  let source_files = doc_nodes_by_url
+    .keys()
+    .map(|specifier| {
+      let text = fs::read_to_string(specifier.to_file_path().unwrap()).unwrap();
+      (specifier.clone(), text.into())
+    })
+    .collect();
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: Some(source_files),
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+  let files = generate(ctx).unwrap();
+
+  let source = files.get("a.ts.html").unwrap();
+  assert!(source.contains(
+    "<div id=\"L39\" class=\"sourceLine\"><a href=\"#L39\" class=\"lineNumber\">39</a><span class=\"keyword\">export</span> <span class=\"keyword\">class</span> <a href=\"./a.ts/~/Foo.html\" class=\"sourceDeclaration\">Foo</a>"
+  ));
+
+  let foo = files.get("a.ts/~/Foo.html").unwrap();
+  assert!(foo.contains("href=\"..&#x2F;..&#x2F;a.ts.html#L39\""));
+}
+
+#[tokio::test]
+async fn single_file_source_page() {
+  let doc_nodes_by_url = get_files("single").await;
+  let source_files = doc_nodes_by_url
+    .keys()
+    .map(|specifier| {
+      let text = fs::read_to_string(specifier.to_file_path().unwrap()).unwrap();
+      (specifier.clone(), text.into())
+    })
+    .collect();
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: Some(source_files),
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+  let files = generate(ctx).unwrap();
+
+  // the only module is its own common ancestor, so its short path is `.`
+  assert!(!files.contains_key("..html"));
+  assert!(files.contains_key("a.ts.html"));
+
+  let foo = files.get("./~/Foo.html").unwrap();
+  assert!(foo.contains("href=\"..&#x2F;a.ts.html#L"));
+}
+
+#[tokio::test]
+async fn type_references() {
+  let doc_nodes_by_url = get_files("multiple").await;
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+
+  let foo = ctx
+    .doc_nodes
+    .values()
+    .flatten()
+    .find(|node| node.get_name() == "Foo")
+    .unwrap();
+  let references = ctx.reverse_index.get(foo).unwrap();
+  assert_eq!(
+    references
+      .subclasses
+      .iter()
+      .map(|node| node.get_name())
+      .collect::<Vec<_>>(),
+    vec!["Bar"]
+  );
+
+  let files = generate(ctx).unwrap();
+  assert!(!files.contains_key("hierarchy.html"));
+  let foo = files.get("a.ts/~/Foo.html").unwrap();
+  assert!(foo.contains("Subclasses"));
+  assert!(!foo.contains("hierarchyNode"));
+  assert!(
+    foo.contains("href=\"..&#x2F;..&#x2F;.&#x2F;a.ts&#x2F;~&#x2F;Bar.html\"")
+  );
+}
+
+#[tokio::test]
+async fn class_hierarchy() {
+  let doc_nodes_by_url = get_files("multiple").await;
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: true,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+  let files = generate(ctx).unwrap();
+
+  let bar = files.get("a.ts/~/Bar.html").unwrap();
+  assert!(bar.contains("href=\"..&#x2F;..&#x2F;hierarchy.html\""));
+  assert!(bar.contains(
+    "<line x1=\"26\" y1=\"62\" x2=\"26\" y2=\"30\" class=\"hierarchyEdge\" />"
+  ));
+  assert!(bar.contains(
+    "<a href=\"..&#x2F;..&#x2F;.&#x2F;a.ts&#x2F;~&#x2F;Foo.html\"><g class=\"hierarchyNode\">"
+  ));
+  assert!(bar.contains("<g class=\"hierarchyNode current\">"));
+
+  let hierarchy = files.get("hierarchy.html").unwrap();
+  assert!(hierarchy.contains("<a href=\".&#x2F;a.ts&#x2F;~&#x2F;Bar.html\">"));
+}
+
+#[tokio::test]
+async fn search_index_chunks() {
+  use deno_doc::html::search::InvertedSearchIndex;
+  use deno_doc::html::search::TermField;
+  use deno_doc::html::search::tokenize_doc;
+  use deno_doc::html::search::tokenize_name;
+
+  assert_eq!(
+    tokenize_name("Deno.HTMLElement2D"),
+    vec![
+      ("htmlelement2d".to_string(), TermField::Name),
+      ("element2d".to_string(), TermField::NamePart),
+      ("2d".to_string(), TermField::NamePart),
+      ("d".to_string(), TermField::NamePart),
+      ("deno".to_string(), TermField::NamePart),
+    ]
+  );
+  assert_eq!(
+    tokenize_doc("Reads the file at the given path, and returns it."),
+    vec!["reads", "file", "given", "path", "returns"]
+  );
+  assert_eq!(
+    tokenize_doc("ファイルを読む readFile 日"),
+    vec![
+      "ファ", "ァイ", "イル", "ルを", "を読", "読む", "readfile", "日"
+    ]
+  );
+  assert_eq!(
+    tokenize_name("読込File"),
+    vec![
+      ("読込file".to_string(), TermField::Name),
+      ("読込".to_string(), TermField::NamePart),
+    ]
+  );
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("single").await,
+  )
+  .unwrap();
+
+  let search_index = InvertedSearchIndex::new(&ctx);
+  insta::assert_json_snapshot!(search_index.manifest());
+  insta::assert_json_snapshot!(
+    search_index
+      .chunks()
+      .into_iter()
+      .collect::<std::collections::BTreeMap<_, _>>()
+  );
+}
+
+#[tokio::test]
+async fn full_text_search() {
+  use deno_doc::html::search::collect_full_text_entries;
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: true,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("multiple").await,
+  )
+  .unwrap();
+
+  let entries = collect_full_text_entries(&ctx)
+    .into_iter()
+    .map(|entry| {
+      (
+        entry.name.to_string(),
+        entry.section.to_string(),
+        entry.url.to_string(),
+      )
+    })
+    .collect::<Vec<_>>();
+
+  assert!(entries.contains(&(
+    "d".to_string(),
+    "Parameters: foo".to_string(),
+    "./a.ts/~/d.html#function_d_0_parameter_foo".to_string(),
+  )));
+  assert!(entries.contains(&(
+    "d".to_string(),
+    "Return Type".to_string(),
+    "./a.ts/~/d.html#function_d_0_return".to_string(),
+  )));
+  assert!(entries.contains(&(
+    "Foo".to_string(),
+    "test".to_string(),
+    "./a.ts/~/Foo.html#example_0".to_string(),
+  )));
+
+  let files = generate(ctx).unwrap();
+  let manifest = &files["search_index.js"];
+  assert!(manifest.contains("\"textShards\""));
+  assert!(files.keys().any(|name| name.starts_with("search/text_")));
+  assert!(files.contains_key("search/entries_0.js"));
+}
+
+#[tokio::test]
+async fn seo() {
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: Some("pkg".to_string()),
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: Some("https://example.com/docs".to_string()),
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("multiple").await,
+  )
+  .unwrap();
+
+  let files = generate(ctx).unwrap();
+
+  let sitemap = &files["sitemap.xml"];
+  assert!(sitemap.contains("<loc>https://example.com/docs/</loc>"));
+  assert!(
+    sitemap.contains("<loc>https://example.com/docs/a.ts/~/Foo.html</loc>")
+  );
+  assert!(!sitemap.contains("Foo.prototype.html"));
+
+  assert_eq!(
+    files["robots.txt"],
+    "User-agent: *\nAllow: /\n\nSitemap: https://example.com/docs/sitemap.xml\n"
+  );
+
+  let page = html_escape::decode_html_entities(&files["a.ts/~/Foo.html"]);
+  assert!(page.contains(
+    r#"<link rel="canonical" href="https://example.com/docs/a.ts/~/Foo.html">"#
+  ));
+  assert!(
+    page.contains(r#"<meta name="description" content="some Foo docs Bar">"#)
+  );
+  assert!(page.contains(r#"<meta property="og:site_name" content="pkg">"#));
+}
+
+#[tokio::test]
+async fn single_page() {
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("multiple").await,
+  )
+  .unwrap();
+
+  let page = generate_single_page(ctx).unwrap();
+  let page = html_escape::decode_html_entities(&page);
+
+  assert!(page.contains("<title>documentation</title>"));
+  assert!(page.contains("<style>"));
+  assert!(!page.contains(r#"<link rel="stylesheet""#));
+  assert!(!page.contains(r#"<script src="#));
+  assert!(!page.contains(r#"id="searchbar""#));
+
+  assert!(page.contains(r#"<section class="singlePageSection" id="a.ts">"#));
+  assert!(
+    page.contains(r#"<section class="singlePageSection" id="a.ts/~/Foo">"#)
+  );
+  // the redirect of the prototype page points to the class
+  assert!(page.contains(r#"<span id="a.ts/~/Foo.prototype"></span>"#));
+  assert!(page.contains(r##"href="#a.ts/~/Foo""##));
+  assert!(
+    page.contains(r##"href="#a.ts/~/Foo.prototype.methodWithOverloads""##)
+  );
+  assert!(!page.contains(r#".html""#));
+}
+
+#[tokio::test]
+async fn output_sinks() {
+  let doc_nodes = get_files("multiple").await;
+  let create_ctx = || {
+    GenerateCtx::create_basic(
+      GenerateOptions {
+        package_name: None,
+        main_entrypoint: None,
+        href_resolver: Shared::new(EmptyResolver),
+        usage_composer: Shared::new(EmptyResolver),
+        rewrite_map: None,
+        category_docs: None,
+        disable_search: false,
+        symbol_redirect_map: None,
+        default_symbol_map: None,
+        markdown_renderer: comrak::create_renderer(None, None, None),
+        markdown_stripper: Shared::new(comrak::strip),
+        head_inject: None,
+        id_prefix: None,
+        template_overrides: None,
+        messages: None,
+        versions: None,
+        source_files: None,
+        class_hierarchy: false,
+        full_text_search: false,
+        base_url: None,
+        inventories: None,
+        expand_types: false,
+      },
+      doc_nodes.clone(),
+    )
+    .unwrap()
+  };
+
+  let files = generate(create_ctx()).unwrap();
+
+  let mut written = vec![];
+  let mut sink =
+    ProgressSink::new(std::collections::HashMap::new(), |progress| {
+      written.push((progress.path.to_string(), progress.count));
+    });
+  generate_to_sink(create_ctx(), &mut sink).unwrap();
+  assert_eq!(sink.into_inner(), files);
+  assert_eq!(written.len(), files.len());
+  assert_eq!(written.last().unwrap().1, files.len());
+
+  let dir = std::env::temp_dir().join("deno_doc_output_sinks");
+  let _ = fs::remove_dir_all(&dir);
+  generate_to_sink(create_ctx(), &mut DirectorySink::new(&dir)).unwrap();
+  assert_eq!(
+    fs::read_to_string(dir.join("a.ts/~/Foo.html")).unwrap(),
+    files["a.ts/~/Foo.html"]
+  );
+  fs::remove_dir_all(&dir).unwrap();
+
+  #[cfg(feature = "zip")]
+  {
+    let mut sink = ZipSink::new(std::io::Cursor::new(vec![]));
+    generate_to_sink(create_ctx(), &mut sink).unwrap();
+    let archive = sink.finish().unwrap();
+    let mut archive = zip::ZipArchive::new(archive).unwrap();
+    assert_eq!(archive.len(), files.len());
+    let mut page = String::new();
+    std::io::Read::read_to_string(
+      &mut archive.by_name("a.ts/~/Foo.html").unwrap(),
+      &mut page,
+    )
+    .unwrap();
+    assert_eq!(page, files["a.ts/~/Foo.html"]);
+  }
+
+  let json_files = generate_json(create_ctx()).unwrap();
+  let mut json_sink = std::collections::HashMap::new();
+  generate_json_to_sink(create_ctx(), &mut json_sink).unwrap();
+  assert_eq!(json_sink.len(), json_files.len());
+  assert_eq!(
+    serde_json::from_str::<serde_json::Value>(&json_sink["./index.json"])
+      .unwrap(),
+    json_files["./index.json"]
+  );
+}
+
+#[tokio::test]
+async fn deterministic_output() {
+  let doc_nodes = get_files("single").await;
+  let generate_in_order = || {
+    let ctx = GenerateCtx::create_basic(
+      GenerateOptions {
+        package_name: None,
+        main_entrypoint: None,
+        href_resolver: Shared::new(EmptyResolver),
+        usage_composer: Shared::new(EmptyResolver),
+        rewrite_map: None,
+        category_docs: None,
+        disable_search: false,
+        symbol_redirect_map: None,
+        default_symbol_map: None,
+        markdown_renderer: comrak::create_renderer(None, None, None),
+        markdown_stripper: Shared::new(comrak::strip),
+        head_inject: None,
+        id_prefix: None,
+        template_overrides: None,
+        messages: None,
+        versions: None,
+        source_files: None,
+        class_hierarchy: false,
+        full_text_search: false,
+        base_url: Some("https://example.com/docs/".to_string()),
+        inventories: None,
+        expand_types: false,
+      },
+      doc_nodes.clone(),
+    )
+    .unwrap();
+
+    let mut files = vec![];
+    let mut sink =
+      ProgressSink::new(std::collections::HashMap::new(), |progress| {
+        files.push(progress.path.to_string());
+      });
+    generate_to_sink(ctx, &mut sink).unwrap();
+    let contents = sink.into_inner();
+    (files, contents)
+  };
+
+  // the symbol pages are rendered in parallel with the `parallel` feature,
+  // which must not affect the order or content of the files
+  let (order, files) = generate_in_order();
+  for _ in 0..3 {
+    let (other_order, other_files) = generate_in_order();
+    assert_eq!(other_order, order);
+    assert_eq!(other_files, files);
+  }
+}
+
+#[tokio::test]
+async fn incremental() {
+  let doc_nodes = get_files("multiple").await;
+  let create_ctx = |doc_nodes| {
+    GenerateCtx::create_basic(
+      GenerateOptions {
+        package_name: None,
+        main_entrypoint: None,
+        href_resolver: Shared::new(EmptyResolver),
+        usage_composer: Shared::new(EmptyResolver),
+        rewrite_map: None,
+        category_docs: None,
+        disable_search: false,
+        symbol_redirect_map: None,
+        default_symbol_map: None,
+        markdown_renderer: comrak::create_renderer(None, None, None),
+        markdown_stripper: Shared::new(comrak::strip),
+        head_inject: None,
+        id_prefix: None,
+        template_overrides: None,
+        messages: None,
+        versions: None,
+        source_files: None,
+        class_hierarchy: false,
+        full_text_search: false,
+        base_url: Some("https://example.com/".to_string()),
+        inventories: None,
+        expand_types: false,
+      },
+      doc_nodes,
+    )
+    .unwrap()
+  };
+
+  let mut files = std::collections::HashMap::new();
+  let output =
+    generate_incremental(create_ctx(doc_nodes.clone()), None, &mut files)
+      .unwrap();
+  assert_eq!(output.added.len(), files.len());
+  assert!(output.changed.is_empty() && output.removed.is_empty());
+
+  // the assets are hashed, and the pages link to the hashed filenames
+  let stylesheet = files
+    .keys()
+    .find(|path| path.starts_with("styles.") && path.ends_with(".css"))
+    .unwrap()
+    .clone();
+  assert_ne!(stylesheet, STYLESHEET_FILENAME);
+  assert!(!files.contains_key(STYLESHEET_FILENAME));
+  assert!(files["a.ts/~/Foo.html"].contains(&stylesheet));
+
+  // and so are the chunks of the search index, which lists their filenames
+  let search_index = files
+    .iter()
+    .find(|(path, _)| path.starts_with("search_index."))
+    .unwrap()
+    .1;
+  let chunks = files
+    .keys()
+    .filter(|path| path.starts_with("search/"))
+    .collect::<Vec<_>>();
+  assert!(!chunks.is_empty());
+  for chunk in chunks {
+    let (name, filename) =
+      chunk.trim_start_matches("search/").split_once('.').unwrap();
+    assert_ne!(filename, "js");
+    assert!(search_index.contains(&format!(r#""{name}":"{name}.{filename}""#)));
+  }
+
+  // nothing is written when nothing changed
+  let mut unchanged_files = std::collections::HashMap::new();
+  let unchanged = generate_incremental(
+    create_ctx(doc_nodes.clone()),
+    Some(&output.manifest),
+    &mut unchanged_files,
+  )
+  .unwrap();
+  assert!(unchanged_files.is_empty());
+  assert!(unchanged.added.is_empty() && unchanged.changed.is_empty());
+  assert!(unchanged.removed.is_empty());
+  assert_eq!(unchanged.manifest, output.manifest);
+
+  // a changed doc comment only re-renders the pages showing it
+  let mut changed_doc_nodes = doc_nodes.clone();
+  for (specifier, nodes) in &mut changed_doc_nodes {
+    if specifier.path().ends_with("/b.ts") {
+      let node = nodes.iter_mut().find(|node| &*node.name == "x").unwrap();
+      node.js_doc.doc = Some("A changed doc comment.".into());
+    }
+  }
+  let mut changed_files = std::collections::HashMap::new();
+  let changed = generate_incremental(
+    create_ctx(changed_doc_nodes.clone()),
+    Some(&output.manifest),
+    &mut changed_files,
+  )
+  .unwrap();
+  assert!(changed.changed.contains(&"b.ts/~/x.html".to_string()));
+  assert!(
+    changed
+      .changed
+      .iter()
+      .all(|path| !path.starts_with("a.ts/"))
+  );
+  assert!(changed.added.is_empty() && changed.removed.is_empty());
+  assert_eq!(changed_files.len(), changed.changed.len());
+
+  // the output matches a full build
+  let mut merged_files = files.clone();
+  merged_files.extend(changed_files);
+  let mut full_files = std::collections::HashMap::new();
+  generate_incremental(create_ctx(changed_doc_nodes), None, &mut full_files)
+    .unwrap();
+  assert_eq!(merged_files, full_files);
+
+  // the files of removed modules are reported
+  let mut removed_doc_nodes = doc_nodes;
+  removed_doc_nodes.retain(|specifier, _| !specifier.path().ends_with("/c.ts"));
+  let removed = generate_incremental(
+    create_ctx(removed_doc_nodes),
+    Some(&output.manifest),
+    &mut std::collections::HashMap::new(),
+  )
+  .unwrap();
+  assert!(removed.removed.iter().any(|path| path.starts_with("c.ts/")));
+  assert!(
+    removed
+      .removed
+      .iter()
+      .all(|path| !removed.manifest.files.contains_key(path))
+  );
+}
+
+#[tokio::test]
+async fn inventory() {
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: Some("a".to_string()),
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: Some("https://example.com/a".to_string()),
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("multiple").await,
+  )
+  .unwrap();
+
+  let files = generate(ctx).unwrap();
+  let inventory =
+    serde_json::from_str::<Inventory>(&files[INVENTORY_FILENAME]).unwrap();
+  assert_eq!(inventory.base_url.as_deref(), Some("https://example.com/a"));
+  assert_eq!(inventory.package_name.as_deref(), Some("a"));
+  assert_eq!(
+    inventory.modules.keys().collect::<Vec<_>>(),
+    vec!["_d.ts", "a.ts", "b.ts", "c.ts"]
+  );
+
+  let module = &inventory.modules["a.ts"];
+  assert_eq!(module.href, "a.ts/index.html");
+  assert_eq!(module.symbols["Foo"].href, "a.ts/~/Foo.html");
+  assert_eq!(module.symbols["Foo"].kind, vec!["Class"]);
+  assert_eq!(
+    module.symbols["Foo.prototype.methodWithOverloads"].href,
+    "a.ts/~/Foo.prototype.methodWithOverloads.html"
+  );
+  assert_eq!(
+    module.symbols["Foo.prototype.methodWithOverloads"].kind,
+    vec!["Method", "Method", "Method"]
+  );
+}
+
+#[tokio::test]
+async fn webassembly() {
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let doc_nodes_by_url = deno_doc::parse_sources(
+    std::slice::from_ref(&root),
+    IndexMap::from([
+      (
+        root.clone(),
+        r#"export { add } from "./add.wasm";"#.to_string(),
+      ),
+      (
+        ModuleSpecifier::parse("file:///add.wasm").unwrap(),
+        [
+          "\0asm\x01\0\0\0",
+          "\x01\x07\x01\x60\x02\x7f\x7e\x01\x7e",
+          "\x03\x02\x01\0",
+          "\x07\x07\x01\x03add\0\0",
+          "\x0a\x06\x01\x04\0\x20\x01\x0b",
+          "\0\x14\x04name\x02\x0d\x01\0\x02\0\x03lhs\x01\x03rhs",
+        ]
+        .concat(),
+      ),
+    ]),
+    Default::default(),
+  )
+  .await
+  .unwrap();
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+
+  let files = generate(ctx).unwrap();
+  let page = &files["./~/add.html"];
+  assert!(page.contains("text-webassembly"));
+  assert!(page.contains(">WebAssembly</div>"));
+  assert!(page.contains("lhs"));
+}
+
+#[tokio::test]
+async fn interface_accessors() {
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let doc_nodes_by_url = deno_doc::parse_sources(
+    std::slice::from_ref(&root),
+    IndexMap::from([(
+      root.clone(),
+      r#"
+export interface Thing {
+  /** The size. */
+  get size(): number;
+  set size(value: number | string);
+  get label(): string;
+}
+"#
+      .to_string(),
+    )]),
+    Default::default(),
+  )
+  .await
+  .unwrap();
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+
+  let files = generate(ctx).unwrap();
+  let page = &files["./~/Thing.html"];
+  assert_eq!(page.matches(r#"id="accessor_size""#).count(), 1);
+  assert!(page.contains(r#"<span class="font-normal">, set: </span>"#));
+  assert!(page.contains(r#"id="accessor_label""#));
+  assert!(page.contains(">readonly</div>"));
+  assert!(!page.contains(r#"id="methods""#));
+}
+
+#[tokio::test]
+async fn expand_types() {
+  let root = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let settings = ModuleSpecifier::parse("file:///settings.ts").unwrap();
+  let limits = ModuleSpecifier::parse("file:///limits.ts").unwrap();
+  let doc_nodes_by_url = deno_doc::parse_sources(
+    &[root.clone(), settings.clone(), limits.clone()],
+    IndexMap::from([
+      (
+        root.clone(),
+        r#"
+import type { Settings } from "./settings.ts";
+
+export interface Config {
+  name: string;
+  port: number;
+}
+
+export type Options = Partial<Config>;
+
+export const defaults: Pick<Config, "port"> = { port: 80 };
+
+export type Name = string;
+
+export type Verbose = Pick<Settings, "verbose">;
+
+export type Bounds = Partial<Limits>;
+
+export function serve(options: Partial<Config>): Readonly<Config> {
+  return { name: "", port: 80, ...options };
+}
+"#
+        .to_string(),
+      ),
+      (
+        settings,
+        r#"
+export interface Settings {
+  verbose: boolean;
+  quiet: boolean;
+}
+"#
+        .to_string(),
+      ),
+      (
+        limits,
+        r#"
+export interface Limits {
+  max: number;
+}
+"#
+        .to_string(),
+      ),
+    ]),
+    Default::default(),
+  )
+  .await
+  .unwrap();
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(EmptyResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: None,
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: true,
+    },
+    doc_nodes_by_url,
+  )
+  .unwrap();
+
+  let files = generate(ctx).unwrap();
+
+  let options = &files["/mod.ts/~/Options.html"];
+  assert!(options.contains(r#"<details class="expandedType">"#));
+  assert!(
+    options.contains("<div>name?<span>: <span>string</span></span></div>")
+  );
+  assert!(
+    options.contains("<div>port?<span>: <span>number</span></span></div>")
+  );
+
+  let defaults = &files["/mod.ts/~/defaults.html"];
+  assert!(defaults.contains(r#"<details class="expandedType">"#));
+  assert!(
+    defaults.contains("<div>port<span>: <span>number</span></span></div>")
+  );
+  assert!(!defaults.contains("<div>name"));
+
+  assert!(!files["/mod.ts/~/Name.html"].contains("expandedType"));
+
+  let verbose = &files["/mod.ts/~/Verbose.html"];
+  assert!(
+    verbose.contains("<div>verbose<span>: <span>boolean</span></span></div>")
+  );
+  assert!(!verbose.contains("<div>quiet"));
+
+  // `Limits` is declared in another module, but not imported
+  assert!(!files["/mod.ts/~/Bounds.html"].contains("expandedType"));
+
+  let serve = &files["/mod.ts/~/serve.html"];
+  assert!(serve.contains("<summary>Expanded type: <code>options</code>"));
+  assert!(serve.contains("<div>name?<span>: <span>string</span></span></div>"));
+  assert!(serve.contains(
+    "<div><span>readonly </span>name<span>: <span>string</span></span></div>"
+  ));
+}
+
+#[tokio::test]
+async fn category_resolve_kind() {
+  struct CategoryResolver;
+
+  impl HrefResolver for CategoryResolver {
+    fn resolve_path(
+      &self,
+      current: UrlResolveKind,
+      target: UrlResolveKind,
+    ) -> String {
+      match target {
+        UrlResolveKind::Category { category } => format!("category:{category}"),
+        _ => href_path_resolve(current, target),
+      }
+    }
+
+    fn resolve_global_symbol(&self, _symbol: &[String]) -> Option<String> {
+      None
+    }
+
+    fn resolve_import_href(
+      &self,
+      _symbol: &[String],
+      _src: &str,
+    ) -> Option<String> {
+      None
+    }
+
+    fn resolve_source(&self, _location: &deno_doc::Location) -> Option<String> {
+      None
+    }
+
+    fn resolve_external_jsdoc_module(
+      &self,
+      _module: &str,
+      _symbol: Option<&str>,
+    ) -> Option<(String, String)> {
+      None
+    }
+  }
+
+  let ctx = GenerateCtx::create_basic(
+    GenerateOptions {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver: Shared::new(CategoryResolver),
+      usage_composer: Shared::new(EmptyResolver),
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      markdown_renderer: comrak::create_renderer(None, None, None),
+      markdown_stripper: Shared::new(comrak::strip),
+      head_inject: None,
+      id_prefix: None,
+      template_overrides: None,
+      messages: Some(Messages {
+        lang: "de".to_string(),
+        ..Default::default()
+      }),
+      versions: None,
+      source_files: None,
+      class_hierarchy: false,
+      full_text_search: false,
+      base_url: None,
+      inventories: None,
+      expand_types: false,
+    },
+    get_files("single").await,
+  )
+  .unwrap();
+
+  // resolvers are handed the category name, not the slug of the page
+  assert_eq!(
+    ctx.resolve_path(
+      UrlResolveKind::Root,
+      UrlResolveKind::Category {
+        category: "Größe ändern"
+      }
+    ),
+    "category:Größe ändern"
+  );
+}